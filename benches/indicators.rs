@@ -28,6 +28,16 @@ fn bench_indicator_average_directional_index(b: &mut test::Bencher) {
 	bench_indicator::<AverageDirectionalIndex>(b);
 }
 
+#[bench]
+fn bench_anomaly_detector(b: &mut test::Bencher) {
+	bench_indicator::<AnomalyDetector>(b);
+}
+
+#[bench]
+fn bench_aroon_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<AroonOscillator>(b);
+}
+
 #[bench]
 fn bench_awesome_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<AwesomeOscillator>(b);
@@ -38,6 +48,36 @@ fn bench_bollinger_bands(b: &mut test::Bencher) {
 	bench_indicator::<BollingerBands>(b);
 }
 
+#[bench]
+fn bench_bollinger_percent_b(b: &mut test::Bencher) {
+	bench_indicator::<BollingerPercentB>(b);
+}
+
+#[bench]
+fn bench_candlestick_patterns(b: &mut test::Bencher) {
+	bench_indicator::<yata::patterns::CandlestickPatterns>(b);
+}
+
+#[bench]
+fn bench_chaikin_volatility(b: &mut test::Bencher) {
+	bench_indicator::<ChaikinVolatility>(b);
+}
+
+#[bench]
+fn bench_channel(b: &mut test::Bencher) {
+	bench_indicator::<Channel>(b);
+}
+
+#[bench]
+fn bench_chart_patterns(b: &mut test::Bencher) {
+	bench_indicator::<yata::patterns::ChartPatterns>(b);
+}
+
+#[bench]
+fn bench_center_of_gravity(b: &mut test::Bencher) {
+	bench_indicator::<CenterOfGravity>(b);
+}
+
 #[bench]
 fn bench_chaikin_money_flow(b: &mut test::Bencher) {
 	bench_indicator::<ChaikinMoneyFlow>(b);
@@ -48,6 +88,11 @@ fn bench_chaikin_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<ChaikinOscillator>(b);
 }
 
+#[bench]
+fn bench_chande_forecast_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<ChandeForecastOscillator>(b);
+}
+
 #[bench]
 fn bench_chande_kroll_stop(b: &mut test::Bencher) {
 	bench_indicator::<ChandeKrollStop>(b);
@@ -58,6 +103,11 @@ fn bench_chande_momentum_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<ChandeMomentumOscillator>(b);
 }
 
+#[bench]
+fn bench_chandelier_exit(b: &mut test::Bencher) {
+	bench_indicator::<ChandelierExit>(b);
+}
+
 #[bench]
 fn bench_commodity_channel_index(b: &mut test::Bencher) {
 	bench_indicator::<CommodityChannelIndex>(b);
@@ -68,11 +118,31 @@ fn bench_coppock_curve(b: &mut test::Bencher) {
 	bench_indicator::<CoppockCurve>(b);
 }
 
+#[bench]
+fn bench_cumulative_volume_delta(b: &mut test::Bencher) {
+	bench_indicator::<CumulativeVolumeDelta>(b);
+}
+
+#[bench]
+fn bench_demarker(b: &mut test::Bencher) {
+	bench_indicator::<DeMarker>(b);
+}
+
 #[bench]
 fn bench_detrended_price_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<DetrendedPriceOscillator>(b);
 }
 
+#[bench]
+fn bench_disparity_index(b: &mut test::Bencher) {
+	bench_indicator::<DisparityIndex>(b);
+}
+
+#[bench]
+fn bench_divergence(b: &mut test::Bencher) {
+	bench_indicator::<Divergence<RSI>>(b);
+}
+
 #[bench]
 fn bench_donchian_channel(b: &mut test::Bencher) {
 	bench_indicator::<DonchianChannel>(b);
@@ -83,6 +153,11 @@ fn bench_ease_of_movement(b: &mut test::Bencher) {
 	bench_indicator::<EaseOfMovement>(b);
 }
 
+#[bench]
+fn bench_elder_impulse(b: &mut test::Bencher) {
+	bench_indicator::<ElderImpulse>(b);
+}
+
 #[bench]
 fn bench_elders_force_index(b: &mut test::Bencher) {
 	bench_indicator::<EldersForceIndex>(b);
@@ -93,11 +168,36 @@ fn bench_envelopes(b: &mut test::Bencher) {
 	bench_indicator::<Envelopes>(b);
 }
 
+#[bench]
+fn bench_filtered(b: &mut test::Bencher) {
+	bench_indicator::<Filtered<MACD>>(b);
+}
+
 #[bench]
 fn bench_fisher_transform(b: &mut test::Bencher) {
 	bench_indicator::<FisherTransform>(b);
 }
 
+#[bench]
+fn bench_gann_hilo_activator(b: &mut test::Bencher) {
+	bench_indicator::<GannHiLoActivator>(b);
+}
+
+#[bench]
+fn bench_gaps(b: &mut test::Bencher) {
+	bench_indicator::<Gaps>(b);
+}
+
+#[bench]
+fn bench_gator_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<GatorOscillator>(b);
+}
+
+#[bench]
+fn bench_historical_volatility(b: &mut test::Bencher) {
+	bench_indicator::<HistoricalVolatility>(b);
+}
+
 #[bench]
 fn bench_hull_moving_average(b: &mut test::Bencher) {
 	bench_indicator::<HullMovingAverage>(b);
@@ -108,11 +208,21 @@ fn bench_ichimoku_cloud(b: &mut test::Bencher) {
 	bench_indicator::<IchimokuCloud>(b);
 }
 
+#[bench]
+fn bench_intraday_momentum_index(b: &mut test::Bencher) {
+	bench_indicator::<IntradayMomentumIndex>(b);
+}
+
 #[bench]
 fn bench_kaufman(b: &mut test::Bencher) {
 	bench_indicator::<Kaufman>(b);
 }
 
+#[bench]
+fn bench_kdj(b: &mut test::Bencher) {
+	bench_indicator::<KDJ>(b);
+}
+
 #[bench]
 fn bench_keltner_channel(b: &mut test::Bencher) {
 	bench_indicator::<KeltnerChannel>(b);
@@ -128,11 +238,31 @@ fn bench_know_sure_thing(b: &mut test::Bencher) {
 	bench_indicator::<KnowSureThing>(b);
 }
 
+#[bench]
+fn bench_laguerre_rsi(b: &mut test::Bencher) {
+	bench_indicator::<LaguerreRSI>(b);
+}
+
+#[bench]
+fn bench_linear_regression_channel(b: &mut test::Bencher) {
+	bench_indicator::<LinearRegressionChannel>(b);
+}
+
 #[bench]
 fn bench_macd(b: &mut test::Bencher) {
 	bench_indicator::<MACD>(b);
 }
 
+#[bench]
+fn bench_market_regime(b: &mut test::Bencher) {
+	bench_indicator::<MarketRegime>(b);
+}
+
+#[bench]
+fn bench_mesa_sine_wave(b: &mut test::Bencher) {
+	bench_indicator::<MESASineWave>(b);
+}
+
 #[bench]
 fn bench_momentum_index(b: &mut test::Bencher) {
 	bench_indicator::<MomentumIndex>(b);
@@ -143,16 +273,96 @@ fn bench_money_flow_index(b: &mut test::Bencher) {
 	bench_indicator::<MoneyFlowIndex>(b);
 }
 
+#[bench]
+fn bench_moving_average_ribbon(b: &mut test::Bencher) {
+	bench_indicator::<MovingAverageRibbon>(b);
+}
+
+#[bench]
+fn bench_negative_volume_index(b: &mut test::Bencher) {
+	bench_indicator::<NegativeVolumeIndex>(b);
+}
+
+#[bench]
+fn bench_normalized(b: &mut test::Bencher) {
+	bench_indicator::<Normalized<MACD>>(b);
+}
+
+#[bench]
+fn bench_open_interest_change(b: &mut test::Bencher) {
+	bench_indicator::<OpenInterestChange>(b);
+}
+
+#[bench]
+fn bench_open_interest_divergence(b: &mut test::Bencher) {
+	bench_indicator::<OpenInterestDivergence>(b);
+}
+
 #[bench]
 fn bench_parabolic_sar(b: &mut test::Bencher) {
 	bench_indicator::<ParabolicSAR>(b);
 }
 
+#[bench]
+fn bench_percent_rank(b: &mut test::Bencher) {
+	bench_indicator::<PercentRank>(b);
+}
+
+#[bench]
+fn bench_percentage_price_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<PercentagePriceOscillator>(b);
+}
+
+#[bench]
+fn bench_percentage_volume_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<PercentageVolumeOscillator>(b);
+}
+
+#[bench]
+fn bench_pivot_points(b: &mut test::Bencher) {
+	bench_indicator::<PivotPoints>(b);
+}
+
 #[bench]
 fn bench_pivot_reversal_strategy(b: &mut test::Bencher) {
 	bench_indicator::<PivotReversalStrategy>(b);
 }
 
+#[bench]
+fn bench_positive_volume_index(b: &mut test::Bencher) {
+	bench_indicator::<PositiveVolumeIndex>(b);
+}
+
+#[bench]
+fn bench_premier_stochastic(b: &mut test::Bencher) {
+	bench_indicator::<PremierStochastic>(b);
+}
+
+#[bench]
+fn bench_pretty_good_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<PrettyGoodOscillator>(b);
+}
+
+#[bench]
+fn bench_price_volume_trend(b: &mut test::Bencher) {
+	bench_indicator::<PriceVolumeTrend>(b);
+}
+
+#[bench]
+fn bench_psychological_line(b: &mut test::Bencher) {
+	bench_indicator::<PsychologicalLine>(b);
+}
+
+#[bench]
+fn bench_rainbow_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<RainbowOscillator>(b);
+}
+
+#[bench]
+fn bench_relative_momentum_index(b: &mut test::Bencher) {
+	bench_indicator::<RelativeMomentumIndex>(b);
+}
+
 #[bench]
 fn bench_relative_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeStrengthIndex>(b);
@@ -163,21 +373,66 @@ fn bench_relative_vigor_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeVigorIndex>(b);
 }
 
+#[bench]
+fn bench_relative_volatility_index(b: &mut test::Bencher) {
+	bench_indicator::<RelativeVolatilityIndex>(b);
+}
+
 #[bench]
 fn bench_smi_ergodic_indicator(b: &mut test::Bencher) {
 	bench_indicator::<SMIErgodicIndicator>(b);
 }
 
+#[bench]
+fn bench_smoothed(b: &mut test::Bencher) {
+	bench_indicator::<Smoothed<RSI>>(b);
+}
+
+#[bench]
+fn bench_standard_error_bands(b: &mut test::Bencher) {
+	bench_indicator::<StandardErrorBands>(b);
+}
+
+#[bench]
+fn bench_starc_bands(b: &mut test::Bencher) {
+	bench_indicator::<STARCBands>(b);
+}
+
+#[bench]
+fn bench_stochastic_momentum_index(b: &mut test::Bencher) {
+	bench_indicator::<StochasticMomentumIndex>(b);
+}
+
 #[bench]
 fn bench_stochastic_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<StochasticOscillator>(b);
 }
 
+#[bench]
+fn bench_stop_target(b: &mut test::Bencher) {
+	bench_indicator::<StopTarget<RSI>>(b);
+}
+
+#[bench]
+fn bench_support_resistance(b: &mut test::Bencher) {
+	bench_indicator::<SupportResistance>(b);
+}
+
+#[bench]
+fn bench_trend_intensity_index(b: &mut test::Bencher) {
+	bench_indicator::<TrendIntensityIndex>(b);
+}
+
 #[bench]
 fn bench_trend_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrendStrengthIndex>(b);
 }
 
+#[bench]
+fn bench_trendlines(b: &mut test::Bencher) {
+	bench_indicator::<Trendlines>(b);
+}
+
 #[bench]
 fn bench_trix(b: &mut test::Bencher) {
 	bench_indicator::<Trix>(b);
@@ -188,7 +443,47 @@ fn bench_true_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrueStrengthIndex>(b);
 }
 
+#[bench]
+fn bench_ulcer_index(b: &mut test::Bencher) {
+	bench_indicator::<UlcerIndex>(b);
+}
+
+#[bench]
+fn bench_vertical_horizontal_filter(b: &mut test::Bencher) {
+	bench_indicator::<VerticalHorizontalFilter>(b);
+}
+
+#[bench]
+fn bench_volatility_stop(b: &mut test::Bencher) {
+	bench_indicator::<VolatilityStop>(b);
+}
+
+#[bench]
+fn bench_volume_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<VolumeOscillator>(b);
+}
+
+#[bench]
+fn bench_volume_profile(b: &mut test::Bencher) {
+	bench_indicator::<VolumeProfile>(b);
+}
+
+#[bench]
+fn bench_wave_trend(b: &mut test::Bencher) {
+	bench_indicator::<WaveTrend>(b);
+}
+
+#[bench]
+fn bench_williams_fractals(b: &mut test::Bencher) {
+	bench_indicator::<WilliamsFractals>(b);
+}
+
 #[bench]
 fn bench_woodies_cci(b: &mut test::Bencher) {
 	bench_indicator::<WoodiesCCI>(b);
 }
+
+#[bench]
+fn bench_zig_zag(b: &mut test::Bencher) {
+	bench_indicator::<ZigZag>(b);
+}
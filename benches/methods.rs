@@ -894,3 +894,49 @@ fn bench_heikin_ashi(b: &mut test::Bencher) {
 	let mut method = HeikinAshi::new((), &candles[0]).unwrap();
 	b.iter(|| method.next(&iter.next().unwrap()))
 }
+
+// BarsSinceExtreme  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_bars_since_extreme_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = BarsSinceExtreme::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_bars_since_extreme_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = BarsSinceExtreme::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// PercentRank  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_percent_rank_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = PercentRank::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_percent_rank_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = PercentRank::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
@@ -0,0 +1,480 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::simulator;
+use super::{Fill, Ledger, Report, Side, Sizing, Slippage, Trade};
+use crate::core::{
+	Action, Error, IndicatorInstanceDyn, IndicatorResult, PeriodType, ValueType, OHLCV,
+};
+use crate::helpers::{method, RegularMethods};
+
+/// Maps indicators results into a trading action on every candle
+///
+/// Any `FnMut(&[IndicatorResult]) -> Action` closure is a `Rule` too.
+pub trait Rule {
+	/// Returns trading action for the current indicators results
+	///
+	/// Results are provided in the same order as indicators were given to the [`Backtest`].
+	fn next(&mut self, results: &[IndicatorResult]) -> Action;
+}
+
+impl<F> Rule for F
+where
+	F: FnMut(&[IndicatorResult]) -> Action,
+{
+	fn next(&mut self, results: &[IndicatorResult]) -> Action {
+		self(results)
+	}
+}
+
+/// Simple [`Rule`] which just takes some signal of some indicator
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalRule {
+	/// Index of the indicator. Default is `0`.
+	pub indicator: usize,
+
+	/// Index of the indicator's signal. Default is `0`.
+	pub signal: usize,
+}
+
+impl Rule for SignalRule {
+	fn next(&mut self, results: &[IndicatorResult]) -> Action {
+		results[self.indicator].signal(self.signal)
+	}
+}
+
+/// Backtesting engine
+///
+/// On every candle all the indicators are evaluated and their results are passed into the [`Rule`].
+/// Only the sign of the returned action matters:
+///
+/// * buy action closes short position and opens long position;
+/// * sell action closes long position and opens short position, if `allow_short` is `true`;
+/// * no action holds the current position.
+///
+/// Orders are filled according to the `fill` model. Market orders are filled with the `slippage`.
+/// Relative `commission` is paid on every fill. Position which is still open after the last candle
+/// is closed at the last `close` value.
+///
+/// Part of the equity invested into every position is calculated by the `sizing` model
+/// with the [`ATR`](crate::methods::TR) of the `volatility_period` as the volatility value.
+/// By default whole equity is invested, so equity is compounded from trade to trade.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Backtest {
+	/// Allows to open short positions. Default is `false`.
+	pub allow_short: bool,
+
+	/// Orders fill model. Default is [`Close`](Fill::Close).
+	pub fill: Fill,
+
+	/// Relative commission paid on every fill. Default is `0.0`.
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub commission: ValueType,
+
+	/// Slippage of market orders. Default is zero [`Relative`](Slippage::Relative) slippage.
+	pub slippage: Slippage,
+
+	/// Position sizing model. Default is [`Fixed(1.0)`](Sizing::Fixed).
+	pub sizing: Sizing,
+
+	/// Period of the `ATR` passed into the `sizing` model. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub volatility_period: PeriodType,
+}
+
+impl Backtest {
+	/// Validates backtest parameters
+	#[must_use]
+	pub fn validate(&self) -> bool {
+		simulator::validate(self.fill, self.slippage, self.commission)
+			&& self.sizing.validate()
+			&& self.volatility_period > 0
+	}
+
+	/// Runs backtest over the series of `candles`
+	///
+	/// `indicators` should be already initialized. Every candle is passed into every indicator exactly once.
+	pub fn run<T: OHLCV, R: Rule>(
+		&self,
+		candles: &[T],
+		indicators: &mut [Box<dyn IndicatorInstanceDyn<T>>],
+		mut rule: R,
+	) -> Result<Report, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let mut ledger = Ledger::new(self.commission)?;
+		let mut trades = Vec::new();
+		let mut equity = Vec::with_capacity(candles.len());
+		let mut results = Vec::with_capacity(indicators.len());
+
+		if candles.is_empty() {
+			return Ok(Report::default());
+		}
+
+		let first = &candles[0];
+		let mut atr = method(
+			RegularMethods::RMA,
+			self.volatility_period,
+			first.high() - first.low(),
+		)?;
+		let mut prev_close = first.close();
+		let mut volatility = atr.next(first.tr_close(prev_close));
+
+		// order waiting for the next candle: side and price
+		let mut order: Option<(Side, ValueType)> = None;
+
+		for (index, candle) in candles.iter().enumerate() {
+			if let Some((side, price)) = order {
+				let price = match self.fill {
+					Fill::Limit(_) => limit_fill(side, price, candle),
+					_ => Some(self.slippage.apply(side, candle.open(), candle)),
+				};
+
+				if let Some(price) = price {
+					trades.extend(self.execute(&mut ledger, side, index, price, volatility));
+					order = None;
+				}
+			}
+
+			if index > 0 {
+				volatility = atr.next(candle.tr_close(prev_close));
+				prev_close = candle.close();
+			}
+
+			results.clear();
+			results.extend(
+				indicators
+					.iter_mut()
+					.map(|indicator| indicator.next(candle)),
+			);
+
+			let close = candle.close();
+			let side = match rule.next(&results).analog() {
+				1 => Some(Side::Long),
+				-1 => Some(Side::Short),
+				_ => None,
+			};
+
+			if let Some(side) = side {
+				let current = ledger.position().map(|position| position.side);
+
+				if current == Some(side)
+					|| (current.is_none() && side == Side::Short && !self.allow_short)
+				{
+					order = None;
+				} else {
+					match self.fill {
+						Fill::Close => {
+							let price = self.slippage.apply(side, close, candle);
+							trades.extend(self.execute(
+								&mut ledger,
+								side,
+								index,
+								price,
+								volatility,
+							));
+						}
+						Fill::NextOpen => order = Some((side, close)),
+						Fill::Limit(offset) => {
+							order =
+								Some((side, close * offset.mul_add(-side.sign() as ValueType, 1.)));
+						}
+					}
+				}
+			}
+
+			equity.push(ledger.equity(close));
+		}
+
+		if let (Some(position), Some(candle)) = (ledger.position(), candles.last()) {
+			let price = self
+				.slippage
+				.apply(position.side.opposite(), candle.close(), candle);
+			trades.extend(ledger.close(candles.len() - 1, price));
+
+			if let Some(last) = equity.last_mut() {
+				*last = ledger.balance();
+			}
+		}
+
+		Ok(Report::new(trades, equity))
+	}
+
+	fn execute(
+		&self,
+		ledger: &mut Ledger,
+		side: Side,
+		index: usize,
+		price: ValueType,
+		volatility: ValueType,
+	) -> Option<Trade> {
+		if side == Side::Long || self.allow_short {
+			let fraction = self.sizing.fraction(price, volatility);
+			ledger.open_fraction(side, index, price, fraction)
+		} else {
+			ledger.close(index, price)
+		}
+	}
+}
+
+impl Default for Backtest {
+	fn default() -> Self {
+		Self {
+			allow_short: false,
+			fill: Fill::default(),
+			commission: 0.,
+			slippage: Slippage::default(),
+			sizing: Sizing::default(),
+			volatility_period: 14,
+		}
+	}
+}
+
+/// Returns fill price of the limit order of the given `side` and `limit` price on the `candle`, if the order is filled
+fn limit_fill<T: OHLCV>(side: Side, limit: ValueType, candle: &T) -> Option<ValueType> {
+	match side {
+		Side::Long if candle.open() <= limit => Some(candle.open()),
+		Side::Long if candle.low() <= limit => Some(limit),
+		Side::Short if candle.open() >= limit => Some(candle.open()),
+		Side::Short if candle.high() >= limit => Some(limit),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Backtest, SignalRule};
+	use crate::backtest::{Fill, Side, Sizing, Slippage};
+	use crate::core::{
+		Action, Candle, IndicatorConfigDyn, IndicatorInstanceDyn, IndicatorResult, ValueType,
+	};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::RSI;
+
+	fn candles(closes: &[ValueType]) -> Vec<Candle> {
+		closes
+			.iter()
+			.map(|&close| Candle {
+				open: close,
+				high: close,
+				low: close,
+				close,
+				volume: 1.0,
+			})
+			.collect()
+	}
+
+	fn ohlc(values: &[(ValueType, ValueType, ValueType, ValueType)]) -> Vec<Candle> {
+		values
+			.iter()
+			.map(|&(open, high, low, close)| Candle {
+				open,
+				high,
+				low,
+				close,
+				volume: 1.0,
+			})
+			.collect()
+	}
+
+	fn rule(actions: &'static [i8]) -> impl FnMut(&[IndicatorResult]) -> Action {
+		let mut index = 0;
+
+		move |_: &[IndicatorResult]| {
+			let action = actions[index].into();
+			index += 1;
+			action
+		}
+	}
+
+	#[test]
+	fn test_backtest_long_only() {
+		let candles = candles(&[10., 11., 12., 11., 10., 12., 14.]);
+		let mut indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> = Vec::new();
+
+		let report = Backtest::default()
+			.run(&candles, &mut indicators, rule(&[1, 1, -1, 0, 1, 0, 0]))
+			.unwrap();
+
+		assert_eq!(report.trades.len(), 2);
+
+		let trade = report.trades[0];
+		assert_eq!(trade.side, Side::Long);
+		assert_eq!((trade.entry_index, trade.exit_index), (0, 2));
+		assert_eq_float(0.2, trade.return_ratio());
+
+		// still open position is closed at the last candle
+		let trade = report.trades[1];
+		assert_eq!((trade.entry_index, trade.exit_index), (4, 6));
+		assert_eq_float(4.0, trade.profit());
+
+		assert_eq!(report.summary.wins, 2);
+		assert_eq_float(1.0, report.summary.win_rate);
+		assert_eq_float(
+			ValueType::mul_add(1.2, 1.4, -1.),
+			report.summary.total_return,
+		);
+
+		let equity = [1.0, 1.1, 1.2, 1.2, 1.2, 1.2 * 1.2, 1.2 * 1.4];
+		assert_eq!(report.equity.len(), equity.len());
+		for (&expected, &value) in equity.iter().zip(&report.equity) {
+			assert_eq_float(expected, value);
+		}
+	}
+
+	#[test]
+	fn test_backtest_short() {
+		let candles = candles(&[10., 8., 12., 11.]);
+		let mut indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> = Vec::new();
+
+		let backtest = Backtest {
+			allow_short: true,
+			..Backtest::default()
+		};
+		let report = backtest
+			.run(&candles, &mut indicators, rule(&[-1, 1, -1, 0]))
+			.unwrap();
+
+		assert_eq!(report.trades.len(), 3);
+		assert_eq!(report.trades[0].side, Side::Short);
+		assert_eq_float(0.2, report.trades[0].return_ratio());
+		assert_eq!(report.trades[1].side, Side::Long);
+		assert_eq_float(0.5, report.trades[1].return_ratio());
+		assert_eq!(report.trades[2].side, Side::Short);
+		assert_eq_float(1. / 12., report.trades[2].return_ratio());
+		assert_eq!(report.summary.losses, 0);
+	}
+
+	#[test]
+	fn test_backtest_next_open() {
+		let candles = ohlc(&[
+			(10., 10., 10., 10.),
+			(11., 12., 10., 11.5),
+			(12., 13., 11., 12.),
+			(11., 11., 9., 10.),
+		]);
+		let mut indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> = Vec::new();
+
+		let backtest = Backtest {
+			fill: Fill::NextOpen,
+			..Backtest::default()
+		};
+		let report = backtest
+			.run(&candles, &mut indicators, rule(&[1, 0, -1, 0]))
+			.unwrap();
+
+		assert_eq!(report.trades.len(), 1);
+		let trade = report.trades[0];
+		assert_eq!((trade.entry_index, trade.exit_index), (1, 3));
+		assert_eq_float(11., trade.entry_price);
+		assert_eq_float(11., trade.exit_price);
+
+		let backtest = Backtest {
+			fill: Fill::Limit(0.1),
+			..Backtest::default()
+		};
+		let report = backtest
+			.run(&candles, &mut indicators, rule(&[1, 0, 0, 0]))
+			.unwrap();
+
+		assert_eq!(report.trades.len(), 1);
+		let trade = report.trades[0];
+		assert_eq!((trade.entry_index, trade.exit_index), (3, 3));
+		assert_eq_float(9., trade.entry_price);
+		assert_eq_float(1. / 9., trade.return_ratio());
+	}
+
+	#[test]
+	fn test_backtest_costs() {
+		let candles = candles(&[10., 12.]);
+		let mut indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> = Vec::new();
+
+		let backtest = Backtest {
+			commission: 0.01,
+			slippage: Slippage::Relative(0.01),
+			..Backtest::default()
+		};
+		let report = backtest
+			.run(&candles, &mut indicators, rule(&[1, 0]))
+			.unwrap();
+
+		let expected = ValueType::mul_add(11.88 / 10.1 * 0.99, 0.99, -1.);
+		assert_eq_float(10.1, report.trades[0].entry_price);
+		assert_eq_float(11.88, report.trades[0].exit_price);
+		assert_eq_float(expected, report.summary.total_return);
+		assert_eq_float(1. + expected, report.equity[1]);
+
+		let backtest = Backtest {
+			commission: 1.,
+			..Backtest::default()
+		};
+		assert!(backtest
+			.run(&candles, &mut indicators, rule(&[1, 0]))
+			.is_err());
+	}
+
+	#[test]
+	fn test_backtest_sizing() {
+		let candles = ohlc(&[
+			(10., 11., 9., 10.),
+			(10., 11., 9., 10.),
+			(10., 11., 9., 12.),
+		]);
+		let mut indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> = Vec::new();
+
+		// ATR is 2.0, so 10% risk at 2 ATR gives 0.25 of equity at price 10.0
+		let backtest = Backtest {
+			sizing: Sizing::Volatility {
+				risk: 0.1,
+				multiplier: 2.,
+			},
+			..Backtest::default()
+		};
+		let report = backtest
+			.run(&candles, &mut indicators, rule(&[0, 1, 0]))
+			.unwrap();
+
+		assert_eq_float(0.25, report.trades[0].size);
+		assert_eq_float(0.05, report.summary.total_return);
+		assert_eq_float(1.05, report.equity[2]);
+
+		let backtest = Backtest {
+			sizing: Sizing::Fixed(2.),
+			..Backtest::default()
+		};
+		assert!(!backtest.validate());
+	}
+
+	#[test]
+	fn test_backtest_signal_rule() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let config: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(RSI::default());
+		let mut indicators = vec![config.init(&candles[0]).unwrap()];
+
+		let report = Backtest::default()
+			.run(&candles, &mut indicators, SignalRule::default())
+			.unwrap();
+
+		let signals = config.over(&candles).unwrap();
+		let buys = signals.iter().filter(|x| x.signal(0).analog() > 0).count();
+		assert!(!report.trades.is_empty());
+		assert!(report.trades.len() <= buys);
+
+		for pair in report.trades.windows(2) {
+			assert!(pair[0].exit_index <= pair[1].entry_index);
+		}
+
+		assert_eq!(
+			report.summary.trades,
+			report.summary.wins
+				+ report.summary.losses
+				+ report.trades.iter().filter(|x| x.profit() == 0.).count()
+		);
+	}
+}
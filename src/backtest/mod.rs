@@ -0,0 +1,40 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Backtesting of indicators signals.
+//!
+//! [`Backtest`] feeds a series of candles into one or more indicator instances, maps their results into trading actions
+//! with some [`Rule`] and returns a [`Report`] with the list of closed [`Trade`]s and their [`Summary`].
+//!
+//! Orders are filled according to the [`Fill`] model with [`Slippage`] and commission applied.
+//! Positions and equity are tracked by the [`Ledger`], which may also be used separately.
+//! Part of the equity invested into every position is calculated by the [`Sizing`] model.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::prelude::*;
+//! use yata::prelude::dd::*;
+//! use yata::backtest::{Backtest, SignalRule};
+//! use yata::helpers::RandomCandles;
+//! use yata::indicators::RSI;
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(200).collect();
+//! let rsi: Box<dyn IndicatorConfigDyn<_>> = Box::new(RSI::default());
+//! let mut indicators = vec![rsi.init(&candles[0]).unwrap()];
+//!
+//! // trade on the first signal of the first indicator
+//! let report = Backtest::default().run(&candles, &mut indicators, SignalRule::default()).unwrap();
+//!
+//! println!("{:?}", report.summary);
+//! ```
+
+mod engine;
+pub use engine::{Backtest, Rule, SignalRule};
+
+mod report;
+pub use report::{Report, Side, Summary, Trade};
+
+mod sizing;
+pub use sizing::Sizing;
+
+mod simulator;
+pub use simulator::{Fill, Ledger, Position, Slippage};
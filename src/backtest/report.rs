@@ -0,0 +1,183 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::ValueType;
+
+/// Side of the position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Side {
+	/// Long position: profits when price goes up
+	Long,
+
+	/// Short position: profits when price goes down
+	Short,
+}
+
+impl Side {
+	/// Returns `1` for [`Long`](Side::Long) and `-1` for [`Short`](Side::Short)
+	#[must_use]
+	pub const fn sign(self) -> i8 {
+		match self {
+			Self::Long => 1,
+			Self::Short => -1,
+		}
+	}
+
+	/// Returns the opposite side
+	#[must_use]
+	pub const fn opposite(self) -> Self {
+		match self {
+			Self::Long => Self::Short,
+			Self::Short => Self::Long,
+		}
+	}
+}
+
+/// Closed trade
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trade {
+	/// Side of the trade
+	pub side: Side,
+
+	/// Index of the candle the position was opened at
+	pub entry_index: usize,
+
+	/// Price the position was opened at
+	pub entry_price: ValueType,
+
+	/// Index of the candle the position was closed at
+	pub exit_index: usize,
+
+	/// Price the position was closed at
+	pub exit_price: ValueType,
+
+	/// Part of the equity allocated to the position
+	pub size: ValueType,
+
+	/// Relative commission paid on entry and on exit
+	pub commission: ValueType,
+}
+
+impl Trade {
+	/// Returns profit of the trade per unit of position size before paying commissions
+	#[must_use]
+	pub fn profit(&self) -> ValueType {
+		(self.exit_price - self.entry_price) * self.side.sign() as ValueType
+	}
+
+	/// Returns relative profit of the position after paying commissions
+	///
+	/// Relative change of the whole equity is `size` * `return_ratio`.
+	#[must_use]
+	pub fn return_ratio(&self) -> ValueType {
+		let keep = 1. - self.commission;
+
+		((1. + self.profit() / self.entry_price) * keep).mul_add(keep, -1.)
+	}
+
+	/// Returns count of bars the position was held
+	#[must_use]
+	pub const fn bars(&self) -> usize {
+		self.exit_index - self.entry_index
+	}
+}
+
+/// Summary statistics of the trades list
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Summary {
+	/// Count of trades
+	pub trades: usize,
+
+	/// Count of trades with positive profit
+	pub wins: usize,
+
+	/// Count of trades with negative profit
+	pub losses: usize,
+
+	/// Ratio of winning trades to all trades. `0.0` when there are no trades.
+	pub win_rate: ValueType,
+
+	/// Compounded relative return of all trades, weighted by their sizes
+	pub total_return: ValueType,
+
+	/// Average relative return of a trade. `0.0` when there are no trades.
+	pub average_return: ValueType,
+
+	/// Best relative return of a trade. `0.0` when there are no trades.
+	pub best_return: ValueType,
+
+	/// Worst relative return of a trade. `0.0` when there are no trades.
+	pub worst_return: ValueType,
+}
+
+impl Summary {
+	/// Calculates summary statistics of the trades list
+	#[must_use]
+	pub fn new(trades: &[Trade]) -> Self {
+		if trades.is_empty() {
+			return Self::default();
+		}
+
+		let mut summary = Self {
+			trades: trades.len(),
+			best_return: ValueType::NEG_INFINITY,
+			worst_return: ValueType::INFINITY,
+			..Self::default()
+		};
+
+		let mut equity = 1.;
+		let mut sum = 0.;
+
+		for trade in trades {
+			let ratio = trade.return_ratio();
+
+			summary.wins += (ratio > 0.) as usize;
+			summary.losses += (ratio < 0.) as usize;
+			summary.best_return = summary.best_return.max(ratio);
+			summary.worst_return = summary.worst_return.min(ratio);
+
+			equity *= trade.size.mul_add(ratio, 1.);
+			sum += ratio;
+		}
+
+		let count = trades.len() as ValueType;
+
+		summary.win_rate = summary.wins as ValueType / count;
+		summary.total_return = equity - 1.;
+		summary.average_return = sum / count;
+
+		summary
+	}
+}
+
+/// Result of the [`Backtest`](crate::backtest::Backtest)
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Report {
+	/// List of closed trades
+	pub trades: Vec<Trade>,
+
+	/// Relative equity at the end of every candle. Equity starts at `1.0`.
+	pub equity: Vec<ValueType>,
+
+	/// Summary statistics of the trades
+	pub summary: Summary,
+}
+
+impl Report {
+	/// Creates report from the list of closed trades and equity series
+	#[must_use]
+	pub fn new(trades: Vec<Trade>, equity: Vec<ValueType>) -> Self {
+		let summary = Summary::new(&trades);
+
+		Self {
+			trades,
+			equity,
+			summary,
+		}
+	}
+}
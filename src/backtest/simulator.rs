@@ -0,0 +1,294 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Side, Trade};
+use crate::core::{Error, ValueType, OHLCV};
+
+/// Order fill model of the [`Backtest`](crate::backtest::Backtest)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Fill {
+	/// Market order filled at the `close` value of the candle where the signal happened.
+	#[default]
+	Close,
+
+	/// Market order filled at the `open` value of the next candle.
+	NextOpen,
+
+	/// Limit order placed at the `close` value of the candle where the signal happened, shifted by the given relative offset
+	/// (down for buy orders and up for sell orders).
+	///
+	/// Order is filled at the first next candle which reaches the limit price: at the limit price or at the `open` value,
+	/// when candle opens beyond the limit price. Order stays active until it is filled or replaced by the next signal.
+	Limit(ValueType),
+}
+
+/// Slippage model of market orders of the [`Backtest`](crate::backtest::Backtest)
+///
+/// Buy orders are filled higher and sell orders are filled lower by the slippage value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Slippage {
+	/// Slippage relative to the fill price.
+	Relative(ValueType),
+
+	/// Slippage relative to the candle's range (`high` - `low`).
+	Range(ValueType),
+}
+
+impl Slippage {
+	/// Returns fill `price` of the market order of the given `side` on the `candle` with the slippage applied
+	pub fn apply<T: OHLCV>(self, side: Side, price: ValueType, candle: &T) -> ValueType {
+		let slippage = match self {
+			Self::Relative(ratio) => price * ratio,
+			Self::Range(ratio) => (candle.high() - candle.low()) * ratio,
+		};
+
+		slippage.mul_add(side.sign() as ValueType, price)
+	}
+
+	fn validate(self) -> bool {
+		match self {
+			Self::Relative(ratio) | Self::Range(ratio) => ratio >= 0. && ratio.is_finite(),
+		}
+	}
+}
+
+impl Default for Slippage {
+	fn default() -> Self {
+		Self::Relative(0.)
+	}
+}
+
+/// Open position of the [`Ledger`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Position {
+	/// Side of the position
+	pub side: Side,
+
+	/// Index of the candle the position was opened at
+	pub entry_index: usize,
+
+	/// Price the position was opened at
+	pub entry_price: ValueType,
+
+	/// Part of the equity allocated to the position at the time it was opened
+	pub size: ValueType,
+
+	/// Amount invested into the position after paying entry commission
+	pub invested: ValueType,
+}
+
+impl Position {
+	/// Returns current value of the position at the given `price`
+	#[must_use]
+	pub fn value(&self, price: ValueType) -> ValueType {
+		let change = (price - self.entry_price) / self.entry_price * self.side.sign() as ValueType;
+
+		self.invested * (1. + change)
+	}
+}
+
+/// Running position and profit and loss ledger
+///
+/// Positions are opened with the whole equity or with some part of it (see [`Sizing`](crate::backtest::Sizing)),
+/// the rest stays in cash. Relative `commission` is paid on every fill. Equity starts at `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ledger {
+	commission: ValueType,
+	balance: ValueType,
+	fees: ValueType,
+	position: Option<Position>,
+}
+
+impl Ledger {
+	/// Creates new ledger with the relative `commission` per fill
+	///
+	/// `commission` must be in range \[`0.0`; `1.0`\)
+	pub fn new(commission: ValueType) -> Result<Self, Error> {
+		if !(0. ..1.).contains(&commission) {
+			return Err(Error::WrongConfig);
+		}
+
+		Ok(Self {
+			commission,
+			balance: 1.,
+			fees: 0.,
+			position: None,
+		})
+	}
+
+	/// Returns current open position
+	#[must_use]
+	pub const fn position(&self) -> Option<Position> {
+		self.position
+	}
+
+	/// Returns cash which is not invested into the open position
+	#[must_use]
+	pub const fn balance(&self) -> ValueType {
+		self.balance
+	}
+
+	/// Returns total commission paid
+	#[must_use]
+	pub const fn fees(&self) -> ValueType {
+		self.fees
+	}
+
+	/// Returns current equity, when open position is valued at the given `price`
+	#[must_use]
+	pub fn equity(&self, price: ValueType) -> ValueType {
+		self.position.map_or(self.balance, |position| {
+			self.balance + position.value(price)
+		})
+	}
+
+	/// Opens new position with the whole equity at the given `price`. Currently open position is closed before at the same `price`.
+	pub fn open(&mut self, side: Side, index: usize, price: ValueType) -> Option<Trade> {
+		self.open_fraction(side, index, price, 1.)
+	}
+
+	/// Opens new position with the `fraction` of the equity at the given `price`. Currently open position is closed before at the same `price`.
+	///
+	/// `fraction` is clamped to \[`0.0`; `1.0`\].
+	pub fn open_fraction(
+		&mut self,
+		side: Side,
+		index: usize,
+		price: ValueType,
+		fraction: ValueType,
+	) -> Option<Trade> {
+		let trade = self.close(index, price);
+
+		let fraction = fraction.clamp(0., 1.);
+		let amount = self.balance * fraction;
+		let fee = amount * self.commission;
+		self.fees += fee;
+		self.balance -= amount;
+
+		self.position = Some(Position {
+			side,
+			entry_index: index,
+			entry_price: price,
+			size: fraction,
+			invested: amount - fee,
+		});
+
+		trade
+	}
+
+	/// Closes currently open position at the given `price`
+	pub fn close(&mut self, index: usize, price: ValueType) -> Option<Trade> {
+		let position = self.position.take()?;
+
+		let value = position.value(price);
+		let fee = value * self.commission;
+		self.fees += fee;
+		self.balance += value - fee;
+
+		Some(Trade {
+			side: position.side,
+			entry_index: position.entry_index,
+			entry_price: position.entry_price,
+			exit_index: index,
+			exit_price: price,
+			size: position.size,
+			commission: self.commission,
+		})
+	}
+}
+
+impl Default for Ledger {
+	fn default() -> Self {
+		Self::new(0.).unwrap()
+	}
+}
+
+pub(super) fn validate(fill: Fill, slippage: Slippage, commission: ValueType) -> bool {
+	let fill = match fill {
+		Fill::Limit(offset) => (0. ..1.).contains(&offset),
+		_ => true,
+	};
+
+	fill && slippage.validate() && (0. ..1.).contains(&commission)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Ledger, Slippage};
+	use crate::backtest::Side;
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_ledger() {
+		let mut ledger = Ledger::new(0.01).unwrap();
+		assert!(ledger.close(0, 10.).is_none());
+
+		assert!(ledger.open(Side::Long, 0, 10.).is_none());
+		assert_eq_float(0.99, ledger.equity(10.));
+		assert_eq_float(0.99 * 1.2, ledger.equity(12.));
+
+		let trade = ledger.open(Side::Short, 1, 12.).unwrap();
+		assert_eq_float(
+			ValueType::mul_add(0.99 * 1.2, 0.99, -1.),
+			trade.return_ratio(),
+		);
+		assert_eq_float(0.99 * 1.2 * 0.99 * 0.99, ledger.equity(12.));
+
+		let trade = ledger.close(2, 9.).unwrap();
+		assert_eq!(trade.side, Side::Short);
+		assert_eq_float(
+			ValueType::mul_add(1.25 * 0.99, 0.99, -1.),
+			trade.return_ratio(),
+		);
+		assert_eq_float(ValueType::powi(0.99, 4) * 1.2 * 1.25, ledger.equity(100.));
+		assert!(ledger.position().is_none());
+
+		assert!(Ledger::new(1.).is_err());
+	}
+
+	#[test]
+	fn test_ledger_fraction() {
+		let mut ledger = Ledger::new(0.01).unwrap();
+
+		assert!(ledger.open_fraction(Side::Long, 0, 10., 0.5).is_none());
+		assert_eq_float(0.5, ledger.balance());
+		assert_eq_float(ValueType::mul_add(0.495, 1.2, 0.5), ledger.equity(12.));
+
+		let trade = ledger.close(1, 12.).unwrap();
+		assert_eq_float(0.5, trade.size);
+		assert_eq_float(
+			ValueType::mul_add(0.5, trade.return_ratio(), 1.),
+			ledger.balance(),
+		);
+		assert_eq_float(ValueType::mul_add(0.495 * 1.2, 0.01, 0.005), ledger.fees());
+	}
+
+	#[test]
+	fn test_slippage() {
+		let candle = Candle {
+			open: 10.,
+			high: 12.,
+			low: 8.,
+			close: 11.,
+			volume: 1.,
+		};
+
+		assert_eq_float(
+			11.11,
+			Slippage::Relative(0.01).apply(Side::Long, 11., &candle),
+		);
+		assert_eq_float(
+			10.89,
+			Slippage::Relative(0.01).apply(Side::Short, 11., &candle),
+		);
+		assert_eq_float(11.4, Slippage::Range(0.1).apply(Side::Long, 11., &candle));
+		assert_eq_float(10.6, Slippage::Range(0.1).apply(Side::Short, 11., &candle));
+	}
+}
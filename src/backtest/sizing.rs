@@ -0,0 +1,156 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::ValueType;
+
+/// Position sizing model
+///
+/// Calculates part of the account equity to invest into the next position. The part is always clamped to \[`0.0`; `1.0`\].
+///
+/// # Examples
+///
+/// ```
+/// use yata::backtest::Sizing;
+///
+/// // risk 1% of equity when price moves against position by 2 ATR
+/// let sizing = Sizing::Volatility { risk: 0.01, multiplier: 2.0 };
+///
+/// // price is 100.0 and ATR is 2.5
+/// assert_eq!(sizing.fraction(100.0, 2.5), 0.2);
+///
+/// // account equity is 10000.0
+/// assert_eq!(sizing.size(10000.0, 100.0, 2.5), 20.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Sizing {
+	/// Fixed fractional sizing: invests the given part of equity into every position.
+	Fixed(ValueType),
+
+	/// Volatility based sizing: invests such part of equity, that the price movement against the position
+	/// by `multiplier` volatility values (e.g. `ATR`) loses `risk` part of equity.
+	Volatility {
+		/// Part of equity to risk. Range in \[`0.0`; `1.0`\]
+		risk: ValueType,
+
+		/// Count of volatility values to the protective stop. Range in \(`0.0`; `+inf`\)
+		multiplier: ValueType,
+	},
+
+	/// Kelly criterion: `win_rate` - (`1.0` - `win_rate`) / `payoff`, scaled by `scale` (e.g. `0.5` for half-Kelly).
+	///
+	/// Negative fraction (no edge) is clamped to `0.0`.
+	Kelly {
+		/// Ratio of winning trades. Range in \[`0.0`; `1.0`\]
+		win_rate: ValueType,
+
+		/// Ratio of the average win to the average loss. Range in \(`0.0`; `+inf`\)
+		payoff: ValueType,
+
+		/// Multiplier of the Kelly fraction. Range in \[`0.0`; `1.0`\]
+		scale: ValueType,
+	},
+}
+
+impl Sizing {
+	/// Returns part of the equity to invest into position at the `price`, when current `volatility` (e.g. `ATR`) is known
+	///
+	/// Range in \[`0.0`; `1.0`\]
+	#[must_use]
+	pub fn fraction(&self, price: ValueType, volatility: ValueType) -> ValueType {
+		let fraction = match *self {
+			Self::Fixed(fraction) => fraction,
+			Self::Volatility { risk, multiplier } => {
+				let stop = multiplier * volatility;
+
+				if stop > 0. {
+					risk * price / stop
+				} else {
+					1.
+				}
+			}
+			Self::Kelly {
+				win_rate,
+				payoff,
+				scale,
+			} => (win_rate - (1. - win_rate) / payoff) * scale,
+		};
+
+		fraction.clamp(0., 1.)
+	}
+
+	/// Returns position size in units of the asset for the account `equity`, `price` and current `volatility` (e.g. `ATR`)
+	#[must_use]
+	pub fn size(&self, equity: ValueType, price: ValueType, volatility: ValueType) -> ValueType {
+		equity * self.fraction(price, volatility) / price
+	}
+
+	/// Validates sizing parameters
+	#[must_use]
+	pub fn validate(&self) -> bool {
+		let unit = |x: ValueType| (0. ..=1.).contains(&x);
+
+		match *self {
+			Self::Fixed(fraction) => unit(fraction),
+			Self::Volatility { risk, multiplier } => unit(risk) && multiplier > 0.,
+			Self::Kelly {
+				win_rate,
+				payoff,
+				scale,
+			} => unit(win_rate) && payoff > 0. && unit(scale),
+		}
+	}
+}
+
+impl Default for Sizing {
+	fn default() -> Self {
+		Self::Fixed(1.)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Sizing;
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_sizing_fixed() {
+		let sizing = Sizing::Fixed(0.25);
+
+		assert_eq_float(0.25, sizing.fraction(10., 1.));
+		assert_eq_float(25., sizing.size(1000., 10., 1.));
+		assert!(!Sizing::Fixed(1.5).validate());
+	}
+
+	#[test]
+	fn test_sizing_volatility() {
+		let sizing = Sizing::Volatility {
+			risk: 0.02,
+			multiplier: 3.,
+		};
+
+		assert_eq_float(0.02 * 50. / 6., sizing.fraction(50., 2.));
+		assert_eq_float(1., sizing.fraction(50., 0.));
+		assert_eq_float(1., sizing.fraction(50., 0.1));
+	}
+
+	#[test]
+	fn test_sizing_kelly() {
+		let sizing = Sizing::Kelly {
+			win_rate: 0.6,
+			payoff: 2.,
+			scale: 0.5,
+		};
+
+		assert_eq_float(0.2, sizing.fraction(10., 1.));
+
+		let sizing = Sizing::Kelly {
+			win_rate: 0.3,
+			payoff: 1.,
+			scale: 1.,
+		};
+
+		assert_eq_float(0., sizing.fraction(10., 1.));
+	}
+}
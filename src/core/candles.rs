@@ -7,7 +7,7 @@ use std::str::FromStr;
 use crate::core::{Error, ValueType, OHLCV};
 
 /// Source enum represents common parts of a *Candle*
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Source {
@@ -23,10 +23,10 @@ pub enum Source {
 	/// *Low* part of a candle
 	Low,
 
-	/// (*High*+*Low*)/2 part of a candle
+	/// (*High*+*Low*)/2 part of a candle, also known as *median price*
 	HL2,
 
-	/// [Typical price](https://en.wikipedia.org/wiki/Typical_price) of a candle
+	/// [Typical price](https://en.wikipedia.org/wiki/Typical_price) (*High*+*Low*+*Close*)/3 of a candle
 	TP,
 
 	/// *Volume* part of a candle
@@ -35,6 +35,71 @@ pub enum Source {
 	/// Same as `typical price * volume`
 	#[cfg_attr(feature = "serde", serde(rename = "volumed_price"))]
 	VolumedPrice,
+
+	/// *Open interest* of a candle (see [`OHLCV::open_interest`])
+	#[cfg_attr(feature = "serde", serde(rename = "open_interest"))]
+	OpenInterest,
+
+	/// Weighted close (*High*+*Low*+2\**Close*)/4 of a candle
+	HLCC4,
+
+	/// (*Open*+*High*+*Low*+*Close*)/4 part of a candle
+	OHLC4,
+
+	/// Custom value of a candle, calculated by the user function
+	///
+	/// Any non-capturing closure may be used as the function. The function gets the candle itself,
+	/// so it can use any [`OHLCV`] data (e.g. [`buy_volume`](OHLCV::buy_volume) or [`open_interest`](OHLCV::open_interest)).
+	/// Custom source cannot be parsed from string and cannot be serialized.
+	///
+	/// Functions cannot be reliably compared, so all custom sources are equal to each other.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Source;
+	///
+	/// let body = Source::Custom(|candle| (candle.close() - candle.open()).abs());
+	/// let candle = Candle { open: 5.0, high: 7.0, low: 2.0, close: 3.0, volume: 1.0 };
+	///
+	/// assert_eq!(candle.source(body), 2.0);
+	/// ```
+	#[cfg_attr(feature = "serde", serde(skip))]
+	Custom(fn(&dyn OHLCV) -> ValueType),
+}
+
+impl Source {
+	/// Returns position of the variant to compare sources
+	const fn key(self) -> u8 {
+		match self {
+			Self::Close => 0,
+			Self::Open => 1,
+			Self::High => 2,
+			Self::Low => 3,
+			Self::HL2 => 4,
+			Self::TP => 5,
+			Self::Volume => 6,
+			Self::VolumedPrice => 7,
+			Self::OpenInterest => 8,
+			Self::HLCC4 => 9,
+			Self::OHLC4 => 10,
+			Self::Custom(_) => 11,
+		}
+	}
+}
+
+// functions addresses are not guaranteed to be unique nor stable, so custom sources are compared by the variant only
+impl PartialEq for Source {
+	fn eq(&self, other: &Self) -> bool {
+		self.key() == other.key()
+	}
+}
+
+impl Eq for Source {}
+
+impl PartialOrd for Source {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		self.key().partial_cmp(&other.key())
+	}
 }
 
 impl FromStr for Source {
@@ -47,9 +112,12 @@ impl FromStr for Source {
 			"low" => Ok(Self::Low),
 			"volume" => Ok(Self::Volume),
 			"tp" | "hlc3" => Ok(Self::TP),
-			"hl2" => Ok(Self::HL2),
+			"hl2" | "median" | "median_price" => Ok(Self::HL2),
+			"hlcc4" | "weighted_close" => Ok(Self::HLCC4),
+			"ohlc4" => Ok(Self::OHLC4),
 			"open" => Ok(Self::Open),
 			"volumed_price" => Ok(Self::VolumedPrice),
+			"open_interest" | "oi" => Ok(Self::OpenInterest),
 
 			value => Err(Error::SourceParse(value.to_string())),
 		}
@@ -83,6 +151,10 @@ impl From<Source> for &'static str {
 			Source::HL2 => "hl2",
 			Source::Volume => "volume",
 			Source::VolumedPrice => "volumed_price",
+			Source::OpenInterest => "open_interest",
+			Source::HLCC4 => "hlcc4",
+			Source::OHLC4 => "ohlc4",
+			Source::Custom(_) => "custom",
 		}
 	}
 }
@@ -212,9 +284,202 @@ impl PartialEq for Candle {
 
 impl Eq for Candle {}
 
+/// Candle of a derivative contract with open interest for implementing [`OHLCV`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{FuturesCandle, Source};
+///
+/// let candle = FuturesCandle {
+///     close: 10.0,
+///     open_interest: 1500.0,
+///     ..FuturesCandle::default()
+/// };
+///
+/// assert_eq!(candle.source(Source::OpenInterest), 1500.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuturesCandle {
+	/// *Open* value of the candle
+	pub open: ValueType,
+
+	/// *High* value of the candle
+	pub high: ValueType,
+
+	/// *Low* value of the candle
+	pub low: ValueType,
+
+	/// *Close* value of the candle
+	pub close: ValueType,
+
+	/// *Volume* value of the candle
+	pub volume: ValueType,
+
+	/// *Open interest* value at the end of the candle
+	pub open_interest: ValueType,
+}
+
+impl OHLCV for FuturesCandle {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.open
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.high
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.low
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.close
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.volume
+	}
+
+	#[inline]
+	fn open_interest(&self) -> ValueType {
+		self.open_interest
+	}
+}
+
+/// Candle with known buy/sell volume split for implementing [`OHLCV`]
+///
+/// Buy volume is the volume of trades initiated by buyers (aggressive buys), sell volume is the volume of trades initiated by sellers.
+/// Total `volume` is the sum of both.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::DeltaCandle;
+///
+/// // trades: price, volume and whether buyer was the aggressor
+/// let trades = [(10.0, 5.0, true), (12.0, 1.0, false), (9.0, 2.0, false), (11.0, 3.0, true)];
+/// let candle = DeltaCandle::from_trades(trades.iter().copied()).unwrap();
+///
+/// assert_eq!((candle.open(), candle.high(), candle.low(), candle.close()), (10.0, 12.0, 9.0, 11.0));
+/// assert_eq!(candle.volume(), 11.0);
+/// assert_eq!(candle.volume_delta(), 5.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeltaCandle {
+	/// *Open* value of the candle
+	pub open: ValueType,
+
+	/// *High* value of the candle
+	pub high: ValueType,
+
+	/// *Low* value of the candle
+	pub low: ValueType,
+
+	/// *Close* value of the candle
+	pub close: ValueType,
+
+	/// *Buy* volume of the candle
+	pub buy_volume: ValueType,
+
+	/// *Sell* volume of the candle
+	pub sell_volume: ValueType,
+}
+
+impl DeltaCandle {
+	/// Aggregates trades into a candle
+	///
+	/// Every trade is a tuple of price, volume and aggression flag, which is `true` when buyer was the aggressor.
+	/// Returns `None` when there are no trades.
+	pub fn from_trades<I>(trades: I) -> Option<Self>
+	where
+		I: IntoIterator<Item = (ValueType, ValueType, bool)>,
+	{
+		let mut trades = trades.into_iter();
+		let (price, volume, is_buy) = trades.next()?;
+
+		let mut candle = Self {
+			open: price,
+			high: price,
+			low: price,
+			close: price,
+			buy_volume: 0.,
+			sell_volume: 0.,
+		};
+		candle.push_trade(price, volume, is_buy);
+
+		for (price, volume, is_buy) in trades {
+			candle.push_trade(price, volume, is_buy);
+		}
+
+		Some(candle)
+	}
+
+	/// Adds the next trade into the candle
+	pub fn push_trade(&mut self, price: ValueType, volume: ValueType, is_buy: bool) {
+		self.high = self.high.max(price);
+		self.low = self.low.min(price);
+		self.close = price;
+
+		if is_buy {
+			self.buy_volume += volume;
+		} else {
+			self.sell_volume += volume;
+		}
+	}
+}
+
+impl OHLCV for DeltaCandle {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.open
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.high
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.low
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.close
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.buy_volume + self.sell_volume
+	}
+
+	#[inline]
+	fn buy_volume(&self) -> ValueType {
+		self.buy_volume
+	}
+
+	#[inline]
+	fn sell_volume(&self) -> ValueType {
+		self.sell_volume
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::Source;
+	use super::{FuturesCandle, Source};
+	use crate::core::OHLCV;
+	use crate::helpers::assert_eq_float;
 
 	#[test]
 	fn test_source_to_string_str() {
@@ -227,6 +492,10 @@ mod tests {
 			Source::VolumedPrice,
 			Source::TP,
 			Source::HL2,
+			Source::OpenInterest,
+			Source::HLCC4,
+			Source::OHLC4,
+			Source::Custom(|candle| candle.close()),
 		];
 
 		values.iter().for_each(|&v| {
@@ -244,6 +513,10 @@ mod tests {
 				Source::VolumedPrice => assert_eq!("volumed_price", r1),
 				Source::TP => assert_eq!("tp", r1),
 				Source::HL2 => assert_eq!("hl2", r1),
+				Source::OpenInterest => assert_eq!("open_interest", r1),
+				Source::HLCC4 => assert_eq!("hlcc4", r1),
+				Source::OHLC4 => assert_eq!("ohlc4", r1),
+				Source::Custom(_) => assert_eq!("custom", r1),
 			}
 		});
 	}
@@ -260,6 +533,12 @@ mod tests {
 			"tP",
 			"hlc3",
 			"Hl2",
+			"Open_Interest",
+			"OI",
+			"median_price",
+			"HLCC4",
+			"weighted_close",
+			"ohlc4",
 		];
 
 		values.iter().enumerate().for_each(|(i, s)| {
@@ -272,7 +551,10 @@ mod tests {
 				4 => assert_eq!(Source::Volume, r),
 				5 => assert_eq!(Source::VolumedPrice, r),
 				6 | 7 => assert_eq!(Source::TP, r),
-				8 => assert_eq!(Source::HL2, r),
+				8 | 11 => assert_eq!(Source::HL2, r),
+				9 | 10 => assert_eq!(Source::OpenInterest, r),
+				12 | 13 => assert_eq!(Source::HLCC4, r),
+				14 => assert_eq!(Source::OHLC4, r),
 				_ => panic!("Wow. You cannot be here."),
 			}
 		});
@@ -280,5 +562,24 @@ mod tests {
 		let src: Result<Source, _> = "some other string".parse();
 
 		assert!(src.is_err());
+		assert!("custom".parse::<Source>().is_err());
+	}
+
+	#[test]
+	fn test_source_custom() {
+		let candle = FuturesCandle {
+			close: 10.,
+			volume: 5.,
+			open_interest: 100.,
+			..FuturesCandle::default()
+		};
+
+		let oi_per_volume = Source::Custom(|candle| candle.open_interest() / candle.volume());
+		assert_eq_float(20., candle.source(oi_per_volume));
+		let candle: &dyn OHLCV = &candle;
+		assert_eq_float(20., candle.source(oi_per_volume));
+
+		assert_eq!(oi_per_volume, Source::Custom(|candle| candle.close()));
+		assert_ne!(oi_per_volume, Source::Close);
 	}
 }
@@ -3,13 +3,21 @@
 //! Every indicator **Configuration** must implement [`IndicatorConfig`].
 //!
 //! Every indicator **State** must implement [`IndicatorInstance`].
+//!
+//! Several indicators may be combined into a [`Strategy`], which is an indicator itself.
+//!
+//! [`Recorder`] logs candles fed to the indicator **State** to [replay](ReplayLog) them later.
 
 mod config;
 mod dd;
 mod instance;
+mod replay_log;
 mod result;
+mod strategy;
 
 pub use config::*;
 pub use dd::*;
 pub use instance::*;
+pub use replay_log::*;
 pub use result::*;
+pub use strategy::*;
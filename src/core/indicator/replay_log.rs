@@ -0,0 +1,219 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, OHLCV};
+
+/// Replay log of the indicator **State**
+///
+/// Replay log holds the indicator **Configuration** and every candle the **State** was fed with,
+/// so the same **State** is rebuilt by replaying these candles over a new **State**.
+/// With `serde` feature enabled replay log may be persisted for crash recovery.
+///
+/// Replay log is not a snapshot of the **State**: indicator **States** hold trait objects and other non-serializable data,
+/// so the **State** itself is never stored. Rebuilding the **State** takes time proportional to the count of logged candles
+/// and gives the same **State** only when the indicator results depend on nothing but the fed candles
+/// (see [`verify_replay`](crate::validation::verify_replay)).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReplayLog<C, T> {
+	/// Indicator **Configuration**
+	pub config: C,
+
+	/// Candle the **State** was initialized with
+	pub initial: T,
+
+	/// Candles the **State** was fed with
+	pub candles: Vec<T>,
+}
+
+/// Indicator **State**, which records fed candles into the [replay log](ReplayLog)
+///
+/// [`replay`](Recorder::replay) rebuilds the **State** from the log by feeding a new **State** with all the logged candles.
+/// Memory usage grows with every candle, so the log should be [trimmed](Recorder::rebase) from time to time.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Recorder;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// let mut recorder = Recorder::new(MACD::default(), &candles[0]).unwrap();
+/// candles[..50].iter().for_each(|candle| { recorder.next(candle); });
+///
+/// let log = recorder.log();
+/// let mut replayed = Recorder::replay(log).unwrap();
+///
+/// for candle in &candles[50..] {
+///     assert_eq!(recorder.next(candle).values(), replayed.next(candle).values());
+/// }
+/// ```
+pub struct Recorder<C: IndicatorConfig, T> {
+	instance: C::Instance,
+	log: ReplayLog<C, T>,
+	last: Option<IndicatorResult>,
+}
+
+impl<C, T> Recorder<C, T>
+where
+	C: IndicatorConfig,
+	T: OHLCV + Clone,
+{
+	/// Initializes the **State** of the `config` with the `initial` candle
+	pub fn new(config: C, initial: &T) -> Result<Self, Error> {
+		Ok(Self {
+			instance: config.clone().init(initial)?,
+			log: ReplayLog {
+				config,
+				initial: initial.clone(),
+				candles: Vec::new(),
+			},
+			last: None,
+		})
+	}
+
+	/// Evaluates the **State** over the `candle` and records it
+	pub fn next(&mut self, candle: &T) -> IndicatorResult {
+		self.log.candles.push(candle.clone());
+		let result = self.instance.next(candle);
+		self.last = Some(result);
+		result
+	}
+
+	/// Returns the indicator **State**
+	#[must_use]
+	pub const fn instance(&self) -> &C::Instance {
+		&self.instance
+	}
+
+	/// Returns the replay log of the current **State**
+	#[must_use]
+	pub fn log(&self) -> ReplayLog<C, T> {
+		self.log.clone()
+	}
+
+	/// Rebuilds the **State** by replaying the `log`
+	///
+	/// Returns an error when the log's config is invalid.
+	pub fn replay(log: ReplayLog<C, T>) -> Result<Self, Error> {
+		let mut instance = log.config.clone().init(&log.initial)?;
+		let last = log
+			.candles
+			.iter()
+			.map(|candle| instance.next(candle))
+			.last();
+
+		Ok(Self {
+			instance,
+			log,
+			last,
+		})
+	}
+
+	/// Drops the first `count` logged candles and takes the last dropped candle as the new initial one
+	///
+	/// The last logged candle is never dropped. The **State** is rebuilt from the trimmed log and replaces the current one,
+	/// so the **State** always matches the one [replayed](Recorder::replay) from the [log](Recorder::log).
+	///
+	/// Returns an error and keeps the log untouched, when the rebuilt **State** gives a result different from the recorded one
+	/// on the last logged candle, which means the indicator still depends on the dropped candles.
+	/// Only the last result is compared, so differences of the **State**, which do not affect this result, are not detected.
+	pub fn rebase(&mut self, count: usize) -> Result<(), Error> {
+		let count = count.min(self.log.candles.len().saturating_sub(1));
+
+		if count == 0 {
+			return Ok(());
+		}
+
+		let log = ReplayLog {
+			config: self.log.config.clone(),
+			initial: self.log.candles[count - 1].clone(),
+			candles: self.log.candles[count..].to_vec(),
+		};
+
+		let rebuilt = Self::replay(log)?;
+
+		match (rebuilt.last, self.last) {
+			(Some(a), Some(b)) if a.is_identical(&b) => {
+				*self = rebuilt;
+				Ok(())
+			}
+			(a, b) => Err(Error::Other(format!(
+				"Unable to rebase on {count} candles: rebuilt result {a:?} differs from recorded {b:?}"
+			))),
+		}
+	}
+}
+
+impl<C, T> fmt::Debug for Recorder<C, T>
+where
+	C: IndicatorConfig,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Recorder")
+			.field("instance", &self.instance)
+			.field("candles", &self.log.candles.len())
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Recorder;
+	use crate::core::Candle;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{DonchianChannel, MACD};
+	use crate::prelude::*;
+
+	#[test]
+	fn test_recorder_rebase() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+
+		// EMA depends on every candle it has ever seen
+		let mut recorder = Recorder::new(MACD::default(), &candles[0]).unwrap();
+		for candle in &candles {
+			recorder.next(candle);
+		}
+
+		assert!(recorder.rebase(50).is_err());
+		assert_eq!(recorder.log().candles.len(), candles.len());
+
+		// Donchian Channel depends on the last `period` candles only
+		let mut recorder = Recorder::new(DonchianChannel::default(), &candles[0]).unwrap();
+		for candle in &candles[..50] {
+			recorder.next(candle);
+		}
+
+		assert!(recorder.rebase(40).is_err());
+		recorder.rebase(20).unwrap();
+
+		let log = recorder.log();
+		assert_eq!(log.candles.len(), 30);
+		assert_eq_float(log.initial.close(), candles[19].close());
+
+		let mut replayed = Recorder::replay(log).unwrap();
+		for candle in &candles[50..] {
+			assert!(recorder.next(candle).is_identical(&replayed.next(candle)));
+		}
+	}
+
+	#[test]
+	fn test_recorder_rebase_keeps_last_candle() {
+		let candles: Vec<Candle> = RandomCandles::new().take(2).collect();
+
+		let mut recorder = Recorder::new(DonchianChannel::default(), &candles[0]).unwrap();
+		recorder.rebase(10).unwrap();
+		assert!(recorder.log().candles.is_empty());
+
+		recorder.next(&candles[1]);
+		recorder.rebase(10).unwrap();
+		assert_eq!(recorder.log().candles.len(), 1);
+	}
+}
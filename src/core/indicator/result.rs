@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// Every `Indicator` proceed an input of [`OHLCV`](crate::core::OHLCV) and returns an `IndicatorResult` which consist of some returned raw values and some calculated signals.
 ///
-/// `Indicator` may return up to 4 signals and 4 raw values at each step
+/// `Indicator` may return up to 16 signals and 16 raw values at each step
 #[derive(Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IndicatorResult {
@@ -18,7 +18,7 @@ pub struct IndicatorResult {
 impl IndicatorResult {
 	/// Size of pre-allocated result array
 	/// For the most of cases it should not be used anywhere outside this crate
-	pub const SIZE: usize = 4;
+	pub const SIZE: usize = 16;
 
 	/// Returns a slice of signals of current indicator result
 	#[must_use]
@@ -68,6 +68,18 @@ impl IndicatorResult {
 		self.signals[index]
 	}
 
+	/// Checks both results have the same raw values bit-for-bit and the same signals
+	#[must_use]
+	pub fn is_identical(&self, other: &Self) -> bool {
+		self.length == other.length
+			&& self
+				.values()
+				.iter()
+				.zip(other.values())
+				.all(|(a, b)| a.to_bits() == b.to_bits())
+			&& self.signals() == other.signals()
+	}
+
 	/// Creates a new instance of `IndicatorResult` with provided *values* and *signals*
 	#[inline]
 	#[must_use]
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, OHLCV};
+
+/// Set of indicators **Configurations** owned by a [`Strategy`]
+///
+/// It is implemented for tuples of up to 8 [`IndicatorConfig`]s.
+pub trait Indicators: Clone {
+	/// Set of indicators **States**
+	type Instances: IndicatorInstances;
+
+	/// Count of indicators in the set
+	const COUNT: usize;
+
+	/// Validates every indicator **Configuration** in the set
+	fn validate(&self) -> bool;
+
+	/// Returns [`size`](IndicatorConfig::size) of every indicator **Configuration** in the set in the same order
+	fn sizes(&self) -> Vec<(u8, u8)>;
+
+	/// Initializes every indicator **State** in the set
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instances, Error>;
+}
+
+/// Set of indicators **States** owned by a [`Strategy`] instance
+pub trait IndicatorInstances: fmt::Debug {
+	/// Evaluates every indicator **State** in the set and writes their results into `results` in the same order
+	fn next<T: OHLCV>(&mut self, candle: &T, results: &mut [IndicatorResult]);
+}
+
+macro_rules! impl_indicators {
+	($($config:ident $instance:ident $index:tt),+) => {
+		impl<$($config),+> Indicators for ($($config,)+)
+		where
+			$($config: IndicatorConfig, $config::Instance: fmt::Debug),+
+		{
+			type Instances = ($($config::Instance,)+);
+
+			const COUNT: usize = [$($index),+].len();
+
+			fn validate(&self) -> bool {
+				$(self.$index.validate())&&+
+			}
+
+			fn sizes(&self) -> Vec<(u8, u8)> {
+				vec![$(self.$index.size()),+]
+			}
+
+			fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instances, Error> {
+				Ok(($(self.$index.init(candle)?,)+))
+			}
+		}
+
+		impl<$($instance: IndicatorInstance + fmt::Debug),+> IndicatorInstances for ($($instance,)+) {
+			fn next<T: OHLCV>(&mut self, candle: &T, results: &mut [IndicatorResult]) {
+				$(results[$index] = self.$index.next(candle);)+
+			}
+		}
+	};
+}
+
+impl_indicators!(A IA 0);
+impl_indicators!(A IA 0, B IB 1);
+impl_indicators!(A IA 0, B IB 1, C IC 2);
+impl_indicators!(A IA 0, B IB 1, C IC 2, D ID 3);
+impl_indicators!(A IA 0, B IB 1, C IC 2, D ID 3, E IE 4);
+impl_indicators!(A IA 0, B IB 1, C IC 2, D ID 3, E IE 4, F IF 5);
+impl_indicators!(A IA 0, B IB 1, C IC 2, D ID 3, E IE 4, F IF 5, G IG 6);
+impl_indicators!(A IA 0, B IB 1, C IC 2, D ID 3, E IE 4, F IF 5, G IG 6, H IH 7);
+
+/// Composite trading strategy, which combines several indicators into a single action per candle
+///
+/// Every `Strategy` is an [`IndicatorConfig`] itself, so it may be initialized, evaluated over a series of candles,
+/// dynamically dispatched, serialized (when it derives `Serialize`/`Deserialize`) or put into [`Backtest`](crate::backtest::Backtest)
+/// just like any other indicator. Strategy's [`IndicatorResult`] has no values and a single signal, which is the combined action.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Action, IndicatorResult};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{MACD, RSI};
+///
+/// // buy when both MACD and RSI say to buy, sell when both say to sell
+/// #[derive(Debug, Clone, Default)]
+/// struct Agreement {
+///     macd: MACD,
+///     rsi: RSI,
+/// }
+///
+/// impl Strategy for Agreement {
+///     type Indicators = (MACD, RSI);
+///
+///     const NAME: &'static str = "Agreement";
+///
+///     fn indicators(&self) -> Self::Indicators {
+///         (self.macd, self.rsi)
+///     }
+///
+///     fn action(&self, results: &[IndicatorResult]) -> Action {
+///         match (results[0].signal(1).analog(), results[1].signal(0).analog()) {
+///             (1, 1) => Action::BUY_ALL,
+///             (-1, -1) => Action::SELL_ALL,
+///             _ => Action::None,
+///         }
+///     }
+/// }
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = Agreement::default().over(&candles).unwrap();
+///
+/// assert_eq!(results[0].size(), (0, 1));
+/// ```
+pub trait Strategy: Clone {
+	/// Set of indicators configurations the strategy is built of
+	type Indicators: Indicators;
+
+	/// Name of the strategy
+	const NAME: &'static str;
+
+	/// Returns indicators configurations for the strategy
+	fn indicators(&self) -> Self::Indicators;
+
+	/// Combines indicators results (in the same order as in [`Indicators`](Strategy::Indicators)) into a single action
+	fn action(&self, results: &[IndicatorResult]) -> Action;
+
+	/// Validates the strategy. By default validates every indicator.
+	fn validate(&self) -> bool {
+		self.indicators().validate()
+	}
+
+	/// Sets strategy parameters. By default there are no parameters.
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		Err(Error::ParameterParse(name.to_string(), value))
+	}
+}
+
+impl<S: Strategy> IndicatorConfig for S {
+	type Instance = StrategyInstance<S>;
+
+	const NAME: &'static str = S::NAME;
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !Strategy::validate(&self) {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			instances: cfg.indicators().init(candle)?,
+			results: vec![IndicatorResult::new(&[], &[]); S::Indicators::COUNT],
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		Strategy::validate(self)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		Strategy::set(self, name, value)
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(0, 1)
+	}
+}
+
+/// [`Strategy`] **State**
+#[derive(Debug)]
+pub struct StrategyInstance<S: Strategy> {
+	cfg: S,
+
+	instances: <S::Indicators as Indicators>::Instances,
+	results: Vec<IndicatorResult>,
+}
+
+impl<S: Strategy> IndicatorInstance for StrategyInstance<S> {
+	type Config = S;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.instances.next(candle, &mut self.results);
+
+		let action = self.cfg.action(&self.results);
+
+		IndicatorResult::new(&[], &[action])
+	}
+}
@@ -98,6 +98,27 @@ pub trait OHLCV {
 		(self.high() + self.low() + self.close() + self.open()) * 0.25
 	}
 
+	/// Calculates weighted close: arithmetic average of `high`, `low` and doubled `close` values of the candle
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Candle;
+	///
+	/// let candle = Candle {
+	///     high: 10.0,
+	///     low: 4.0,
+	///     close: 7.0,
+	///     ..Candle::default()
+	/// };
+	///
+	/// assert_eq!(candle.hlcc4(), 7.0);
+	/// ```
+	fn hlcc4(&self) -> ValueType {
+		self.close().mul_add(2., self.high() + self.low()) * 0.25
+	}
+
 	/// CLV = \[\(close - low\) - \(high - close\)\] / \(high - low\)
 	///
 	/// # Examples
@@ -262,6 +283,10 @@ pub trait OHLCV {
 			Source::Volume => self.volume(),
 			Source::VolumedPrice => self.volumed_price(),
 			Source::Open => self.open(),
+			Source::OpenInterest => self.open_interest(),
+			Source::HLCC4 => self.hlcc4(),
+			Source::OHLC4 => self.ohlc4(),
+			Source::Custom(f) => f(&self),
 		}
 	}
 
@@ -271,6 +296,59 @@ pub trait OHLCV {
 	fn volumed_price(&self) -> ValueType {
 		self.tp() * self.volume()
 	}
+
+	/// Should return *buy* volume (volume of the aggressive buyers) of the period
+	///
+	/// By default buy/sell volume split is unknown, so it is estimated by the close location value: `volume` * \(`1` + [`clv`](OHLCV::clv)\) / `2`.
+	/// Candles which carry real volume split (see [`DeltaCandle`](crate::core::DeltaCandle)) should override it together with [`sell_volume`](OHLCV::sell_volume).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Candle;
+	///
+	/// let candle = Candle {
+	///     high: 5.0,
+	///     low: 1.0,
+	///     close: 4.0,
+	///     volume: 100.0,
+	///     ..Candle::default()
+	/// };
+	///
+	/// assert_eq!(candle.buy_volume(), 75.0);
+	/// assert_eq!(candle.sell_volume(), 25.0);
+	/// assert_eq!(candle.volume_delta(), 50.0);
+	/// ```
+	#[inline]
+	fn buy_volume(&self) -> ValueType {
+		self.volume() * (1. + self.clv()) * 0.5
+	}
+
+	/// Should return *sell* volume (volume of the aggressive sellers) of the period
+	///
+	/// By default equals to `volume` - [`buy_volume`](OHLCV::buy_volume).
+	#[inline]
+	fn sell_volume(&self) -> ValueType {
+		self.volume() - self.buy_volume()
+	}
+
+	/// Volume delta
+	///
+	/// Same as [`OHLCV::buy_volume()`] - [`OHLCV::sell_volume()`]
+	#[inline]
+	fn volume_delta(&self) -> ValueType {
+		self.buy_volume() - self.sell_volume()
+	}
+
+	/// Should return *open interest* value at the end of the period
+	///
+	/// Open interest is known only for derivatives, so by default it is `NaN`.
+	/// Candles of derivatives (see [`FuturesCandle`](crate::core::FuturesCandle)) should override it.
+	#[inline]
+	fn open_interest(&self) -> ValueType {
+		ValueType::NAN
+	}
 }
 
 // impl<T: OHLCV + Copy> Sequence<T> {
@@ -336,7 +414,7 @@ impl OHLCV for [ValueType; 5] {
 	}
 }
 
-impl<T: OHLCV> OHLCV for &T {
+impl<T: OHLCV + ?Sized> OHLCV for &T {
 	#[inline]
 	fn open(&self) -> ValueType {
 		(**self).open()
@@ -361,4 +439,24 @@ impl<T: OHLCV> OHLCV for &T {
 	fn volume(&self) -> ValueType {
 		(**self).volume()
 	}
+
+	#[inline]
+	fn source(&self, source: Source) -> ValueType {
+		(**self).source(source)
+	}
+
+	#[inline]
+	fn buy_volume(&self) -> ValueType {
+		(**self).buy_volume()
+	}
+
+	#[inline]
+	fn sell_volume(&self) -> ValueType {
+		(**self).sell_volume()
+	}
+
+	#[inline]
+	fn open_interest(&self) -> ValueType {
+		(**self).open_interest()
+	}
 }
@@ -0,0 +1,236 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::json;
+use crate::core::{Candle, Error, IndicatorConfig, ValueType, OHLCV};
+
+/// Kind of the [`Series`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SeriesKind {
+	/// Raw value of the indicator
+	Value,
+
+	/// Signal of the indicator as a [ratio](crate::core::Action::ratio)
+	Signal,
+}
+
+impl SeriesKind {
+	const fn as_str(self) -> &'static str {
+		match self {
+			Self::Value => "value",
+			Self::Signal => "signal",
+		}
+	}
+}
+
+/// Labeled series of timestamped values
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Series {
+	/// Name of the series
+	pub name: String,
+
+	/// Kind of the series
+	pub kind: SeriesKind,
+
+	/// Timestamps and values
+	///
+	/// `NaN` values and candles without signal are skipped, so the series may be sparse.
+	pub points: Vec<(i64, ValueType)>,
+}
+
+/// Chart of candles and indicators series
+///
+/// Runs indicators over the candles and collects every raw value and every signal into a separate labeled [`Series`].
+/// Series are named `<name>.value<index>` and `<name>.signal<index>`, where `<name>` is the name given to the indicator.
+///
+/// [`to_json`](Chart::to_json) serializes the chart in the format, which is directly accepted by
+/// `lightweight-charts` (`time`-`open`-`high`-`low`-`close` candles and `time`-`value` points) and easily mapped to `Plotly` traces.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chart {
+	/// Timestamps of the candles
+	pub timestamps: Vec<i64>,
+
+	/// Candles
+	pub candles: Vec<Candle>,
+
+	/// Indicators series
+	pub series: Vec<Series>,
+}
+
+impl Chart {
+	/// Creates chart of `candles` with corresponding `timestamps`
+	///
+	/// Returns an error when count of `timestamps` differs from count of `candles`.
+	pub fn new<T: OHLCV>(candles: &[T], timestamps: &[i64]) -> Result<Self, Error> {
+		if candles.len() != timestamps.len() {
+			return Err(Error::Other(
+				"count of timestamps must be equal to count of candles".to_string(),
+			));
+		}
+
+		Ok(Self {
+			timestamps: timestamps.to_vec(),
+			candles: candles.iter().map(Candle::from).collect(),
+			series: Vec::new(),
+		})
+	}
+
+	/// Runs indicator over the candles and adds its values and signals series under the `name`
+	///
+	/// Returns an error when indicator config is invalid.
+	pub fn add<I: IndicatorConfig>(&mut self, name: &str, config: I) -> Result<&mut Self, Error> {
+		let (values, signals) = config.size();
+		let results = config.over(&self.candles)?;
+
+		let values = (0..values as usize).map(|index| Series {
+			name: format!("{name}.value{index}"),
+			kind: SeriesKind::Value,
+			points: self
+				.timestamps
+				.iter()
+				.zip(&results)
+				.map(|(&timestamp, result)| (timestamp, result.value(index)))
+				.filter(|(_, value)| !value.is_nan())
+				.collect(),
+		});
+
+		let signals = (0..signals as usize).map(|index| Series {
+			name: format!("{name}.signal{index}"),
+			kind: SeriesKind::Signal,
+			points: self
+				.timestamps
+				.iter()
+				.zip(&results)
+				.filter_map(|(&timestamp, result)| {
+					result.signal(index).ratio().map(|ratio| (timestamp, ratio))
+				})
+				.collect(),
+		});
+
+		let series: Vec<_> = values.chain(signals).collect();
+		self.series.extend(series);
+
+		Ok(self)
+	}
+
+	/// Returns series with the `name`
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&Series> {
+		self.series.iter().find(|series| series.name == name)
+	}
+
+	/// Serializes the chart to JSON
+	///
+	/// ```json
+	/// {
+	///   "candles": [{"time": 0, "open": 1.0, "high": 2.0, "low": 0.5, "close": 1.5, "volume": 10.0}, ...],
+	///   "series": [{"name": "rsi.value0", "kind": "value", "data": [{"time": 0, "value": 0.5}, ...]}, ...]
+	/// }
+	/// ```
+	///
+	/// Non-finite values are written as `null`.
+	#[must_use]
+	pub fn to_json(&self) -> String {
+		let mut output = String::from("{\"candles\":[");
+
+		for (i, (timestamp, candle)) in self.timestamps.iter().zip(&self.candles).enumerate() {
+			if i > 0 {
+				output.push(',');
+			}
+
+			output.push_str("{\"time\":");
+			output.push_str(&timestamp.to_string());
+			for (key, value) in [
+				("open", candle.open),
+				("high", candle.high),
+				("low", candle.low),
+				("close", candle.close),
+				("volume", candle.volume),
+			] {
+				output.push(',');
+				json::push_string(&mut output, key);
+				output.push(':');
+				json::push_number(&mut output, value);
+			}
+			output.push('}');
+		}
+
+		output.push_str("],\"series\":[");
+
+		for (i, series) in self.series.iter().enumerate() {
+			if i > 0 {
+				output.push(',');
+			}
+
+			output.push_str("{\"name\":");
+			json::push_string(&mut output, &series.name);
+			output.push_str(",\"kind\":");
+			json::push_string(&mut output, series.kind.as_str());
+			output.push_str(",\"data\":[");
+
+			for (j, &(timestamp, value)) in series.points.iter().enumerate() {
+				if j > 0 {
+					output.push(',');
+				}
+
+				output.push_str("{\"time\":");
+				output.push_str(&timestamp.to_string());
+				output.push_str(",\"value\":");
+				json::push_number(&mut output, value);
+				output.push('}');
+			}
+
+			output.push_str("]}");
+		}
+
+		output.push_str("]}");
+		output
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Chart, SeriesKind};
+	use crate::core::{Candle, ValueType};
+	use crate::indicators::Gaps;
+
+	#[test]
+	fn test_chart() {
+		let candles: Vec<_> = [100., 110., 111., 112.]
+			.iter()
+			.map(|&close: &ValueType| Candle {
+				open: close,
+				high: close + 1.,
+				low: close - 1.,
+				close,
+				volume: 1.,
+			})
+			.collect();
+
+		let mut chart = Chart::new(&candles, &[60, 120, 180, 240]).unwrap();
+		chart.add("gaps", Gaps::default()).unwrap();
+		assert_eq!(chart.series.len(), 7);
+
+		// nearest gap level above is NaN all the time
+		assert!(chart.get("gaps.value1").unwrap().points.is_empty());
+
+		let signal = chart.get("gaps.signal0").unwrap();
+		assert_eq!(signal.kind, SeriesKind::Signal);
+		assert_eq!(signal.points, vec![(120, 1.)]);
+
+		let json = chart.to_json();
+		assert!(json.starts_with(
+			"{\"candles\":[{\"time\":60,\"open\":100,\"high\":101,\"low\":99,\"close\":100,\"volume\":1},"
+		));
+		assert!(json.contains(
+			"{\"name\":\"gaps.signal0\",\"kind\":\"signal\",\"data\":[{\"time\":120,\"value\":1}]}"
+		));
+		assert!(json.ends_with("]}]}"));
+
+		assert!(Chart::new(&candles, &[60]).is_err());
+	}
+}
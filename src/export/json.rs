@@ -0,0 +1,53 @@
+use std::fmt::Write;
+
+use crate::core::ValueType;
+
+/// Writes number or `null` for non-finite values
+pub fn push_number(output: &mut String, value: ValueType) {
+	if value.is_finite() {
+		// `Display` of floats never uses exponent notation, so the output is a valid JSON number
+		write!(output, "{value}").unwrap();
+	} else {
+		output.push_str("null");
+	}
+}
+
+/// Writes quoted and escaped string
+pub fn push_string(output: &mut String, value: &str) {
+	output.push('"');
+
+	for c in value.chars() {
+		match c {
+			'"' => output.push_str("\\\""),
+			'\\' => output.push_str("\\\\"),
+			'\n' => output.push_str("\\n"),
+			'\r' => output.push_str("\\r"),
+			'\t' => output.push_str("\\t"),
+			c if c.is_control() => write!(output, "\\u{:04x}", c as u32).unwrap(),
+			c => output.push(c),
+		}
+	}
+
+	output.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{push_number, push_string};
+	use crate::core::ValueType;
+
+	#[test]
+	fn test_json() {
+		let mut output = String::new();
+		push_number(&mut output, 1e-20);
+		output.push(',');
+		push_number(&mut output, ValueType::NAN);
+		output.push(',');
+		push_string(&mut output, "a\"b\\c\n\u{1}");
+
+		assert_eq!(
+			output,
+			"0.00000000000000000001,null,\"a\\\"b\\\\c\\n\\u0001\""
+		);
+	}
+}
@@ -0,0 +1,32 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Export of indicators values for visualization.
+//!
+//! [`Chart`] collects candles and per-candle values and signals of indicators into labeled [`Series`] of `(timestamp, value)` points
+//! and serializes them to JSON, which is ready for charting libraries.
+//!
+//! With `serde` feature enabled, [`Chart`] may also be serialized by any other `serde` format.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::export::Chart;
+//! use yata::helpers::RandomCandles;
+//! use yata::indicators::{Trix, RSI};
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(100).collect();
+//! let timestamps: Vec<i64> = (0..100).map(|i| 1_600_000_000 + i * 60).collect();
+//!
+//! let mut chart = Chart::new(&candles, &timestamps).unwrap();
+//! chart.add("rsi", RSI::default()).unwrap().add("trix", Trix::default()).unwrap();
+//!
+//! let rsi = chart.get("rsi.value0").unwrap();
+//! assert_eq!(rsi.points.len(), 100);
+//!
+//! let json = chart.to_json();
+//! println!("{}", json);
+//! ```
+
+mod chart;
+pub use chart::{Chart, Series, SeriesKind};
+
+mod json;
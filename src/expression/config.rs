@@ -0,0 +1,370 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::parser::{parse, BinaryOp, CrossKind, Node, Output, UnaryOp};
+use super::Registry;
+use crate::core::{Action, Candle, Error, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorInstanceDyn, Method, ValueType, OHLCV};
+use crate::helpers::{method, signi, RegularMethod};
+use crate::methods::{Cross, CrossAbove, CrossUnder};
+
+/// Expression, which composes indicators and methods into a single indicator
+///
+/// Expression consists of:
+///
+/// * numbers: `21`, `0.25`;
+/// * candle [sources](crate::core::Source): `open`, `high`, `low`, `close`, `volume`, `hl2`, `tp`, ...;
+/// * regular [methods](crate::helpers::RegularMethods) calls over any series with a constant length: `ema(close, 21)`, `st_dev(close - open, 10)`;
+/// * crosses of two series: `cross(a, b)` (`1.0` on cross upwards, `-1.0` on cross downwards), `cross_above(a, b)` and `cross_under(a, b)`;
+/// * indicators calls from the [`Registry`] with constant positional or named arguments and an optional output name:
+///   `adx(14).adx`, `macd(12, 26, period3=9).signal`, `bb(20, sigma=2.5).lower`. Without output name the first value is used.
+///   Generic output names `value<index>` and `signal<index>` are also available;
+/// * arithmetic operators `+`, `-`, `*`, `/`;
+/// * comparison operators `<`, `<=`, `>`, `>=`, `==`, `!=`;
+/// * logical operators `&&`, `||`, `!`.
+///
+/// Comparison and logical operators return `1.0` for true and `0.0` for false. Any non-zero value except `NaN` is true.
+///
+/// Names are case insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::expression::Expression;
+/// use yata::helpers::RandomCandles;
+///
+/// let rule: Expression = "ema(close, 21) > ema(close, 55) && adx(14).adx > 0.25".parse().unwrap();
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = rule.over(&candles).unwrap();
+///
+/// assert!(results.iter().all(|result| result.value(0) == 0.0 || result.value(0) == 1.0));
+///
+/// let crossover: Expression = "cross(macd(12, 26, period3=9).macd, macd(12, 26, period3=9).signal)".parse().unwrap();
+/// let lower: Expression = "close < bb(20, sigma=2.5).lower".parse().unwrap();
+/// ```
+///
+/// # 1 value
+///
+/// * Value of the expression
+///
+/// # 1 signal
+///
+/// Returns full buy signal when value of the expression is positive (or true), full sell signal when value is negative.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone)]
+pub struct Expression {
+	text: String,
+	node: Node,
+}
+
+impl Expression {
+	/// Parses expression `text` with indicators from the `registry`
+	pub fn parse(text: &str, registry: &Registry) -> Result<Self, Error> {
+		Ok(Self {
+			text: text.trim().to_string(),
+			node: parse(text, registry)?,
+		})
+	}
+
+	/// Returns text of the expression
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		&self.text
+	}
+}
+
+impl FromStr for Expression {
+	type Err = Error;
+
+	/// Parses expression with indicators from the [default](Registry::default) registry
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse(s, &Registry::default())
+	}
+}
+
+impl fmt::Display for Expression {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.text)
+	}
+}
+
+impl IndicatorConfig for Expression {
+	type Instance = ExpressionInstance;
+
+	const NAME: &'static str = "Expression";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		let candle = Candle::from(candle);
+		let (state, _) = State::new(&self.node, &candle)?;
+
+		Ok(Self::Instance { cfg: self, state })
+	}
+
+	fn validate(&self) -> bool {
+		true
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		Err(Error::ParameterParse(name.to_string(), value))
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+type CrossMethod = Box<
+	dyn Method<'static, Params = (), Input = (ValueType, ValueType), Output = Action> + 'static,
+>;
+
+enum State {
+	Number(ValueType),
+	Source(crate::core::Source),
+	Unary(UnaryOp, Box<Self>),
+	Binary(BinaryOp, Box<Self>, Box<Self>),
+	Method(RegularMethod, Box<Self>),
+	Cross(CrossMethod, Box<Self>, Box<Self>),
+	Indicator(Box<dyn IndicatorInstanceDyn<Candle>>, Output),
+}
+
+impl State {
+	/// Creates state of the `node` together with its initial value
+	fn new(node: &Node, candle: &Candle) -> Result<(Self, ValueType), Error> {
+		let pair = match node {
+			Node::Number(value) => (Self::Number(*value), *value),
+			Node::Source(source) => (Self::Source(*source), candle.source(*source)),
+			Node::Unary(op, node) => {
+				let (state, value) = Self::new(node, candle)?;
+				(Self::Unary(*op, Box::new(state)), unary(*op, value))
+			}
+			Node::Binary(op, a, b) => {
+				let (a, a_value) = Self::new(a, candle)?;
+				let (b, b_value) = Self::new(b, candle)?;
+				let value = binary(*op, a_value, b_value);
+				(Self::Binary(*op, Box::new(a), Box::new(b)), value)
+			}
+			Node::Method(regular_method, length, node) => {
+				let (state, value) = Self::new(node, candle)?;
+				let instance = method(*regular_method, *length, value)?;
+				(Self::Method(instance, Box::new(state)), value)
+			}
+			Node::Cross(kind, a, b) => {
+				let (a, a_value) = Self::new(a, candle)?;
+				let (b, b_value) = Self::new(b, candle)?;
+				let input = (a_value, b_value);
+				let cross: CrossMethod = match kind {
+					CrossKind::Any => Box::new(Cross::new((), input)?),
+					CrossKind::Above => Box::new(CrossAbove::new((), input)?),
+					CrossKind::Under => Box::new(CrossUnder::new((), input)?),
+				};
+				(Self::Cross(cross, Box::new(a), Box::new(b)), 0.)
+			}
+			Node::Indicator {
+				entry,
+				params,
+				output,
+			} => {
+				let mut config = entry.config();
+				for (name, value) in params {
+					config.set(name, value.clone())?;
+				}
+
+				let value = output.get(&config.init(candle)?.next(candle));
+				(Self::Indicator(config.init(candle)?, *output), value)
+			}
+		};
+
+		Ok(pair)
+	}
+
+	fn next(&mut self, candle: &Candle) -> ValueType {
+		match self {
+			Self::Number(value) => *value,
+			Self::Source(source) => candle.source(*source),
+			Self::Unary(op, state) => unary(*op, state.next(candle)),
+			Self::Binary(op, a, b) => binary(*op, a.next(candle), b.next(candle)),
+			Self::Method(method, state) => method.next(state.next(candle)),
+			Self::Cross(cross, a, b) => {
+				cross.next((a.next(candle), b.next(candle))).analog().into()
+			}
+			Self::Indicator(instance, output) => output.get(&instance.next(candle)),
+		}
+	}
+}
+
+impl Output {
+	fn get(self, result: &IndicatorResult) -> ValueType {
+		match self {
+			Self::Value(index) => result.value(index),
+			Self::Signal(index) => result.signal(index).ratio().unwrap_or(0.),
+		}
+	}
+}
+
+fn is_true(value: ValueType) -> bool {
+	value != 0. && !value.is_nan()
+}
+
+fn boolean(value: bool) -> ValueType {
+	ValueType::from(u8::from(value))
+}
+
+fn unary(op: UnaryOp, value: ValueType) -> ValueType {
+	match op {
+		UnaryOp::Negate => -value,
+		UnaryOp::Not => boolean(!is_true(value)),
+	}
+}
+
+#[allow(clippy::float_cmp)]
+fn binary(op: BinaryOp, a: ValueType, b: ValueType) -> ValueType {
+	match op {
+		BinaryOp::Add => a + b,
+		BinaryOp::Sub => a - b,
+		BinaryOp::Mul => a * b,
+		BinaryOp::Div => a / b,
+		BinaryOp::Less => boolean(a < b),
+		BinaryOp::LessEqual => boolean(a <= b),
+		BinaryOp::Greater => boolean(a > b),
+		BinaryOp::GreaterEqual => boolean(a >= b),
+		BinaryOp::Equal => boolean(a == b),
+		BinaryOp::NotEqual => boolean(a != b),
+		BinaryOp::And => boolean(is_true(a) && is_true(b)),
+		BinaryOp::Or => boolean(is_true(a) || is_true(b)),
+	}
+}
+
+/// Instance of the [`Expression`]
+pub struct ExpressionInstance {
+	cfg: Expression,
+	state: State,
+}
+
+impl fmt::Debug for ExpressionInstance {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ExpressionInstance")
+			.field("cfg", &self.cfg)
+			.finish_non_exhaustive()
+	}
+}
+
+impl IndicatorInstance for ExpressionInstance {
+	type Config = Expression;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = self.state.next(&Candle::from(candle));
+
+		IndicatorResult::new(&[value], &[Action::from(signi(value))])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Expression;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::expression::Registry;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{AverageDirectionalIndex, RSI};
+
+	fn eval(text: &str, candles: &[Candle]) -> Vec<ValueType> {
+		let expression: Expression = text.parse().unwrap();
+
+		expression
+			.over(candles)
+			.unwrap()
+			.iter()
+			.map(|result| result.value(0))
+			.collect()
+	}
+
+	#[test]
+	fn test_expression_arithmetic() {
+		let candles: Vec<Candle> = (1..=4)
+			.map(|i| Candle {
+				open: i as ValueType,
+				high: i as ValueType + 2.,
+				low: i as ValueType - 1.,
+				close: i as ValueType + 1.,
+				volume: 10.,
+			})
+			.collect();
+
+		assert_eq!(eval("close - open * 2", &candles), vec![0., -1., -2., -3.]);
+		assert_eq!(eval("-(high - low) / 3 + 1", &candles), vec![0.; 4]);
+		assert_eq!(eval("sma(close, 2)", &candles), vec![2., 2.5, 3.5, 4.5]);
+		assert_eq!(eval("sma(close - open, 3) == 1", &candles), vec![1.; 4]);
+		assert_eq!(
+			eval("close > 3 && !(open >= 4) || volume < 0", &candles),
+			vec![0., 0., 1., 0.]
+		);
+		assert_eq!(
+			eval("cross_above(close, 3.5)", &candles),
+			vec![0., 0., 1., 0.]
+		);
+		assert_eq!(eval("cross(open, 2.5)", &candles), vec![0., 0., 1., 0.]);
+	}
+
+	#[test]
+	fn test_expression_indicators() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+
+		let rsi = RSI {
+			period: 10,
+			..RSI::default()
+		};
+		let expected: Vec<_> = rsi
+			.over(&candles)
+			.unwrap()
+			.iter()
+			.map(|result| result.value(0) * 2.)
+			.collect();
+		assert_eq!(eval("RSI(10) * 2", &candles), expected);
+
+		let adx = AverageDirectionalIndex {
+			di_length: 7,
+			adxr: true,
+			..AverageDirectionalIndex::default()
+		};
+		let expected: Vec<_> = adx
+			.over(&candles)
+			.unwrap()
+			.iter()
+			.map(|result| result.value(3))
+			.collect();
+		assert_eq!(eval("adx(7, adxr=true).adxr", &candles), expected);
+		assert_eq!(eval("adx(7, adxr=true).value3", &candles), expected);
+
+		let mut registry = Registry::new();
+		registry.add("strength", rsi, &[], &["main"]);
+		let expression = Expression::parse("strength().main > 0.5", &registry).unwrap();
+		let mut instance = expression.init(&candles[0]).unwrap();
+		let result = instance.next(&candles[0]);
+		let strength = rsi.over(&candles[..1]).unwrap()[0].value(0);
+		assert_eq_float(if strength > 0.5 { 1. } else { 0. }, result.value(0));
+
+		for text in &[
+			"",
+			"close >",
+			"foo(14)",
+			"bar",
+			"ema(close)",
+			"ema(close, 1.5)",
+			"rsi(close)",
+			"rsi(14, 15)",
+			"rsi(14).upper",
+			"adx(14).adxr",
+			"cross(close)",
+			"(close",
+			"close)",
+			"close # 1",
+		] {
+			assert!(text.parse::<Expression>().is_err(), "{}", text);
+		}
+	}
+}
@@ -0,0 +1,142 @@
+use crate::core::{Error, ValueType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+	Number(ValueType),
+	Ident(String),
+	LeftParen,
+	RightParen,
+	Comma,
+	Dot,
+	Assign,
+	Plus,
+	Minus,
+	Star,
+	Slash,
+	Not,
+	And,
+	Or,
+	Less,
+	LessEqual,
+	Greater,
+	GreaterEqual,
+	Equal,
+	NotEqual,
+}
+
+/// Splits `text` into tokens together with their positions
+pub fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, Error> {
+	let chars: Vec<char> = text.chars().collect();
+	let mut tokens = Vec::new();
+	let mut i = 0;
+
+	while i < chars.len() {
+		let c = chars[i];
+		let next = chars.get(i + 1).copied();
+		let start = i;
+
+		if c.is_whitespace() {
+			i += 1;
+			continue;
+		}
+
+		let token = if c.is_ascii_digit() {
+			while i < chars.len() && chars[i].is_ascii_digit() {
+				i += 1;
+			}
+
+			// dot is a part of the number only when followed by a digit
+			if i + 1 < chars.len() && chars[i] == '.' && chars[i + 1].is_ascii_digit() {
+				i += 1;
+				while i < chars.len() && chars[i].is_ascii_digit() {
+					i += 1;
+				}
+			}
+
+			let number: String = chars[start..i].iter().collect();
+			match number.parse() {
+				Ok(value) => Token::Number(value),
+				Err(_) => return Err(error(start, &format!("invalid number {number}"))),
+			}
+		} else if c.is_ascii_alphabetic() || c == '_' {
+			while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+				i += 1;
+			}
+
+			Token::Ident(
+				chars[start..i]
+					.iter()
+					.collect::<String>()
+					.to_ascii_lowercase(),
+			)
+		} else {
+			let (token, length) = match (c, next) {
+				('&', Some('&')) => (Token::And, 2),
+				('|', Some('|')) => (Token::Or, 2),
+				('<', Some('=')) => (Token::LessEqual, 2),
+				('>', Some('=')) => (Token::GreaterEqual, 2),
+				('=', Some('=')) => (Token::Equal, 2),
+				('!', Some('=')) => (Token::NotEqual, 2),
+				('(', _) => (Token::LeftParen, 1),
+				(')', _) => (Token::RightParen, 1),
+				(',', _) => (Token::Comma, 1),
+				('.', _) => (Token::Dot, 1),
+				('=', _) => (Token::Assign, 1),
+				('+', _) => (Token::Plus, 1),
+				('-', _) => (Token::Minus, 1),
+				('*', _) => (Token::Star, 1),
+				('/', _) => (Token::Slash, 1),
+				('!', _) => (Token::Not, 1),
+				('<', _) => (Token::Less, 1),
+				('>', _) => (Token::Greater, 1),
+				_ => return Err(error(start, &format!("unexpected character {c:?}"))),
+			};
+
+			i += length;
+			token
+		};
+
+		tokens.push((start, token));
+	}
+
+	Ok(tokens)
+}
+
+pub fn error(position: usize, message: &str) -> Error {
+	Error::Other(format!("{message} at position {position}"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{tokenize, Token};
+
+	#[test]
+	fn test_tokenize() {
+		let tokens: Vec<_> = tokenize("EMA(close,21) >= 1.5&&!x.y")
+			.unwrap()
+			.into_iter()
+			.map(|(_, token)| token)
+			.collect();
+
+		assert_eq!(
+			tokens,
+			vec![
+				Token::Ident("ema".to_string()),
+				Token::LeftParen,
+				Token::Ident("close".to_string()),
+				Token::Comma,
+				Token::Number(21.),
+				Token::RightParen,
+				Token::GreaterEqual,
+				Token::Number(1.5),
+				Token::And,
+				Token::Not,
+				Token::Ident("x".to_string()),
+				Token::Dot,
+				Token::Ident("y".to_string()),
+			]
+		);
+
+		assert!(tokenize("close > 1 & 2").is_err());
+	}
+}
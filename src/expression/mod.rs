@@ -0,0 +1,36 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Expressions for defining custom indicators and rules from text.
+//!
+//! [`Expression`] is parsed from text like `ema(close, 21) > ema(close, 55) && adx(14).adx > 0.25` and works as a regular
+//! [indicator](crate::core::IndicatorConfig), so it may be used anywhere indicators are used: in [backtests](crate::backtest),
+//! [optimization](crate::optimize), [charts](crate::export) and so on.
+//!
+//! Indicators are called by names from the [`Registry`]. Any other indicator may be registered under a custom name.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::prelude::*;
+//! use yata::expression::{Expression, Registry};
+//! use yata::helpers::RandomCandles;
+//! use yata::indicators::CommodityChannelIndex;
+//!
+//! let mut registry = Registry::default();
+//! registry.add("cci_indicator", CommodityChannelIndex::default(), &["period"], &["cci"]);
+//!
+//! let rule = Expression::parse("cci_indicator(20) > 0 && close > bb(20).middle", &registry).unwrap();
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(100).collect();
+//! let results = rule.clone().over(&candles).unwrap();
+//!
+//! println!("{}: {:?}", rule, results[99]);
+//! ```
+
+mod config;
+pub use config::{Expression, ExpressionInstance};
+
+mod lexer;
+mod parser;
+
+mod registry;
+pub use registry::{Entry, Registry};
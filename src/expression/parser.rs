@@ -0,0 +1,408 @@
+use std::convert::TryInto;
+use std::str::FromStr;
+
+use super::lexer::{error, tokenize, Token};
+use super::{Entry, Registry};
+use crate::core::{Error, PeriodType, Source, ValueType};
+use crate::helpers::RegularMethods;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+	Negate,
+	Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+	Add,
+	Sub,
+	Mul,
+	Div,
+	Less,
+	LessEqual,
+	Greater,
+	GreaterEqual,
+	Equal,
+	NotEqual,
+	And,
+	Or,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossKind {
+	Any,
+	Above,
+	Under,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+	Value(usize),
+	Signal(usize),
+}
+
+#[derive(Debug, Clone)]
+pub enum Node {
+	Number(ValueType),
+	Source(Source),
+	Unary(UnaryOp, Box<Self>),
+	Binary(BinaryOp, Box<Self>, Box<Self>),
+	Method(RegularMethods, PeriodType, Box<Self>),
+	Cross(CrossKind, Box<Self>, Box<Self>),
+	Indicator {
+		entry: Entry,
+		params: Vec<(String, String)>,
+		output: Output,
+	},
+}
+
+enum Arg {
+	Positional(usize, Node),
+	Named(String, String),
+}
+
+/// Recursive descent parser
+///
+/// ```text
+/// or      := and ("||" and)*
+/// and     := compare ("&&" compare)*
+/// compare := sum (("<" | "<=" | ">" | ">=" | "==" | "!=") sum)?
+/// sum     := product (("+" | "-") product)*
+/// product := unary (("*" | "/") unary)*
+/// unary   := ("-" | "!") unary | primary
+/// primary := number | ident | ident "(" args ")" ("." ident)? | "(" or ")"
+/// args    := (arg ("," arg)*)?
+/// arg     := ident "=" (number | ident) | or
+/// ```
+struct Parser<'a> {
+	tokens: Vec<(usize, Token)>,
+	position: usize,
+	end: usize,
+	registry: &'a Registry,
+}
+
+pub fn parse(text: &str, registry: &Registry) -> Result<Node, Error> {
+	let mut parser = Parser {
+		tokens: tokenize(text)?,
+		position: 0,
+		end: text.chars().count(),
+		registry,
+	};
+
+	let node = parser.or()?;
+
+	match parser.tokens.get(parser.position) {
+		None => Ok(node),
+		Some((position, token)) => Err(error(*position, &format!("unexpected {token:?}"))),
+	}
+}
+
+impl Parser<'_> {
+	fn peek(&self) -> Option<&Token> {
+		self.tokens.get(self.position).map(|(_, token)| token)
+	}
+
+	fn offset(&self) -> usize {
+		self.tokens
+			.get(self.position)
+			.map_or(self.end, |&(position, _)| position)
+	}
+
+	fn next(&mut self) -> Result<Token, Error> {
+		let offset = self.offset();
+		let token = self.peek().cloned();
+		self.position += 1;
+
+		token.ok_or_else(|| error(offset, "unexpected end of expression"))
+	}
+
+	fn expect(&mut self, expected: &Token) -> Result<(), Error> {
+		let offset = self.offset();
+		let token = self.next()?;
+
+		if &token == expected {
+			Ok(())
+		} else {
+			Err(error(
+				offset,
+				&format!("expected {expected:?}, found {token:?}"),
+			))
+		}
+	}
+
+	fn binary(
+		&mut self,
+		operand: fn(&mut Self) -> Result<Node, Error>,
+		operator: fn(&Token) -> Option<BinaryOp>,
+		chain: bool,
+	) -> Result<Node, Error> {
+		let mut node = operand(self)?;
+
+		while let Some(op) = self.peek().and_then(operator) {
+			self.position += 1;
+			node = Node::Binary(op, Box::new(node), Box::new(operand(self)?));
+
+			if !chain {
+				break;
+			}
+		}
+
+		Ok(node)
+	}
+
+	fn or(&mut self) -> Result<Node, Error> {
+		self.binary(
+			Self::and,
+			|token| (token == &Token::Or).then_some(BinaryOp::Or),
+			true,
+		)
+	}
+
+	fn and(&mut self) -> Result<Node, Error> {
+		self.binary(
+			Self::compare,
+			|token| (token == &Token::And).then_some(BinaryOp::And),
+			true,
+		)
+	}
+
+	fn compare(&mut self) -> Result<Node, Error> {
+		self.binary(
+			Self::sum,
+			|token| match token {
+				Token::Less => Some(BinaryOp::Less),
+				Token::LessEqual => Some(BinaryOp::LessEqual),
+				Token::Greater => Some(BinaryOp::Greater),
+				Token::GreaterEqual => Some(BinaryOp::GreaterEqual),
+				Token::Equal => Some(BinaryOp::Equal),
+				Token::NotEqual => Some(BinaryOp::NotEqual),
+				_ => None,
+			},
+			false,
+		)
+	}
+
+	fn sum(&mut self) -> Result<Node, Error> {
+		self.binary(
+			Self::product,
+			|token| match token {
+				Token::Plus => Some(BinaryOp::Add),
+				Token::Minus => Some(BinaryOp::Sub),
+				_ => None,
+			},
+			true,
+		)
+	}
+
+	fn product(&mut self) -> Result<Node, Error> {
+		self.binary(
+			Self::unary,
+			|token| match token {
+				Token::Star => Some(BinaryOp::Mul),
+				Token::Slash => Some(BinaryOp::Div),
+				_ => None,
+			},
+			true,
+		)
+	}
+
+	fn unary(&mut self) -> Result<Node, Error> {
+		let op = match self.peek() {
+			Some(Token::Minus) => UnaryOp::Negate,
+			Some(Token::Not) => UnaryOp::Not,
+			_ => return self.primary(),
+		};
+
+		self.position += 1;
+
+		Ok(Node::Unary(op, Box::new(self.unary()?)))
+	}
+
+	fn primary(&mut self) -> Result<Node, Error> {
+		let offset = self.offset();
+
+		match self.next()? {
+			Token::Number(value) => Ok(Node::Number(value)),
+			Token::LeftParen => {
+				let node = self.or()?;
+				self.expect(&Token::RightParen)?;
+				Ok(node)
+			}
+			Token::Ident(name) if self.peek() == Some(&Token::LeftParen) => {
+				self.position += 1;
+				let args = self.args()?;
+				self.call(offset, &name, args)
+			}
+			Token::Ident(name) => Source::from_str(&name)
+				.map(Node::Source)
+				.map_err(|_| error(offset, &format!("unknown source {name}"))),
+			token => Err(error(offset, &format!("unexpected {token:?}"))),
+		}
+	}
+
+	fn args(&mut self) -> Result<Vec<Arg>, Error> {
+		let mut args = Vec::new();
+
+		if self.peek() == Some(&Token::RightParen) {
+			self.position += 1;
+			return Ok(args);
+		}
+
+		loop {
+			let offset = self.offset();
+			let named = match (self.tokens.get(self.position), self.tokens.get(self.position + 1)) {
+				(Some((_, Token::Ident(name))), Some((_, Token::Assign))) => Some(name.clone()),
+				_ => None,
+			};
+
+			if let Some(name) = named {
+				self.position += 2;
+
+				let value_offset = self.offset();
+				let value = match self.next()? {
+					Token::Number(value) => value.to_string(),
+					Token::Ident(value) => value,
+					token => return Err(error(value_offset, &format!("unexpected {token:?}"))),
+				};
+
+				args.push(Arg::Named(name, value));
+			} else {
+				args.push(Arg::Positional(offset, self.or()?));
+			}
+
+			let offset = self.offset();
+			match self.next()? {
+				Token::Comma => {}
+				Token::RightParen => return Ok(args),
+				token => return Err(error(offset, &format!("unexpected {token:?}"))),
+			}
+		}
+	}
+
+	fn call(&mut self, offset: usize, name: &str, args: Vec<Arg>) -> Result<Node, Error> {
+		let cross = match name {
+			"cross" => Some(CrossKind::Any),
+			"cross_above" => Some(CrossKind::Above),
+			"cross_under" => Some(CrossKind::Under),
+			_ => None,
+		};
+
+		if let Some(kind) = cross {
+			return match positional::<2>(args) {
+				Some([(_, a), (_, b)]) => Ok(Node::Cross(kind, Box::new(a), Box::new(b))),
+				None => Err(error(offset, &format!("{name} expects 2 arguments"))),
+			};
+		}
+
+		if let Ok(method) = RegularMethods::from_str(name) {
+			return match positional::<2>(args) {
+				Some([(_, node), (length_offset, Node::Number(length))]) => {
+					let length = constant(length_offset, length)?;
+					Ok(Node::Method(method, length, Box::new(node)))
+				}
+				_ => Err(error(
+					offset,
+					&format!("{name} expects a series and a constant length"),
+				)),
+			};
+		}
+
+		let registry = self.registry;
+		let entry = registry
+			.get(name)
+			.ok_or_else(|| error(offset, &format!("unknown function {name}")))?;
+
+		let mut params = Vec::with_capacity(args.len());
+		let mut positional_params = entry.params.iter();
+		for arg in args {
+			match arg {
+				Arg::Named(param, value) => params.push((param, value)),
+				Arg::Positional(arg_offset, node) => {
+					let param = positional_params.next().ok_or_else(|| {
+						error(arg_offset, &format!("too many arguments of {name}"))
+					})?;
+
+					match node {
+						Node::Number(value) => params.push((param.clone(), value.to_string())),
+						_ => return Err(error(arg_offset, "indicator arguments must be constant")),
+					}
+				}
+			}
+		}
+
+		let output = self.output(entry)?;
+
+		// validates indicator parameters and output beforehand
+		let mut config = entry.config();
+		for (param, value) in &params {
+			config
+				.set(param, value.clone())
+				.map_err(|e| error(offset, &e.to_string()))?;
+		}
+
+		let (values, signals) = config.size();
+		let is_valid = match output {
+			Output::Value(index) => index < values as usize,
+			Output::Signal(index) => index < signals as usize,
+		};
+
+		if !config.validate() || !is_valid {
+			return Err(error(
+				offset,
+				&format!("invalid config or output of {name}"),
+			));
+		}
+
+		Ok(Node::Indicator {
+			entry: entry.clone(),
+			params,
+			output,
+		})
+	}
+
+	fn output(&mut self, entry: &Entry) -> Result<Output, Error> {
+		if self.peek() != Some(&Token::Dot) {
+			return Ok(Output::Value(0));
+		}
+
+		let offset = self.offset() + 1;
+		let name = match self.tokens.get(self.position + 1) {
+			Some((_, Token::Ident(name))) => name.clone(),
+			_ => return Err(error(offset, "expected output name")),
+		};
+		self.position += 2;
+
+		let output = if let Some(index) = entry.values.iter().position(|value| value == &name) {
+			Output::Value(index)
+		} else if let Some(index) = name.strip_prefix("value").and_then(|i| i.parse().ok()) {
+			Output::Value(index)
+		} else if let Some(index) = name.strip_prefix("signal").and_then(|i| i.parse().ok()) {
+			Output::Signal(index)
+		} else {
+			return Err(error(offset, &format!("unknown output {name}")));
+		};
+
+		Ok(output)
+	}
+}
+
+fn positional<const N: usize>(args: Vec<Arg>) -> Option<[(usize, Node); N]> {
+	let nodes: Vec<_> = args
+		.into_iter()
+		.map(|arg| match arg {
+			Arg::Positional(offset, node) => Some((offset, node)),
+			Arg::Named(..) => None,
+		})
+		.collect::<Option<_>>()?;
+
+	nodes.try_into().ok()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn constant(offset: usize, value: ValueType) -> Result<PeriodType, Error> {
+	if value.fract() == 0. && value >= 0. && value <= PeriodType::MAX as ValueType {
+		Ok(value as PeriodType)
+	} else {
+		Err(error(offset, &format!("invalid length {value}")))
+	}
+}
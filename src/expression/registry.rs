@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::core::{Candle, IndicatorConfig, IndicatorConfigDyn, IndicatorInstanceDyn};
+use crate::indicators::{
+	Aroon, AverageDirectionalIndex, BollingerBands, ChaikinMoneyFlow, KeltnerChannel,
+	MoneyFlowIndex, ParabolicSAR, StochasticOscillator, Trix, MACD, RSI,
+};
+
+type Factory = dyn Fn() -> Box<dyn IndicatorConfigDyn<Candle>> + Send + Sync;
+
+/// Indicator available in expressions
+#[derive(Clone)]
+pub struct Entry {
+	/// Parameters names for positional arguments
+	pub params: Vec<String>,
+
+	/// Names of the indicator values
+	pub values: Vec<String>,
+
+	factory: Arc<Factory>,
+}
+
+impl Entry {
+	/// Returns new instance of the indicator config with default parameters
+	#[must_use]
+	pub fn config(&self) -> Box<dyn IndicatorConfigDyn<Candle>> {
+		(self.factory)()
+	}
+}
+
+impl fmt::Debug for Entry {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Entry")
+			.field("name", &self.config().name())
+			.field("params", &self.params)
+			.field("values", &self.values)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Registry of indicators, which may be called in [`Expression`](super::Expression)s by names
+///
+/// Default registry contains:
+///
+/// * `rsi(period)`: `rsi`
+/// * `adx(di_length, adx_smoothing)`: `adx`, `plus_di`, `minus_di`, `adxr` (only when `adxr` parameter is `true`)
+/// * `macd(period1, period2, period3)`: `macd`, `signal`
+/// * `bb(avg_size, sigma)`: `upper`, `middle`, `lower`
+/// * `kc(period, sigma)`: `source`, `upper`, `lower`
+/// * `stoch(period, smooth_k, smooth_d)`: `k`, `d`
+/// * `aroon(period)`: `up`, `down`
+/// * `mfi(period)`: `upper`, `mfi`, `lower`
+/// * `sar(af_step, af_max)`: `sar`, `trend`
+/// * `trix(period1, period2)`: `trix`, `signal`
+/// * `cmf(size)`: `cmf`
+///
+/// Names of regular methods (see [`RegularMethods`](crate::helpers::RegularMethods)) are reserved for methods calls.
+#[derive(Debug, Clone)]
+pub struct Registry {
+	entries: HashMap<String, Entry>,
+}
+
+impl Registry {
+	/// Creates empty registry
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			entries: HashMap::new(),
+		}
+	}
+
+	/// Registers indicator `config` under the `name`
+	///
+	/// `params` are names of the indicator parameters, which are set by positional arguments.
+	/// `values` are names of the indicator values, which may be accessed by `name(...).value_name`.
+	///
+	/// Registering indicator under existing name replaces the previous one.
+	pub fn add<I>(&mut self, name: &str, config: I, params: &[&str], values: &[&str]) -> &mut Self
+	where
+		I: IndicatorConfig + Send + Sync + 'static,
+		I::Instance: IndicatorInstanceDyn<Candle> + 'static,
+	{
+		let entry = Entry {
+			params: params.iter().map(ToString::to_string).collect(),
+			values: values.iter().map(ToString::to_string).collect(),
+			factory: Arc::new(move || Box::new(config.clone())),
+		};
+
+		self.entries.insert(name.to_ascii_lowercase(), entry);
+
+		self
+	}
+
+	/// Returns indicator with the `name`
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&Entry> {
+		self.entries.get(&name.to_ascii_lowercase())
+	}
+}
+
+impl Default for Registry {
+	fn default() -> Self {
+		let mut registry = Self::new();
+
+		registry
+			.add("rsi", RSI::default(), &["period"], &["rsi"])
+			.add(
+				"adx",
+				AverageDirectionalIndex::default(),
+				&["di_length", "adx_smoothing"],
+				&["adx", "plus_di", "minus_di", "adxr"],
+			)
+			.add(
+				"macd",
+				MACD::default(),
+				&["period1", "period2", "period3"],
+				&["macd", "signal"],
+			)
+			.add(
+				"bb",
+				BollingerBands::default(),
+				&["avg_size", "sigma"],
+				&["upper", "middle", "lower"],
+			)
+			.add(
+				"kc",
+				KeltnerChannel::default(),
+				&["period", "sigma"],
+				&["source", "upper", "lower"],
+			)
+			.add(
+				"stoch",
+				StochasticOscillator::default(),
+				&["period", "smooth_k", "smooth_d"],
+				&["k", "d"],
+			)
+			.add("aroon", Aroon::default(), &["period"], &["up", "down"])
+			.add(
+				"mfi",
+				MoneyFlowIndex::default(),
+				&["period"],
+				&["upper", "mfi", "lower"],
+			)
+			.add(
+				"sar",
+				ParabolicSAR::default(),
+				&["af_step", "af_max"],
+				&["sar", "trend"],
+			)
+			.add(
+				"trix",
+				Trix::default(),
+				&["period1", "period2"],
+				&["trix", "signal"],
+			)
+			.add("cmf", ChaikinMoneyFlow::default(), &["size"], &["cmf"]);
+
+		registry
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::core::{Candle, IndicatorConfig, ValueType};
+	use crate::expression::Expression;
+	use crate::helpers::RandomCandles;
+
+	fn eval(text: &str, candles: &[Candle]) -> Vec<ValueType> {
+		let expression: Expression = text.parse().unwrap();
+
+		expression
+			.over(candles)
+			.unwrap()
+			.iter()
+			.map(|result| result.value(0))
+			.collect()
+	}
+
+	fn is_true(text: &str, candles: &[Candle]) -> bool {
+		eval(text, candles).iter().skip(30).all(|&x| x > 0.)
+	}
+
+	// total variation of the series, which is lower for the smoothed lines
+	fn variation(text: &str, candles: &[Candle]) -> ValueType {
+		eval(text, candles)[30..]
+			.windows(2)
+			.map(|pair| (pair[1] - pair[0]).abs())
+			.sum()
+	}
+
+	#[test]
+	fn test_registry_values_order() {
+		let random: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let rising: Vec<Candle> = (0..100)
+			.map(|i| {
+				let close = 100. + i as ValueType + (i % 3) as ValueType;
+				Candle {
+					open: close - 1.,
+					high: close + 1.,
+					low: close - 2.,
+					close,
+					volume: 1.,
+				}
+			})
+			.collect();
+
+		assert!(is_true("kc(20).source == close", &random));
+		assert!(is_true("kc(20).upper > kc(20).lower", &random));
+		assert!(is_true(
+			"bb(20).upper >= bb(20).middle && bb(20).middle >= bb(20).lower",
+			&random
+		));
+		assert!(is_true("mfi(14).upper > mfi(14).lower", &random));
+		assert!(variation("mfi(14).upper", &random) < variation("mfi(14).mfi", &random));
+		assert!(is_true("aroon(14).up > aroon(14).down", &rising));
+		assert!(is_true("adx(14).plus_di > adx(14).minus_di", &rising));
+		assert!(is_true("adx(14, adxr=true).adxr > 0", &rising));
+		assert!(is_true(
+			"sar(0.02, 0.2).sar < low && sar(0.02, 0.2).trend > 0",
+			&rising
+		));
+		assert!(is_true("rsi(14).rsi > 0.5 && cmf(20).cmf != 0", &rising));
+
+		for (main, signal) in &[
+			("macd(12, 26, 9).macd", "macd(12, 26, 9).signal"),
+			("stoch(14, 3, 3).k", "stoch(14, 3, 3).d"),
+			("trix(18, 6).trix", "trix(18, 6).signal"),
+		] {
+			assert!(variation(signal, &random) < variation(main, &random));
+		}
+	}
+}
@@ -0,0 +1,145 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Calendar date and time in UTC of the Unix timestamp
+///
+/// Timestamps are counted in seconds since `1970-01-01 00:00:00 UTC`. Leap seconds are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::DateTime;
+///
+/// let datetime = DateTime::from_timestamp(951_782_400 + 3600 * 13 + 61);
+///
+/// assert_eq!((datetime.year, datetime.month, datetime.day), (2000, 2, 29));
+/// assert_eq!((datetime.hour, datetime.minute, datetime.second), (13, 1, 1));
+/// // Tuesday
+/// assert_eq!(datetime.weekday, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DateTime {
+	/// Year
+	pub year: i32,
+
+	/// Month in range \[`1`; `12`\]
+	pub month: u8,
+
+	/// Day of month in range \[`1`; `31`\]
+	pub day: u8,
+
+	/// Hour in range \[`0`; `23`\]
+	pub hour: u8,
+
+	/// Minute in range \[`0`; `59`\]
+	pub minute: u8,
+
+	/// Second in range \[`0`; `59`\]
+	pub second: u8,
+
+	/// Day of week in range \[`0`; `6`\], where `0` is Monday and `6` is Sunday
+	pub weekday: u8,
+}
+
+impl DateTime {
+	/// Count of seconds in a day
+	pub const DAY: i64 = 86_400;
+
+	/// Converts Unix `timestamp` in seconds into calendar date and time
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	pub const fn from_timestamp(timestamp: i64) -> Self {
+		let days = timestamp.div_euclid(Self::DAY);
+		let time = timestamp.rem_euclid(Self::DAY);
+
+		// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+		let z = days + 719_468;
+		let era = z.div_euclid(146_097);
+		let doe = z.rem_euclid(146_097);
+		let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+		let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+		let mp = (5 * doy + 2) / 153;
+		let day = doy - (153 * mp + 2) / 5 + 1;
+		let month = if mp < 10 { mp + 3 } else { mp - 9 };
+		let year = yoe + era * 400 + (month <= 2) as i64;
+
+		Self {
+			year: year as i32,
+			month: month as u8,
+			day: day as u8,
+			hour: (time / 3600) as u8,
+			minute: (time % 3600 / 60) as u8,
+			second: (time % 60) as u8,
+			// 1970-01-01 is Thursday
+			weekday: (days + 3).rem_euclid(7) as u8,
+		}
+	}
+
+	/// Converts calendar date and time into Unix timestamp in seconds
+	///
+	/// `weekday` is ignored.
+	#[must_use]
+	pub const fn timestamp(&self) -> i64 {
+		// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+		let month = self.month as i64;
+		let year = self.year as i64 - (month <= 2) as i64;
+		let era = year.div_euclid(400);
+		let yoe = year.rem_euclid(400);
+		let doy = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+		let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+		let days = era * 146_097 + doe - 719_468;
+
+		days * Self::DAY + self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::DateTime;
+
+	#[test]
+	fn test_datetime() {
+		let epoch = DateTime::from_timestamp(0);
+		assert_eq!(
+			(epoch.year, epoch.month, epoch.day, epoch.weekday),
+			(1970, 1, 1, 3)
+		);
+
+		let before = DateTime::from_timestamp(-1);
+		assert_eq!((before.year, before.month, before.day), (1969, 12, 31));
+		assert_eq!((before.hour, before.minute, before.second), (23, 59, 59));
+		assert_eq!(before.weekday, 2);
+
+		// 2021-03-01 00:00:00, Monday
+		let datetime = DateTime::from_timestamp(1_614_556_800);
+		assert_eq!(
+			(
+				datetime.year,
+				datetime.month,
+				datetime.day,
+				datetime.weekday
+			),
+			(2021, 3, 1, 0)
+		);
+
+		// 2024-12-31 23:00:00, Tuesday
+		let datetime = DateTime::from_timestamp(1_735_686_000);
+		assert_eq!(
+			(datetime.year, datetime.month, datetime.day, datetime.hour),
+			(2024, 12, 31, 23)
+		);
+		assert_eq!(datetime.weekday, 1);
+
+		for &timestamp in &[
+			-1,
+			0,
+			951_782_400,
+			1_614_556_800,
+			1_735_686_000,
+			-5_000_000_000,
+		] {
+			assert_eq!(DateTime::from_timestamp(timestamp).timestamp(), timestamp);
+		}
+	}
+}
@@ -10,6 +10,16 @@ use crate::methods::{
 
 use std::convert::TryFrom;
 use std::str::FromStr;
+use std::sync::{PoisonError, RwLock};
+
+/// Constructor of the [custom regular method](register_method)
+pub type MethodConstructor = fn(PeriodType, ValueType) -> Result<RegularMethod, Error>;
+
+/// Name of the [custom regular method](register_method)
+pub type CustomMethodName = &'static str;
+
+static CUSTOM_METHODS: RwLock<Vec<(&'static str, MethodConstructor)>> = RwLock::new(Vec::new());
+
 /// A shortcut for dynamically (runtime) generated regular methods
 ///
 /// Regular method is a method which has parameters of single [`PeriodType`], input is single [`ValueType`] and output is single [`ValueType`].
@@ -125,44 +135,67 @@ pub enum RegularMethods {
 	/// [HighestLowestDelta](crate::methods::HighestLowestDelta)
 	#[cfg_attr(feature = "serde", serde(rename = "highest_lowest_delta"))]
 	HighestLowestDelta,
+
+	/// Custom method [registered](register_method) under the name
+	#[cfg_attr(
+		feature = "serde",
+		serde(
+			serialize_with = "serialize_custom",
+			deserialize_with = "deserialize_custom"
+		)
+	)]
+	Custom(CustomMethodName),
+}
+
+impl RegularMethods {
+	fn builtin(name: &str) -> Option<Self> {
+		let method = match name {
+			"sma" => Self::SMA,
+			"wma" => Self::WMA,
+			"hma" => Self::HMA,
+			"rma" => Self::RMA,
+			"ema" => Self::EMA,
+			"dma" => Self::DMA,
+			"dema" => Self::DEMA,
+			"tma" => Self::TMA,
+			"tema" => Self::TEMA,
+			"wsma" => Self::WSMA,
+			"smm" => Self::SMM,
+			"swma" => Self::SWMA,
+			"trima" => Self::TRIMA,
+			"lin_reg" | "linreg" => Self::LinReg,
+			"vidya" => Self::Vidya,
+
+			"past" | "move" => Self::Past,
+			"derivative" => Self::Derivative,
+			"integral" => Self::Integral,
+			"mean_abs_dev" => Self::MeanAbsDev,
+			"median_abs_dev" => Self::MedianAbsDev,
+			"st_dev" | "stdev" => Self::StDev,
+			"cci" => Self::CCI,
+			"momentum" | "change" => Self::Momentum,
+			"rate_of_change" | "rateofchange" | "roc" => Self::RateOfChange,
+			"highest" => Self::Highest,
+			"lowest" => Self::Lowest,
+			"highest_lowest_delta" => Self::HighestLowestDelta,
+
+			_ => return None,
+		};
+
+		Some(method)
+	}
 }
 
 impl FromStr for RegularMethods {
 	type Err = String;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match s.to_ascii_lowercase().trim() {
-			"sma" => Ok(Self::SMA),
-			"wma" => Ok(Self::WMA),
-			"hma" => Ok(Self::HMA),
-			"rma" => Ok(Self::RMA),
-			"ema" => Ok(Self::EMA),
-			"dma" => Ok(Self::DMA),
-			"dema" => Ok(Self::DEMA),
-			"tma" => Ok(Self::TMA),
-			"tema" => Ok(Self::TEMA),
-			"wsma" => Ok(Self::WSMA),
-			"smm" => Ok(Self::SMM),
-			"swma" => Ok(Self::SWMA),
-			"trima" => Ok(Self::TRIMA),
-			"lin_reg" | "linreg" => Ok(Self::LinReg),
-			"vidya" => Ok(Self::Vidya),
-
-			"past" | "move" => Ok(Self::Past),
-			"derivative" => Ok(Self::Derivative),
-			"integral" => Ok(Self::Integral),
-			"mean_abs_dev" => Ok(Self::MeanAbsDev),
-			"median_abs_dev" => Ok(Self::MedianAbsDev),
-			"st_dev" | "stdev" => Ok(Self::StDev),
-			"cci" => Ok(Self::CCI),
-			"momentum" | "change" => Ok(Self::Momentum),
-			"rate_of_change" | "rateofchange" | "roc" => Ok(Self::RateOfChange),
-			"highest" => Ok(Self::Highest),
-			"lowest" => Ok(Self::Lowest),
-			"highest_lowest_delta" => Ok(Self::HighestLowestDelta),
-
-			_ => Err(format!("Unknown regular method name {}", s)),
-		}
+		let name = s.to_ascii_lowercase();
+		let name = name.trim();
+
+		Self::builtin(name)
+			.or_else(|| custom(name).map(|(name, _)| Self::Custom(name)))
+			.ok_or_else(|| format!("Unknown regular method name {}", s))
 	}
 }
 
@@ -210,6 +243,7 @@ impl TryFrom<String> for RegularMethods {
 /// * `momentum`, `change` - [absolute change of values](Momentum)
 /// * `rate_of_change` - [relative change of values](RateOfChange)
 /// * [`highest`](Highest), [`lowest`](Lowest), [`highest_lowest_delta`](HighestLowestDelta)
+/// * any [custom method](register_method) registered by the user
 ///
 /// # Examples
 ///
@@ -271,5 +305,142 @@ pub fn method(
 		RegularMethods::HighestLowestDelta => {
 			Ok(Box::new(HighestLowestDelta::new(length, initial_value)?))
 		}
+		RegularMethods::Custom(name) => match custom(name) {
+			Some((_, constructor)) => constructor(length, initial_value),
+			None => Err(Error::Other(format!("Unknown regular method name {name}"))),
+		},
+	}
+}
+
+/// Registers custom [`RegularMethod`] `constructor` under the `name`
+///
+/// After that the method may be selected wherever [`RegularMethods`] are used: by parsing the `name`
+/// (including indicators' `set` calls) or by the returned [`RegularMethods::Custom`] value.
+/// Names are case-insensitive.
+///
+/// The registry is global for the whole process: registered methods are never removed and are shared between
+/// all threads and all crates linked against `yata` (including tests running in parallel), so prefer unique
+/// names, e.g. prefixed by the crate name.
+///
+/// Returns an error when the `name` is empty, is already taken by one of the default regular methods
+/// or has already been registered.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Error, Method, PeriodType, ValueType};
+/// use yata::helpers::{method, register_method, RegularMethod, RegularMethods};
+/// use yata::indicators::RelativeStrengthIndex;
+/// use yata::methods::EMA;
+/// use yata::prelude::*;
+///
+/// fn my_ma(length: PeriodType, value: ValueType) -> Result<RegularMethod, Error> {
+///     Ok(Box::new(EMA::new(length.saturating_mul(2), value)?))
+/// }
+///
+/// let my = register_method("MyMA", my_ma).unwrap();
+/// assert_eq!(my, "myma".parse().unwrap());
+///
+/// let mut m = method(my, 3, 1.0).unwrap();
+/// assert_eq!(m.next(1.0), 1.0);
+///
+/// let mut rsi = RelativeStrengthIndex::default();
+/// rsi.set("method", "MyMA".to_string()).unwrap();
+/// assert_eq!(rsi.method, my);
+/// ```
+pub fn register_method(
+	name: &str,
+	constructor: MethodConstructor,
+) -> Result<RegularMethods, Error> {
+	let name = name.trim().to_ascii_lowercase();
+
+	if name.is_empty() || RegularMethods::builtin(&name).is_some() {
+		return Err(Error::Other(format!(
+			"Regular method name {name} is not available"
+		)));
+	}
+
+	let mut methods = CUSTOM_METHODS
+		.write()
+		.unwrap_or_else(PoisonError::into_inner);
+
+	if methods.iter().any(|(n, _)| *n == name) {
+		return Err(Error::Other(format!(
+			"Regular method {name} is already registered"
+		)));
+	}
+
+	// names live as long as the registry does
+	let name: &'static str = Box::leak(name.into_boxed_str());
+	methods.push((name, constructor));
+
+	drop(methods);
+
+	Ok(RegularMethods::Custom(name))
+}
+
+fn custom(name: &str) -> Option<(&'static str, MethodConstructor)> {
+	CUSTOM_METHODS
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.iter()
+		.find(|(n, _)| *n == name)
+		.copied()
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn serialize_custom<S: serde::Serializer>(
+	name: &&'static str,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(name)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_custom<'de, D: serde::Deserializer<'de>>(
+	deserializer: D,
+) -> Result<&'static str, D::Error> {
+	let name = String::deserialize(deserializer)?;
+
+	custom(&name)
+		.map(|(name, _)| name)
+		.ok_or_else(|| serde::de::Error::custom(format!("Unknown regular method name {name}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{method, register_method, RegularMethod, RegularMethods};
+	use crate::core::{Error, Method, PeriodType, ValueType};
+	use crate::helpers::assert_eq_float;
+	use crate::methods::SMA;
+	use std::str::FromStr;
+
+	fn custom_sma(length: PeriodType, value: ValueType) -> Result<RegularMethod, Error> {
+		Ok(Box::new(SMA::new(length, value)?))
+	}
+
+	#[test]
+	fn test_register_method() {
+		assert!(register_method("sma", custom_sma).is_err());
+		assert!(register_method(" ", custom_sma).is_err());
+		assert!(RegularMethods::from_str("test_custom_sma").is_err());
+
+		let custom = register_method("Test_Custom_SMA", custom_sma).unwrap();
+		assert_eq!(custom, RegularMethods::Custom("test_custom_sma"));
+		assert_eq!(
+			RegularMethods::from_str(" TEST_custom_sma ").unwrap(),
+			custom
+		);
+		assert!(register_method("test_custom_sma", custom_sma).is_err());
+
+		let mut a = method(custom, 4, 2.0).unwrap();
+		let mut b = method(RegularMethods::SMA, 4, 2.0).unwrap();
+		for x in 0..20 {
+			let x = x as ValueType;
+			assert_eq_float(b.next(x), a.next(x));
+		}
+
+		assert!(method(RegularMethods::Custom("test_unregistered"), 4, 2.0).is_err());
 	}
 }
@@ -6,6 +6,15 @@ mod methods;
 use crate::core::{Candle, ValueType};
 pub use methods::*;
 
+mod datetime;
+pub use datetime::DateTime;
+
+mod session;
+pub use session::{Session, SessionPeriod, Timezone};
+
+mod rng;
+pub(crate) use rng::Rng;
+
 /// sign is like [f64.signum](https://doc.rust-lang.org/std/primitive.f64.html#method.signum)
 /// except when value == 0.0, then sign returns 0.0
 ///
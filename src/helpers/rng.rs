@@ -0,0 +1,33 @@
+use crate::core::ValueType;
+
+/// Small deterministic pseudo-random numbers generator (`SplitMix64`)
+///
+/// Not suitable for cryptography. Used where reproducible randomness is required, e.g. by optimizers and simulations.
+#[derive(Debug, Clone, Copy)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub const fn new(seed: u64) -> Self {
+		Self(seed)
+	}
+
+	pub const fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		z ^ (z >> 31)
+	}
+
+	/// Returns random value in range \[`0.0`; `1.0`\)
+	pub fn value(&mut self) -> ValueType {
+		(self.next_u64() >> 11) as ValueType / (1_u64 << 53) as ValueType
+	}
+
+	/// Returns random index in range \[`0`; `len`\)
+	#[allow(clippy::cast_possible_truncation)]
+	pub const fn index(&mut self, len: usize) -> usize {
+		(self.next_u64() % len as u64) as usize
+	}
+}
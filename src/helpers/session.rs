@@ -0,0 +1,380 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::DateTime;
+use crate::core::{Candle, Error, OHLCV};
+
+const DAY: i64 = DateTime::DAY;
+const HOUR: i64 = 3600;
+
+/// Timezone of the [`Session`]
+///
+/// Every variant holds standard UTC offset in seconds (e.g. `-5 * 3600` for New York, `3600` for Berlin).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Timezone {
+	/// Fixed offset without daylight saving time.
+	Fixed(i32),
+
+	/// United States daylight saving time rules: `+1` hour from the second Sunday of March 02:00 local time
+	/// till the first Sunday of November 02:00 local time.
+	US(i32),
+
+	/// European Union daylight saving time rules: `+1` hour from the last Sunday of March 01:00 UTC
+	/// till the last Sunday of October 01:00 UTC.
+	EU(i32),
+}
+
+impl Timezone {
+	/// Returns UTC offset in seconds at the `timestamp`
+	#[must_use]
+	pub const fn offset(self, timestamp: i64) -> i64 {
+		match self {
+			Self::Fixed(offset) => offset as i64,
+			Self::US(offset) => {
+				let offset = offset as i64;
+				let year = DateTime::from_timestamp(timestamp + offset).year;
+				let start = local(year, 3, sunday(year, 3, 2), 2) - offset;
+				let end = local(year, 11, sunday(year, 11, 1), 1) - offset;
+
+				offset + HOUR * (start <= timestamp && timestamp < end) as i64
+			}
+			Self::EU(offset) => {
+				let offset = offset as i64;
+				let year = DateTime::from_timestamp(timestamp).year;
+				let start = local(year, 3, last_sunday(year, 3, 31), 1);
+				let end = local(year, 10, last_sunday(year, 10, 31), 1);
+
+				offset + HOUR * (start <= timestamp && timestamp < end) as i64
+			}
+		}
+	}
+}
+
+const fn local(year: i32, month: u8, day: u8, hour: u8) -> i64 {
+	DateTime {
+		year,
+		month,
+		day,
+		hour,
+		minute: 0,
+		second: 0,
+		weekday: 0,
+	}
+	.timestamp()
+}
+
+/// Returns day of month of the `nth` Sunday of the month
+const fn sunday(year: i32, month: u8, nth: u8) -> u8 {
+	let weekday = DateTime::from_timestamp(local(year, month, 1, 0)).weekday;
+
+	1 + (13 - weekday) % 7 + (nth - 1) * 7
+}
+
+/// Returns day of month of the last Sunday of the month, which has `days` days
+const fn last_sunday(year: i32, month: u8, days: u8) -> u8 {
+	let weekday = DateTime::from_timestamp(local(year, month, days, 0)).weekday;
+
+	days - (weekday + 1) % 7
+}
+
+/// Period of bars of the [`Session::aggregate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SessionPeriod {
+	/// Single bar per session.
+	Daily,
+
+	/// Single bar per week (from Monday till Sunday) of session dates.
+	Weekly,
+}
+
+/// Trading session
+///
+/// Defines trading hours from `open` till `close` local time of the `timezone` and trading `days` of the week.
+/// Every session belongs to the local date it closes on: e.g. forex session, which opens on Sunday 17:00 New York time,
+/// belongs to Monday.
+///
+/// When `close` is not greater than `open`, session spans midnight. When `close` equals `open`, session lasts for 24 hours.
+///
+/// Used to aggregate intraday candles into daily and weekly bars, which match the exchange sessions instead of UTC days.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::{RandomCandles, Session, SessionPeriod};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(24 * 12).collect();
+/// // hourly candles starting at 2021-03-01 00:00:00 UTC
+/// let timestamps: Vec<i64> = (0..24 * 12).map(|i| 1_614_556_800 + i * 3600).collect();
+///
+/// let daily = Session::nyse().aggregate(&candles, &timestamps, SessionPeriod::Daily).unwrap();
+/// assert_eq!(daily.len(), 10);
+///
+/// let weekly = Session::forex().aggregate(&candles, &timestamps, SessionPeriod::Weekly).unwrap();
+/// assert_eq!(weekly.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Session {
+	/// Timezone of the session
+	pub timezone: Timezone,
+
+	/// Local time of the session open in seconds since midnight
+	///
+	/// Range in \[`0`; `86400`\)
+	pub open: u32,
+
+	/// Local time of the session close in seconds since midnight
+	///
+	/// Range in \[`0`; `86400`\)
+	pub close: u32,
+
+	/// Trading days of the week, starting from Monday. Sessions of other days are skipped.
+	pub days: [bool; 7],
+}
+
+impl Session {
+	/// 24 hours UTC session for every day of the week
+	#[must_use]
+	pub const fn utc() -> Self {
+		Self {
+			timezone: Timezone::Fixed(0),
+			open: 0,
+			close: 0,
+			days: [true; 7],
+		}
+	}
+
+	/// New York Stock Exchange regular session: 09:30 - 16:00 New York time, Monday - Friday
+	#[must_use]
+	pub const fn nyse() -> Self {
+		Self {
+			timezone: Timezone::US(-5 * 3600),
+			open: 9 * 3600 + 30 * 60,
+			close: 16 * 3600,
+			days: [true, true, true, true, true, false, false],
+		}
+	}
+
+	/// Forex session: 17:00 - 17:00 New York time, Monday - Friday
+	#[must_use]
+	pub const fn forex() -> Self {
+		Self {
+			timezone: Timezone::US(-5 * 3600),
+			open: 17 * 3600,
+			close: 17 * 3600,
+			days: [true, true, true, true, true, false, false],
+		}
+	}
+
+	/// Validates session parameters
+	#[must_use]
+	pub fn validate(&self) -> bool {
+		(self.open as i64) < DAY && (self.close as i64) < DAY && self.days.iter().any(|&day| day)
+	}
+
+	/// Returns the date of the session the `timestamp` belongs to
+	///
+	/// Returns `None` when the `timestamp` is out of trading hours or the session is on non-trading day.
+	#[must_use]
+	pub fn date(&self, timestamp: i64) -> Option<DateTime> {
+		self.day(timestamp)
+			.map(|day| DateTime::from_timestamp(day * DAY))
+	}
+
+	/// Returns the key of the session bar of the `period` the `timestamp` belongs to
+	///
+	/// Key is the count of days since Unix epoch of the session date for [`Daily`](SessionPeriod::Daily) bars
+	/// and of the Monday of the session date's week for [`Weekly`](SessionPeriod::Weekly) bars.
+	///
+	/// Returns `None` when the `timestamp` is out of trading hours or the session is on non-trading day.
+	#[must_use]
+	pub const fn key(&self, timestamp: i64, period: SessionPeriod) -> Option<i64> {
+		match self.day(timestamp) {
+			Some(day) => match period {
+				SessionPeriod::Daily => Some(day),
+				SessionPeriod::Weekly => Some(day - (day + 3).rem_euclid(7)),
+			},
+			None => None,
+		}
+	}
+
+	/// Aggregates intraday `candles` with corresponding `timestamps` into session bars of the `period`
+	///
+	/// Every timestamp is the open time of the candle. Candles out of trading hours and on non-trading days are skipped.
+	/// Returns bars together with timestamps of their first candles.
+	///
+	/// Returns an error when session parameters are invalid or count of `timestamps` differs from count of `candles`.
+	pub fn aggregate<T: OHLCV>(
+		&self,
+		candles: &[T],
+		timestamps: &[i64],
+		period: SessionPeriod,
+	) -> Result<Vec<(i64, Candle)>, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		if candles.len() != timestamps.len() {
+			return Err(Error::Other(
+				"count of timestamps must be equal to count of candles".to_string(),
+			));
+		}
+
+		let mut bars: Vec<(i64, Candle)> = Vec::new();
+		let mut current = None;
+
+		let session_candles = candles
+			.iter()
+			.zip(timestamps)
+			.filter_map(|(candle, &timestamp)| {
+				Some((candle, timestamp, self.key(timestamp, period)?))
+			});
+
+		for (candle, timestamp, key) in session_candles {
+			match bars.last_mut() {
+				Some((_, bar)) if current == Some(key) => {
+					bar.high = bar.high.max(candle.high());
+					bar.low = bar.low.min(candle.low());
+					bar.close = candle.close();
+					bar.volume += candle.volume();
+				}
+				_ => {
+					bars.push((timestamp, Candle::from(candle)));
+					current = Some(key);
+				}
+			}
+		}
+
+		Ok(bars)
+	}
+
+	/// Returns count of days since Unix epoch of the session date
+	const fn day(&self, timestamp: i64) -> Option<i64> {
+		let local = timestamp + self.timezone.offset(timestamp);
+		let (open, close) = (self.open as i64, self.close as i64);
+
+		let length = match (close - open).rem_euclid(DAY) {
+			0 => DAY,
+			length => length,
+		};
+
+		let elapsed = (local.rem_euclid(DAY) - open).rem_euclid(DAY);
+		if elapsed >= length {
+			return None;
+		}
+
+		// session belongs to the date it closes on
+		let day = (local - elapsed + length - 1).div_euclid(DAY);
+
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let weekday = (day + 3).rem_euclid(7) as usize;
+
+		if self.days[weekday] {
+			Some(day)
+		} else {
+			None
+		}
+	}
+}
+
+impl Default for Session {
+	fn default() -> Self {
+		Self::utc()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Session, SessionPeriod, Timezone};
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	// 2021-03-01 00:00:00 UTC, Monday
+	const START: i64 = 1_614_556_800;
+
+	#[test]
+	fn test_timezone() {
+		let new_york = Timezone::US(-5 * 3600);
+		// 2021-03-14 06:59:59 UTC and 07:00:00 UTC
+		assert_eq!(new_york.offset(1_615_705_199), -5 * 3600);
+		assert_eq!(new_york.offset(1_615_705_200), -4 * 3600);
+		// 2021-11-07 05:59:59 UTC and 06:00:00 UTC
+		assert_eq!(new_york.offset(1_636_264_799), -4 * 3600);
+		assert_eq!(new_york.offset(1_636_264_800), -5 * 3600);
+
+		let berlin = Timezone::EU(3600);
+		// 2021-03-28 00:59:59 UTC and 01:00:00 UTC
+		assert_eq!(berlin.offset(1_616_893_199), 3600);
+		assert_eq!(berlin.offset(1_616_893_200), 7200);
+		// 2021-10-31 01:00:00 UTC
+		assert_eq!(berlin.offset(1_635_642_000), 3600);
+	}
+
+	#[test]
+	fn test_session_aggregate() {
+		let timestamps: Vec<i64> = (0..24 * 21).map(|i| START + i * 3600).collect();
+		let candles: Vec<_> = (0..timestamps.len())
+			.map(|i| Candle {
+				open: i as ValueType,
+				high: i as ValueType + 1.,
+				low: i as ValueType - 1.,
+				close: i as ValueType + 0.5,
+				volume: 1.,
+			})
+			.collect();
+
+		let daily = Session::nyse()
+			.aggregate(&candles, &timestamps, SessionPeriod::Daily)
+			.unwrap();
+		assert_eq!(daily.len(), 15);
+
+		// 09:30 - 16:00 EST is 14:30 - 21:00 UTC, so candles 15:00 - 20:00 UTC are taken
+		let (timestamp, bar) = daily[0];
+		assert_eq!(timestamp, START + 15 * 3600);
+		assert_eq_float(15., bar.open);
+		assert_eq_float(20.5, bar.close);
+		assert_eq_float(6., bar.volume);
+
+		// after the daylight saving time change at 2021-03-14 session starts an hour earlier in UTC
+		let (timestamp, bar) = daily[10];
+		assert_eq!(timestamp, START + (14 * 24 + 14) * 3600);
+		assert_eq_float(6., bar.volume);
+
+		// Sunday 17:00 New York time opens the Monday's session
+		let forex = Session::forex();
+		let monday = forex.date(START - 2 * 3600).unwrap();
+		assert_eq!((monday.month, monday.day, monday.weekday), (3, 1, 0));
+		assert!(forex.date(START - 12 * 3600).is_none());
+
+		let daily = forex
+			.aggregate(&candles, &timestamps, SessionPeriod::Daily)
+			.unwrap();
+		// the last candles of Sunday open the session of the next week
+		assert_eq!(daily.len(), 16);
+		assert_eq_float(24., daily[1].1.volume);
+		assert_eq_float(3., daily[15].1.volume);
+
+		let weekly = forex
+			.aggregate(&candles, &timestamps, SessionPeriod::Weekly)
+			.unwrap();
+		assert_eq!(weekly.len(), 4);
+		// the first week starts at 00:00 UTC Monday, since there are no candles before
+		assert_eq_float(ValueType::mul_add(5., 24., -2.), weekly[0].1.volume);
+
+		let utc = Session::utc()
+			.aggregate(&candles, &timestamps, SessionPeriod::Daily)
+			.unwrap();
+		assert_eq!(utc.len(), 21);
+		for (_, bar) in &utc {
+			assert_eq_float(24., bar.volume);
+		}
+
+		assert!(Session::utc()
+			.aggregate(&candles, &timestamps[1..], SessionPeriod::Daily)
+			.is_err());
+	}
+}
@@ -0,0 +1,369 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, signi, RegularMethod, RegularMethods};
+use crate::methods::MedianAbsDev;
+
+/// Response of the [`AnomalyDetector`] to the anomalous bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum AnomalyResponse {
+	/// Signal in the direction of the anomalous bar (momentum).
+	Follow,
+
+	/// Signal against the direction of the anomalous bar (mean reversion).
+	Fade,
+}
+
+impl FromStr for AnomalyResponse {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"follow" => Ok(Self::Follow),
+			"fade" => Ok(Self::Fade),
+
+			value => Err(Error::ParameterParse(
+				"response".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Anomaly Detector
+///
+/// Flags outlier bars by comparing every candle with the statistics of the previous `period` candles:
+///
+/// * return anomaly, when the close-to-close return deviates from the rolling median return
+///   by more than `return_threshold` rolling [`MedianAbsDev`] values;
+/// * volume anomaly, when `volume` is greater than at least `volume_percentile` part of the rolling `volume` values;
+/// * range anomaly, when `high` - `low` exceeds the rolling average range `range_threshold` times.
+///
+/// When `exclude` is `true`, anomalous returns and ranges are not added into the rolling statistics, so a single outlier
+/// does not distort detection of the following bars. Rolling `volume` values are always updated, because percentile
+/// is already robust to single outliers. This makes the indicator usable as a data-quality guard
+/// in front of other indicators.
+///
+/// No anomalies are detected over the first `period` candles, while the rolling statistics are warming up.
+///
+/// Direction of every signal is the direction of the anomalous bar (the sign of the return for return anomaly
+/// and the sign of `close` - `open` for volume and range anomalies), reversed when `response` is [`Fade`](AnomalyResponse::Fade).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{AnomalyDetector, AnomalyResponse};
+///
+/// let detector = AnomalyDetector {
+///     response: AnomalyResponse::Fade,
+///     ..AnomalyDetector::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = detector.over(&candles).unwrap();
+///
+/// let anomalies = results.iter().filter(|result| result.value(3) > 0.).count();
+/// println!("{} anomalous bars", anomalies);
+/// ```
+///
+/// # 4 values
+///
+/// * Deviation of the return from the rolling median in [`MedianAbsDev`] units
+///
+/// Range in \(`-inf`; `+inf`\). `0.0` when rolling deviation is zero.
+///
+/// * Part of the rolling `volume` values, which are less than the current `volume`
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * Ratio of the current range to the rolling average range
+///
+/// Range in \[`0.0`; `+inf`\). `0.0` when rolling average range is zero.
+///
+/// * Anomaly flag: `1.0` when any kind of anomaly is detected, otherwise `0.0`
+///
+/// # 3 signals
+///
+/// * Return anomaly
+/// * Volume anomaly
+/// * Range anomaly
+///
+/// Every signal is full buy or full sell signal by the direction of the bar when anomaly of the kind is detected. Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnomalyDetector {
+	/// Count of previous candles to calculate rolling statistics over. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Return deviation threshold in [`MedianAbsDev`] units. Default is `5.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub return_threshold: ValueType,
+
+	/// Volume percentile threshold. Default is `0.95`.
+	///
+	/// Range in \(`0.0`; `1.0`\]
+	pub volume_percentile: ValueType,
+
+	/// Range threshold in average ranges. Default is `3.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub range_threshold: ValueType,
+
+	/// Response to the anomalous bar. Default is [`Follow`](AnomalyResponse::Follow).
+	pub response: AnomalyResponse,
+
+	/// Excludes anomalous returns and ranges from the rolling statistics. Default is `true`.
+	pub exclude: bool,
+}
+
+impl IndicatorConfig for AnomalyDetector {
+	type Instance = AnomalyDetectorInstance;
+
+	const NAME: &'static str = "AnomalyDetector";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let range = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			returns: MedianAbsDev::new(cfg.period, 0.)?,
+			median: 0.,
+			deviation: 0.,
+			volumes: Window::new(cfg.period, candle.volume()),
+			ranges: method(RegularMethods::SMA, cfg.period, range)?,
+			average_range: range,
+			prev_close: candle.close(),
+			warmup: cfg.period,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.return_threshold > 0.
+			&& self.return_threshold.is_finite()
+			&& self.volume_percentile > 0.
+			&& self.volume_percentile <= 1.
+			&& self.range_threshold > 0.
+			&& self.range_threshold.is_finite()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"return_threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.return_threshold = value,
+			},
+			"volume_percentile" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.volume_percentile = value,
+			},
+			"range_threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.range_threshold = value,
+			},
+			"response" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.response = value,
+			},
+			"exclude" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.exclude = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 3)
+	}
+}
+
+impl Default for AnomalyDetector {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			return_threshold: 5.,
+			volume_percentile: 0.95,
+			range_threshold: 3.,
+			response: AnomalyResponse::Follow,
+			exclude: true,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct AnomalyDetectorInstance {
+	cfg: AnomalyDetector,
+
+	returns: MedianAbsDev,
+	median: ValueType,
+	deviation: ValueType,
+	volumes: Window<ValueType>,
+	ranges: RegularMethod,
+	average_range: ValueType,
+	prev_close: ValueType,
+	warmup: PeriodType,
+}
+
+impl IndicatorInstance for AnomalyDetectorInstance {
+	type Config = AnomalyDetector;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let ret = if self.prev_close > 0. {
+			candle.close() / self.prev_close - 1.
+		} else {
+			0.
+		};
+		self.prev_close = candle.close();
+
+		let volume = candle.volume();
+		let range = candle.high() - candle.low();
+
+		let return_deviation = if self.deviation > 0. {
+			(ret - self.median) / self.deviation
+		} else {
+			0.
+		};
+		let below = self.volumes.iter().filter(|&value| value < volume).count();
+		let volume_rank = below as ValueType / self.cfg.period as ValueType;
+		let range_ratio = if self.average_range > 0. {
+			range / self.average_range
+		} else {
+			0.
+		};
+
+		let ready = self.warmup == 0;
+		self.warmup = self.warmup.saturating_sub(1);
+
+		let return_anomaly = ready && return_deviation.abs() > self.cfg.return_threshold;
+		let volume_anomaly = ready && volume_rank >= self.cfg.volume_percentile;
+		let range_anomaly = ready && range_ratio > self.cfg.range_threshold;
+
+		if !(self.cfg.exclude && return_anomaly) {
+			self.deviation = self.returns.next(ret);
+			self.median = self.returns.get_smm().get_last_value();
+		}
+
+		self.volumes.push(volume);
+
+		if !(self.cfg.exclude && range_anomaly) {
+			self.average_range = self.ranges.next(range);
+		}
+
+		let response = match self.cfg.response {
+			AnomalyResponse::Follow => 1,
+			AnomalyResponse::Fade => -1,
+		};
+		let bar_direction = response * signi(candle.close() - candle.open());
+		let return_direction = response * signi(ret);
+
+		let anomaly = return_anomaly || volume_anomaly || range_anomaly;
+
+		IndicatorResult::new(
+			&[
+				return_deviation,
+				volume_rank,
+				range_ratio,
+				anomaly as i8 as ValueType,
+			],
+			&[
+				Action::from(return_direction * return_anomaly as i8),
+				Action::from(bar_direction * volume_anomaly as i8),
+				Action::from(bar_direction * range_anomaly as i8),
+			],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{AnomalyDetector, AnomalyResponse};
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(open: ValueType, close: ValueType, volume: ValueType) -> Candle {
+		Candle {
+			open,
+			high: open.max(close) + 0.1,
+			low: open.min(close) - 0.1,
+			close,
+			volume,
+		}
+	}
+
+	#[test]
+	fn test_anomaly_detector() {
+		let detector = AnomalyDetector {
+			period: 10,
+			..AnomalyDetector::default()
+		};
+		let mut instance = detector.init(&candle(100., 100., 10.)).unwrap();
+
+		let mut close = 100.;
+		for i in 0..30 {
+			let open = close;
+			close += if i % 2 == 0 { 0.1 } else { -0.1 };
+
+			let result = instance.next(&candle(open, close, 10. + (i % 3) as ValueType));
+			if i > 10 {
+				assert_eq_float(0., result.value(3));
+			}
+		}
+
+		// crash bar with huge volume
+		let result = instance.next(&candle(close, close - 5., 100.));
+		assert_eq_float(1., result.value(3));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq!(result.signal(1), Action::SELL_ALL);
+		assert_eq!(result.signal(2), Action::SELL_ALL);
+
+		// the crash is excluded from the statistics, so the same crash is still anomalous
+		let result = instance.next(&candle(close - 5., close - 10., 10.));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq!(result.signal(1), Action::None);
+		assert_eq!(result.signal(2), Action::SELL_ALL);
+
+		let detector = AnomalyDetector {
+			response: AnomalyResponse::Fade,
+			..detector
+		};
+		let mut instance = detector.init(&candle(100., 100., 10.)).unwrap();
+		for _ in 0..10 {
+			instance.next(&candle(100., 100.1, 10.));
+		}
+		let result = instance.next(&candle(100.1, 110., 10.));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq!(result.signal(1), Action::None);
+		assert_eq!(result.signal(2), Action::SELL_ALL);
+	}
+}
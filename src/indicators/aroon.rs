@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Cross, HighestIndex, LowestIndex};
+use crate::methods::{BarsSinceExtreme, Cross};
 
 // https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/aroon-indicator
 // Aroon-Up = [(Period Specified – Periods Since the Highest High within Period Specified) / Period Specified]
@@ -66,8 +66,7 @@ impl IndicatorConfig for Aroon {
 		let cfg = self;
 
 		Ok(Self::Instance {
-			lowest_index: LowestIndex::new(cfg.period, candle.low())?,
-			highest_index: HighestIndex::new(cfg.period, candle.high())?,
+			bars_since: BarsSinceExtreme::new(cfg.period, candle)?,
 			cross: Cross::default(),
 			uptrend: 0,
 			downtrend: 0,
@@ -126,8 +125,7 @@ impl Default for Aroon {
 #[derive(Debug, Clone)]
 pub struct AroonInstance {
 	cfg: Aroon,
-	lowest_index: LowestIndex,
-	highest_index: HighestIndex,
+	bars_since: BarsSinceExtreme,
 	cross: Cross,
 	uptrend: isize,
 	downtrend: isize,
@@ -143,8 +141,7 @@ impl IndicatorInstance for AroonInstance {
 	}
 
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let highest_index = self.highest_index.next(candle.high());
-		let lowest_index = self.lowest_index.next(candle.low());
+		let (highest_index, lowest_index) = self.bars_since.next(candle);
 
 		let aroon_up =
 			(self.cfg.period - highest_index) as ValueType / self.cfg.period as ValueType;
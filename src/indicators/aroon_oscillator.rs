@@ -0,0 +1,136 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{BarsSinceExtreme, Cross, CrossAbove, CrossUnder};
+
+/// Aroon Oscillator
+///
+/// Difference between `AroonUp` and `AroonDown` values of the [`Aroon`](crate::indicators::Aroon) indicator.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/a/aroonoscillator.asp>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:aroon_oscillator>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * When `main` value crosses `0.0` upwards, returns full buy signal.
+///   When `main` value crosses `0.0` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `main` value crosses `threshold` upwards, returns full buy signal.
+///   When `main` value crosses -`threshold` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AroonOscillator {
+	/// Main period length. Default is `25`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Strong trend threshold. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub threshold: ValueType,
+}
+
+impl IndicatorConfig for AroonOscillator {
+	type Instance = AroonOscillatorInstance;
+
+	const NAME: &'static str = "AroonOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			bars_since: BarsSinceExtreme::new(cfg.period, candle)?,
+			cross: Cross::default(),
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.threshold > 0.
+			&& self.threshold < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for AroonOscillator {
+	fn default() -> Self {
+		Self {
+			period: 25,
+			threshold: 0.5,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct AroonOscillatorInstance {
+	cfg: AroonOscillator,
+
+	bars_since: BarsSinceExtreme,
+	cross: Cross,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for AroonOscillatorInstance {
+	type Config = AroonOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let (highest_index, lowest_index) = self.bars_since.next(candle);
+
+		// `AroonUp` - `AroonDown` = (`period` - `highest_index` - `period` + `lowest_index`) / `period`
+		let value =
+			(lowest_index as ValueType - highest_index as ValueType) / self.cfg.period as ValueType;
+
+		let signal1 = self.cross.next((value, 0.));
+		let signal2 = self.cross_above.next((value, self.cfg.threshold))
+			- self.cross_under.next((value, -self.cfg.threshold));
+
+		IndicatorResult::new(&[value], &[signal1, signal2])
+	}
+}
@@ -14,7 +14,7 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 /// * <https://www.investopedia.com/terms/a/adx.asp>
 /// * <https://primexbt.com/blog/average-directional-index/>
 ///
-/// # 3 values
+/// # 3 or 4 values
 ///
 /// * `ADX`
 ///
@@ -28,6 +28,14 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 ///
 /// Range in \[`0.0`; `1.0`\]
 ///
+/// When the smoothed true range is zero (e.g. for the constant price series), `+DI` and `-DI` are `0.0`.
+///
+/// * `ADXR` (only when `adxr` is `true`)
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// Average of the current `ADX` value and `ADX` value `adx_smoothing` - `1` bars ago.
+///
 /// # 2 signals
 ///
 /// * `BUY_ALL` when `ADX` over `zone` and `+DI` > `-DI`, `SELL_ALL` when `ADX` over `zone` and `-DI` > `+DI`. Otherwise - no signal.
@@ -58,6 +66,17 @@ pub struct AverageDirectionalIndex {
 	///
 	/// Range in \[`0.0`; `1.0`\]
 	pub zone: ValueType,
+
+	/// Add `ADXR` value to the output. Default is `false`
+	pub adxr: bool,
+
+	/// Use Welles Wilder's original smoothing. Default is `false`
+	///
+	/// When `true`, `method1` and `method2` are ignored: `+DM`, `-DM` and true range are seeded by the sum of the first values
+	/// and `ADX` is seeded by the average of the first `DX` values exactly as TA-Lib does,
+	/// so the values are the same as TA-Lib `ADX`, `PLUS_DI`, `MINUS_DI` and `ADXR` divided by `100`
+	/// (after TA-Lib lookback period when `period1` is `1` and `di_length` is equal to `adx_smoothing`).
+	pub wilder: bool,
 }
 
 impl IndicatorConfig for AverageDirectionalIndex {
@@ -80,6 +99,8 @@ impl IndicatorConfig for AverageDirectionalIndex {
 			plus_di: method(cfg.method1, cfg.di_length, 0.0)?,
 			minus_di: method(cfg.method1, cfg.di_length, 0.0)?,
 			ma2: method(cfg.method2, cfg.adx_smoothing, 0.0)?,
+			wilder: Wilder::new(cfg.di_length, cfg.adx_smoothing),
+			adx_window: Window::new(cfg.adx_smoothing - 1, 0.0),
 			cfg,
 		})
 	}
@@ -124,6 +145,14 @@ impl IndicatorConfig for AverageDirectionalIndex {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"adxr" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.adxr = value,
+			},
+			"wilder" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.wilder = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -134,7 +163,7 @@ impl IndicatorConfig for AverageDirectionalIndex {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(3, 2)
+		(3 + self.adxr as u8, 2)
 	}
 }
 
@@ -147,6 +176,8 @@ impl Default for AverageDirectionalIndex {
 			adx_smoothing: 14,
 			period1: 1,
 			zone: 0.2,
+			adxr: false,
+			wilder: false,
 		}
 	}
 }
@@ -161,12 +192,113 @@ pub struct AverageDirectionalIndexInstance {
 	plus_di: RegularMethod,
 	minus_di: RegularMethod,
 	ma2: RegularMethod,
+	wilder: Wilder,
+	adx_window: Window<ValueType>,
+}
+
+/// Welles Wilder's smoothing the same way as TA-Lib does it
+#[derive(Debug, Clone, Copy)]
+struct Wilder {
+	di_length: PeriodType,
+	adx_smoothing: PeriodType,
+	started: bool,
+	bars: PeriodType,
+	dx_bars: PeriodType,
+	tr: ValueType,
+	plus_dm: ValueType,
+	minus_dm: ValueType,
+	sum_dx: ValueType,
+	adx: ValueType,
+}
+
+impl Wilder {
+	const fn new(di_length: PeriodType, adx_smoothing: PeriodType) -> Self {
+		Self {
+			di_length,
+			adx_smoothing,
+			started: false,
+			bars: 0,
+			dx_bars: 0,
+			tr: 0.,
+			plus_dm: 0.,
+			minus_dm: 0.,
+			sum_dx: 0.,
+			adx: 0.,
+		}
+	}
+
+	/// Returns `+DI`, `-DI` and `ADX` values in percents
+	// `mul_add` gives slightly different results, so it is not used here to keep the values the same as TA-Lib's
+	#[allow(clippy::suboptimal_flops)]
+	fn next(
+		&mut self,
+		tr: ValueType,
+		plus_dm: ValueType,
+		minus_dm: ValueType,
+	) -> (ValueType, ValueType, ValueType) {
+		// the first candle has no previous one, so TA-Lib does not count it
+		if !self.started {
+			self.started = true;
+			return (0., 0., 0.);
+		}
+
+		let (n, m) = (self.di_length, self.adx_smoothing);
+		let (length, smoothing) = (n as ValueType, m as ValueType);
+
+		// `bars` is the index of the current candle until it reaches `di_length`
+		if self.bars < n {
+			self.bars += 1;
+		}
+
+		// `dx_bars` counts the first `adx_smoothing` `DX` values, which seed `ADX`
+		if self.bars >= n && self.dx_bars <= m {
+			self.dx_bars += 1;
+		}
+
+		// first `di_length` - `1` values are just summed
+		if self.bars >= n {
+			self.tr -= self.tr / length;
+			self.plus_dm -= self.plus_dm / length;
+			self.minus_dm -= self.minus_dm / length;
+		}
+
+		self.tr += tr;
+		self.plus_dm += plus_dm;
+		self.minus_dm += minus_dm;
+
+		if self.tr <= 0. {
+			return (0., 0., self.adx);
+		}
+
+		let plus = 100.0 * (self.plus_dm / self.tr);
+		let minus = 100.0 * (self.minus_dm / self.tr);
+		let sum = plus + minus;
+		let is_seeding = self.bars >= n && self.dx_bars <= m;
+
+		if self.bars >= n && sum > 0. {
+			let dx = 100.0 * ((minus - plus).abs() / sum);
+
+			if is_seeding {
+				self.sum_dx += dx;
+			} else {
+				self.adx = (self.adx * (smoothing - 1.) + dx) / smoothing;
+			}
+		}
+
+		// first `ADX` value is an average of the first `adx_smoothing` `DX` values
+		if is_seeding {
+			self.adx = self.sum_dx / self.dx_bars as ValueType;
+		}
+
+		(plus, minus, self.adx)
+	}
 }
 
 impl AverageDirectionalIndexInstance {
-	fn dir_mov(&mut self, candle: HLC) -> (ValueType, ValueType) {
+	/// Returns true range, `+DM` and `-DM` values
+	fn dir_mov(&mut self, candle: HLC) -> (ValueType, ValueType, ValueType) {
 		let prev_candle = self.window.push(candle);
-		let true_range = self.tr_ma.next(candle.tr_close(self.prev_close));
+		let tr = candle.tr_close(self.prev_close);
 		self.prev_close = candle.close();
 
 		let (du, dd) = (
@@ -177,10 +309,25 @@ impl AverageDirectionalIndexInstance {
 		let plus_dm = du * (du > dd && du > 0.) as u8 as ValueType; // +DM
 		let minus_dm = dd * (dd > du && dd > 0.) as u8 as ValueType; // -DM
 
+		(tr, plus_dm, minus_dm)
+	}
+
+	fn di(
+		&mut self,
+		tr: ValueType,
+		plus_dm: ValueType,
+		minus_dm: ValueType,
+	) -> (ValueType, ValueType) {
+		let true_range = self.tr_ma.next(tr);
 		let plus_di_value = self.plus_di.next(plus_dm); // +DI
 		let minus_di_value = self.minus_di.next(minus_dm); // -DI
 
-		(plus_di_value / true_range, minus_di_value / true_range)
+		// there is no directional movement at all when the smoothed true range is zero (e.g. constant price series)
+		if true_range > 0. {
+			(plus_di_value / true_range, minus_di_value / true_range)
+		} else {
+			(0., 0.)
+		}
 	}
 
 	fn adx(&mut self, plus: ValueType, minus: ValueType) -> ValueType {
@@ -203,14 +350,238 @@ impl IndicatorInstance for AverageDirectionalIndexInstance {
 	}
 
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let (plus, minus) = self.dir_mov(HLC::from(candle));
-		let adx = self.adx(plus, minus);
+		let (tr, plus_dm, minus_dm) = self.dir_mov(HLC::from(candle));
+
+		// Wilder's mode values are calculated in percents just like TA-Lib does
+		let (adx, plus, minus, scale) = if self.cfg.wilder {
+			let (plus, minus, adx) = self.wilder.next(tr, plus_dm, minus_dm);
+			(adx, plus, minus, 100.)
+		} else {
+			let (plus, minus) = self.di(tr, plus_dm, minus_dm);
+			(self.adx(plus, minus), plus, minus, 1.)
+		};
+
+		let adxr = (adx + self.adx_window.push(adx)) * 0.5;
+		let (adx, plus, minus, adxr) = (adx / scale, plus / scale, minus / scale, adxr / scale);
 
 		let signal1 = (adx > self.cfg.zone) as i8 * ((plus > minus) as i8 - (plus < minus) as i8);
 		let signal2 = plus - minus;
 
-		let values = [adx, plus, minus];
+		let values = [adx, plus, minus, adxr];
+		let length = self.cfg.size().0 as usize;
+
+		IndicatorResult::new(&values[..length], &[signal1.into(), signal2.into()])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::AverageDirectionalIndex;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_adx_const() {
+		let candle = Candle {
+			open: 10.0,
+			high: 10.0,
+			low: 10.0,
+			close: 10.0,
+			volume: 1.0,
+		};
+
+		let mut adx = AverageDirectionalIndex::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = adx.next(&candle);
 
-		IndicatorResult::new(&values, &[signal1.into(), signal2.into()])
+			assert!(result.values().iter().all(|v| v.is_finite()));
+			assert_eq!(result.values(), &[0.0, 0.0, 0.0]);
+		}
+	}
+
+	// 5-period TA-Lib `ADX`, `PLUS_DI`, `MINUS_DI` and `ADXR` over the fixed candles, divided by `100`.
+	// Every series starts right after the corresponding TA-Lib lookback period.
+	const TA_LIB_CANDLES: [(ValueType, ValueType, ValueType, ValueType); 30] = [
+		(50.00, 50.14, 48.91, 49.50),
+		(49.50, 49.98, 47.89, 48.22),
+		(48.22, 48.68, 46.87, 46.90),
+		(46.90, 46.96, 46.66, 46.74),
+		(46.74, 47.48, 46.45, 46.56),
+		(46.56, 47.12, 44.90, 45.75),
+		(45.75, 46.40, 44.87, 46.04),
+		(46.04, 46.81, 44.42, 44.68),
+		(44.68, 44.79, 43.35, 43.63),
+		(43.63, 44.82, 43.11, 44.66),
+		(44.66, 45.48, 44.17, 45.14),
+		(45.14, 45.19, 43.64, 43.83),
+		(43.83, 44.82, 43.55, 44.44),
+		(44.44, 45.17, 44.17, 44.76),
+		(44.76, 46.35, 44.54, 45.72),
+		(45.72, 46.47, 44.93, 46.00),
+		(46.00, 47.02, 45.12, 46.76),
+		(46.76, 47.14, 44.95, 45.63),
+		(45.63, 46.07, 44.56, 44.60),
+		(44.60, 45.86, 44.08, 45.17),
+		(45.17, 46.66, 44.54, 46.38),
+		(46.38, 47.24, 45.97, 46.72),
+		(46.72, 48.67, 46.29, 47.82),
+		(47.82, 48.43, 47.19, 48.38),
+		(48.38, 49.78, 47.64, 48.89),
+		(48.89, 49.24, 47.67, 48.27),
+		(48.27, 48.69, 46.69, 46.84),
+		(46.84, 46.89, 45.01, 45.70),
+		(45.70, 45.92, 44.25, 44.60),
+		(44.60, 45.87, 44.20, 45.80),
+	];
+
+	// ADX, starting from the candle #9
+	#[allow(clippy::unreadable_literal)]
+	const TA_LIB_ADX: [ValueType; 21] = [
+		0.826749250754,
+		0.755700814896,
+		0.717911229647,
+		0.690872166542,
+		0.621157596425,
+		0.533246554296,
+		0.470654748617,
+		0.453813093585,
+		0.419245872645,
+		0.345712349526,
+		0.311682297328,
+		0.287199219285,
+		0.303205869967,
+		0.367078548974,
+		0.41817669218,
+		0.487221527179,
+		0.542457395179,
+		0.481768784438,
+		0.434059733475,
+		0.423195169868,
+		0.416316881912,
+	];
+
+	// PLUS_DI, starting from the candle #5
+	#[allow(clippy::unreadable_literal)]
+	const TA_LIB_PLUS_DI: [ValueType; 25] = [
+		0.0649594003748,
+		0.0500210425059,
+		0.0345203149149,
+		0.0279882597324,
+		0.0218508285366,
+		0.105494096659,
+		0.0839469424251,
+		0.0694240327537,
+		0.110248166871,
+		0.24440511718,
+		0.209695746801,
+		0.229105761527,
+		0.16963950657,
+		0.138627678151,
+		0.109209602189,
+		0.173587021563,
+		0.216727426678,
+		0.317771461319,
+		0.271293606431,
+		0.357530000611,
+		0.293068631441,
+		0.227697540592,
+		0.180412748374,
+		0.146607257289,
+		0.118785037411,
+	];
+
+	// MINUS_DI, starting from the candle #5
+	#[allow(clippy::unreadable_literal)]
+	const TA_LIB_MINUS_DI: [ValueType; 25] = [
+		0.523110555903,
+		0.40732279204,
+		0.339446305766,
+		0.415818665007,
+		0.3554124586,
+		0.29373204607,
+		0.303577591383,
+		0.263318264895,
+		0.225005146821,
+		0.169279080011,
+		0.13398677449,
+		0.101387720787,
+		0.095220062403,
+		0.12502873966,
+		0.155721451248,
+		0.118335709196,
+		0.10030340898,
+		0.0739177789162,
+		0.0631064247819,
+		0.0479705378831,
+		0.0393216229767,
+		0.139848714476,
+		0.296379951812,
+		0.326118787314,
+		0.269911828912,
+	];
+
+	// ADXR, starting from the candle #13
+	#[allow(clippy::unreadable_literal)]
+	const TA_LIB_ADXR: [ValueType; 17] = [
+		0.72395342359,
+		0.644473684596,
+		0.594282989132,
+		0.572342630063,
+		0.520201734535,
+		0.439479451911,
+		0.391168522973,
+		0.370506156435,
+		0.361225871306,
+		0.35639544925,
+		0.364929494754,
+		0.387210373232,
+		0.422831632573,
+		0.424423666706,
+		0.426118212827,
+		0.455208348524,
+		0.479387138546,
+	];
+
+	#[test]
+	fn test_adx_wilder_ta_lib() {
+		let candles: Vec<Candle> = TA_LIB_CANDLES
+			.iter()
+			.map(|&(open, high, low, close)| Candle {
+				open,
+				high,
+				low,
+				close,
+				volume: 1.0,
+			})
+			.collect();
+
+		for &adxr_flag in &[false, true] {
+			let cfg = AverageDirectionalIndex {
+				di_length: 5,
+				adx_smoothing: 5,
+				adxr: adxr_flag,
+				wilder: true,
+				..AverageDirectionalIndex::default()
+			};
+
+			let results = cfg.over(&candles).unwrap();
+			assert_eq!(results[0].size().0, 3 + adxr_flag as u8);
+
+			for (i, result) in results.iter().enumerate() {
+				if i >= 9 {
+					assert_eq_float(TA_LIB_ADX[i - 9], result.value(0));
+				}
+
+				if i >= 5 {
+					assert_eq_float(TA_LIB_PLUS_DI[i - 5], result.value(1));
+					assert_eq_float(TA_LIB_MINUS_DI[i - 5], result.value(2));
+				}
+
+				if adxr_flag && i >= 13 {
+					assert_eq_float(TA_LIB_ADXR[i - 13], result.value(3));
+				}
+			}
+		}
 	}
 }
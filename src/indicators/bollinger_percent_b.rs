@@ -0,0 +1,233 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::bollinger_bands::{BollingerBands, BollingerBandsInstance};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{CrossAbove, CrossUnder, Lowest};
+
+/// Bollinger %B and Bandwidth
+///
+/// Values derived from the [`BollingerBands`](crate::indicators::BollingerBands):
+///
+/// `%B` = (`source` - `lower bound`) / (`upper bound` - `lower bound`)
+///
+/// `Bandwidth` = (`upper bound` - `lower bound`) / `middle line`
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_perce>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_width>
+///
+/// # 2 values
+///
+/// * `%B` value
+///
+/// Range in \(`-inf`; `+inf`\), mostly in \[`0.0`; `1.0`\]
+///
+/// * `Bandwidth` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When `%B` crosses `1.0` upwards, returns full buy signal.
+///   When `%B` crosses `0.0` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * Squeeze signal. When `Bandwidth` hits its lowest value over the last `squeeze_period` bars,
+///   returns full buy signal if `%B` is above `0.5` and full sell signal otherwise.
+///   Otherwise returns no signal.
+///   Bars with zero `Bandwidth` (e.g. flat series) are skipped and never produce squeeze signals.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BollingerPercentB {
+	/// Main period length. Default is `20`
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub avg_size: PeriodType,
+
+	/// Standard deviation multiplier for bounds. Default is `2.0`
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub sigma: ValueType,
+
+	/// Period for searching the lowest `Bandwidth` value. Default is `125`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub squeeze_period: PeriodType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl BollingerPercentB {
+	const fn bollinger_bands(self) -> BollingerBands {
+		BollingerBands {
+			avg_size: self.avg_size,
+			sigma: self.sigma,
+			source: self.source,
+		}
+	}
+}
+
+impl IndicatorConfig for BollingerPercentB {
+	type Instance = BollingerPercentBInstance;
+
+	const NAME: &'static str = "BollingerPercentB";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			bb: cfg.bollinger_bands().init(candle)?,
+			lowest: None,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.bollinger_bands().validate()
+			&& self.squeeze_period > 1
+			&& self.squeeze_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"avg_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.avg_size = value,
+			},
+			"sigma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.sigma = value,
+			},
+			"squeeze_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.squeeze_period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for BollingerPercentB {
+	fn default() -> Self {
+		Self {
+			avg_size: 20,
+			sigma: 2.0,
+			squeeze_period: 125,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct BollingerPercentBInstance {
+	cfg: BollingerPercentB,
+
+	bb: BollingerBandsInstance,
+	lowest: Option<Lowest>,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for BollingerPercentBInstance {
+	type Config = BollingerPercentB;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+
+		let bb = self.bb.next(candle);
+		let (upper, middle, lower) = (bb.value(0), bb.value(1), bb.value(2));
+
+		let range = upper - lower;
+		let percent_b = if range == 0.0 {
+			0.5
+		} else {
+			(source - lower) / range
+		};
+		let bandwidth = if middle == 0.0 { 0.0 } else { range / middle };
+
+		let signal1 =
+			self.cross_above.next((percent_b, 1.0)) - self.cross_under.next((percent_b, 0.0));
+
+		// the lowest bandwidth is seeded with the first non-zero bandwidth value
+		let is_squeeze = if bandwidth <= 0. {
+			false
+		} else if let Some(lowest) = &mut self.lowest {
+			bandwidth <= lowest.next(bandwidth)
+		} else {
+			self.lowest = Lowest::new(self.cfg.squeeze_period, bandwidth).ok();
+			false
+		};
+		let signal2 = is_squeeze as i8 * (((percent_b > 0.5) as i8) * 2 - 1);
+
+		IndicatorResult::new(&[percent_b, bandwidth], &[signal1, signal2.into()])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::BollingerPercentB;
+	use crate::core::{Action, Candle, IndicatorConfig, ValueType};
+
+	fn squeezes(closes: impl Iterator<Item = ValueType>) -> usize {
+		let candles: Vec<_> = closes
+			.map(|close| Candle {
+				close,
+				..Candle::default()
+			})
+			.collect();
+
+		BollingerPercentB {
+			avg_size: 3,
+			squeeze_period: 5,
+			..BollingerPercentB::default()
+		}
+		.over(&candles)
+		.unwrap()
+		.iter()
+		.filter(|r| r.signal(1) != Action::None)
+		.count()
+	}
+
+	#[test]
+	fn test_bollinger_percent_b_squeeze() {
+		// flat series has zero bandwidth, so there is nothing to squeeze
+		assert_eq!(squeezes((0..20).map(|_| 100.)), 0);
+
+		// widening swings never hit the lowest bandwidth after the first non-zero one
+		assert_eq!(
+			squeezes((0..20).map(|i| if i % 2 == 0 {
+				100. + i as ValueType
+			} else {
+				100. - i as ValueType
+			})),
+			0
+		);
+	}
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::Cross;
+
+/// John Ehlers' Center of Gravity oscillator
+///
+/// `CG` = -Σ(`(i + 1) * value[i]`) / Σ(`value[i]`) + (`period` + 1) / 2, where `value[0]` is the most recent value.
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/TheCGOscillator.pdf>
+///
+/// # 2 values
+///
+/// * `CG` value
+///
+/// Range in \(-`period`/2; `period`/2\) for positive `source` values.
+///
+/// * `Signal line` value (`CG` value of the previous bar)
+///
+/// Range is the same as for `CG` value.
+///
+/// # 1 signal
+///
+/// When `CG` crosses `signal line` upwards, returns full buy signal.
+/// When `CG` crosses `signal line` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CenterOfGravity {
+	/// Main period. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Source value type. Default is [`HL2`](crate::core::Source::HL2).
+	pub source: Source,
+}
+
+impl IndicatorConfig for CenterOfGravity {
+	type Instance = CenterOfGravityInstance;
+
+	const NAME: &'static str = "CenterOfGravity";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let length = cfg.period as ValueType;
+
+		Ok(Self::Instance {
+			length,
+			window: Window::new(cfg.period, src),
+			sum: src * length,
+			weighted_sum: src * length * (length + 1.) * 0.5,
+			last_cg: 0.,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for CenterOfGravity {
+	fn default() -> Self {
+		Self {
+			period: 10,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct CenterOfGravityInstance {
+	cfg: CenterOfGravity,
+
+	length: ValueType,
+	window: Window<ValueType>,
+	sum: ValueType,
+	weighted_sum: ValueType,
+	last_cg: ValueType,
+	cross: Cross,
+}
+
+/// Just an alias for `CenterOfGravity`
+pub type CG = CenterOfGravity;
+
+impl IndicatorInstance for CenterOfGravityInstance {
+	type Config = CenterOfGravity;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let past_value = self.window.push(src);
+
+		// every value inside the window gets its weight increased by 1, the oldest value leaves the window with weight `length`
+		self.sum += src - past_value;
+		self.weighted_sum += past_value.mul_add(-self.length, self.sum);
+
+		let cg = if self.sum == 0. {
+			0.
+		} else {
+			(self.length + 1.).mul_add(0.5, -self.weighted_sum / self.sum)
+		};
+
+		let sigline = self.last_cg;
+		self.last_cg = cg;
+
+		let signal = self.cross.next((cg, sigline));
+
+		IndicatorResult::new(&[cg, sigline], &[signal])
+	}
+}
@@ -0,0 +1,165 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{CrossAbove, CrossUnder, RateOfChange, EMA};
+
+/// Chaikin Volatility
+///
+/// Rate of change over `roc_period` bars of the `EMA` of length `ema_period` of the `high` - `low` range.
+///
+/// According to Marc Chaikin, sharp volatility increase over a short time indicates that the market bottom is near,
+/// while volatility decrease over a longer time indicates that the market top is near.
+///
+/// ## Links
+///
+/// * <https://www.metastock.com/customer/resources/taaz/?p=120>
+/// * <https://www.tradingview.com/support/solutions/43000501303-chaikin-volatility-cv/>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`-1.0`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * Spike signal
+///
+/// When `main` value crosses `spike_zone` upwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Contraction signal
+///
+/// When `main` value crosses -`contraction_zone` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChaikinVolatility {
+	/// Range smoothing period. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub ema_period: PeriodType,
+
+	/// Rate of change period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub roc_period: PeriodType,
+
+	/// Volatility spike zone. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub spike_zone: ValueType,
+
+	/// Volatility contraction zone. Default is `0.3`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub contraction_zone: ValueType,
+}
+
+impl IndicatorConfig for ChaikinVolatility {
+	type Instance = ChaikinVolatilityInstance;
+
+	const NAME: &'static str = "ChaikinVolatility";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let range = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			ema: EMA::new(cfg.ema_period, range)?,
+			roc: RateOfChange::new(cfg.roc_period, range)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ema_period > 1
+			&& self.ema_period < PeriodType::MAX
+			&& self.roc_period > 0
+			&& self.roc_period < PeriodType::MAX
+			&& self.spike_zone > 0.
+			&& self.contraction_zone > 0.
+			&& self.contraction_zone < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ema_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.ema_period = value,
+			},
+			"roc_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.roc_period = value,
+			},
+			"spike_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.spike_zone = value,
+			},
+			"contraction_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.contraction_zone = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for ChaikinVolatility {
+	fn default() -> Self {
+		Self {
+			ema_period: 10,
+			roc_period: 10,
+			spike_zone: 0.5,
+			contraction_zone: 0.3,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct ChaikinVolatilityInstance {
+	cfg: ChaikinVolatility,
+
+	ema: EMA,
+	roc: RateOfChange,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for ChaikinVolatilityInstance {
+	type Config = ChaikinVolatility;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let range = self.ema.next(candle.high() - candle.low());
+		let roc = self.roc.next(range);
+
+		// averaged range is zero only when there were no price moves at all
+		let value = if roc.is_finite() { roc } else { 0. };
+
+		let spike = self.cross_above.next((value, self.cfg.spike_zone));
+		let contraction = -self.cross_under.next((value, -self.cfg.contraction_zone));
+
+		IndicatorResult::new(&[value], &[spike, contraction])
+	}
+}
@@ -0,0 +1,126 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::linear_regression_channel::Regression;
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::Cross;
+
+/// Chande Forecast Oscillator
+///
+/// Relative difference between the `source` value and its linear regression forecast over the last `period` values:
+/// (`source` - `forecast`) / `source`.
+///
+/// ## Links
+///
+/// * <https://www.fmlabs.com/reference/default.htm?url=ForecastOscillator.htm>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \(`-inf`; `+inf`\) (`0.01` is `1%`)
+///
+/// # 1 signal
+///
+/// When `main` value crosses `0.0` upwards, returns full buy signal.
+/// When `main` value crosses `0.0` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChandeForecastOscillator {
+	/// Linear regression period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for ChandeForecastOscillator {
+	type Instance = ChandeForecastOscillatorInstance;
+
+	const NAME: &'static str = "ChandeForecastOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			regression: Regression::new(cfg.period, src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for ChandeForecastOscillator {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct ChandeForecastOscillatorInstance {
+	cfg: ChandeForecastOscillator,
+
+	regression: Regression,
+	cross: Cross,
+}
+
+impl IndicatorInstance for ChandeForecastOscillatorInstance {
+	type Config = ChandeForecastOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let forecast = self.regression.next(src).value;
+		let value = if src == 0. {
+			0.
+		} else {
+			(src - forecast) / src
+		};
+
+		let signal = self.cross.next((value, 0.));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
@@ -0,0 +1,193 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Highest, Lowest};
+
+/// Chandelier Exit
+///
+/// `stop long` = highest `high` - `multiplier` * `ATR`
+///
+/// `stop short` = lowest `low` + `multiplier` * `ATR`
+///
+/// While the trend lasts, `stop long` can only move up and `stop short` can only move down.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:chandelier_exit>
+/// * <https://www.tradingview.com/script/AqXxNS7j-Chandelier-Exit/>
+///
+/// # 3 values
+///
+/// * `stop long`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `stop short`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `direction`
+///
+/// `1.0` for uptrend, `-1.0` for downtrend.
+///
+/// # 1 signal
+///
+/// When `source` value crosses previous `stop short` upwards, returns full buy signal.
+/// When `source` value crosses previous `stop long` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChandelierExit {
+	/// ATR and highest/lowest period. Default is `22`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// ATR multiplier. Default is `3.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub multiplier: ValueType,
+
+	/// ATR method. Default is [`RMA`](crate::methods::RMA).
+	pub method: RegularMethods,
+
+	/// Source value type for crossing stop lines. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for ChandelierExit {
+	type Instance = ChandelierExitInstance;
+
+	const NAME: &'static str = "ChandelierExit";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let tr = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			prev_close: candle.close(),
+			prev_src: candle.source(cfg.source),
+			atr: method(cfg.method, cfg.period, tr)?,
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			stop_long: cfg.multiplier.mul_add(-tr, candle.high()),
+			stop_short: cfg.multiplier.mul_add(tr, candle.low()),
+			direction: 1,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.multiplier > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.multiplier = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for ChandelierExit {
+	fn default() -> Self {
+		Self {
+			period: 22,
+			multiplier: 3.0,
+			method: RegularMethods::RMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct ChandelierExitInstance {
+	cfg: ChandelierExit,
+
+	prev_close: ValueType,
+	prev_src: ValueType,
+	atr: RegularMethod,
+	highest: Highest,
+	lowest: Lowest,
+	stop_long: ValueType,
+	stop_short: ValueType,
+	direction: i8,
+}
+
+impl IndicatorInstance for ChandelierExitInstance {
+	type Config = ChandelierExit;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+
+		let atr = self.atr.next(tr) * self.cfg.multiplier;
+		let highest = self.highest.next(candle.high());
+		let lowest = self.lowest.next(candle.low());
+
+		let src = candle.source(self.cfg.source);
+
+		let mut stop_long = highest - atr;
+		if self.prev_src > self.stop_long {
+			stop_long = stop_long.max(self.stop_long);
+		}
+
+		let mut stop_short = lowest + atr;
+		if self.prev_src < self.stop_short {
+			stop_short = stop_short.min(self.stop_short);
+		}
+
+		let prev_direction = self.direction;
+		if src > self.stop_short {
+			self.direction = 1;
+		} else if src < self.stop_long {
+			self.direction = -1;
+		}
+
+		self.prev_src = src;
+		self.stop_long = stop_long;
+		self.stop_short = stop_short;
+
+		let signal = (self.direction != prev_direction) as i8 * self.direction;
+
+		IndicatorResult::new(
+			&[stop_long, stop_short, self.direction as ValueType],
+			&[signal.into()],
+		)
+	}
+}
@@ -0,0 +1,406 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use super::linear_regression_channel::Regression;
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{CrossAbove, CrossUnder, StDev};
+
+/// Width source of the [`Channel`] bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChannelWidth {
+	/// `k` standard deviations of the `source` values over the last `width_period` values.
+	StDev,
+
+	/// `k` average true ranges of length `width_period`, averaged by `width_method`.
+	ATR,
+
+	/// `k` parts of the middle line value.
+	Percent,
+
+	/// `k` standard errors of the linear regression estimate over the last `width_period` values.
+	StdError,
+}
+
+impl FromStr for ChannelWidth {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"st_dev" | "stdev" => Ok(Self::StDev),
+			"atr" => Ok(Self::ATR),
+			"percent" => Ok(Self::Percent),
+			"std_error" | "stderror" => Ok(Self::StdError),
+
+			value => Err(Error::ParameterParse(
+				"width".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Generic price channel
+///
+/// Middle line is any [regular method](RegularMethods) over the `source` values.
+/// Bounds are placed at the `width` distance above and below the middle line.
+///
+/// [Bollinger Bands](crate::indicators::BollingerBands), [Keltner Channel](crate::indicators::KeltnerChannel),
+/// [STARC Bands](crate::indicators::STARCBands) and [Envelopes](crate::indicators::Envelopes) are special cases of the channel,
+/// available through the [`bollinger`](Channel::bollinger), [`keltner`](Channel::keltner), [`starc`](Channel::starc)
+/// and [`envelopes`](Channel::envelopes) constructors. Any other combination makes a hybrid channel.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/c/channel.asp>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{RandomCandles, RegularMethods};
+/// use yata::indicators::{Channel, ChannelWidth};
+///
+/// // hull moving average with the standard error bands
+/// let channel = Channel {
+///     method: RegularMethods::HMA,
+///     width: ChannelWidth::StdError,
+///     ..Channel::bollinger(20, 2.0)
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = channel.over(&candles).unwrap();
+///
+/// let (upper, middle, lower) = (results[99].value(0), results[99].value(1), results[99].value(2));
+/// assert!(upper >= middle && middle >= lower);
+/// ```
+///
+/// # 3 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `middle line`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 2 signals
+///
+/// * Cross signal
+///
+/// When `source` value crosses `lower bound` downwards, returns full buy signal.
+/// When `source` value crosses `upper bound` upwards, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// * Touch signal
+///
+/// When `low` value touches `lower bound`, returns full buy signal.
+/// When `high` value touches `upper bound`, returns full sell signal.
+/// When both bounds are touched or none of them, returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Channel {
+	/// Middle line period. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Middle line method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Width source of the bounds. Default is [`StDev`](ChannelWidth::StDev).
+	pub width: ChannelWidth,
+
+	/// Width period. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\). Not used for [`Percent`](ChannelWidth::Percent) width.
+	pub width_period: PeriodType,
+
+	/// True range averaging method. Default is [`RMA`](crate::methods::RMA).
+	///
+	/// Used only for [`ATR`](ChannelWidth::ATR) width.
+	pub width_method: RegularMethods,
+
+	/// Width multiplier. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\) and \(`0.0`; `1.0`\) for [`Percent`](ChannelWidth::Percent) width.
+	pub k: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl Channel {
+	/// [Bollinger Bands](crate::indicators::BollingerBands): `SMA` of length `period` with `k` standard deviations bounds
+	#[must_use]
+	pub const fn bollinger(period: PeriodType, k: ValueType) -> Self {
+		Self {
+			period,
+			method: RegularMethods::SMA,
+			width: ChannelWidth::StDev,
+			width_period: period,
+			width_method: RegularMethods::RMA,
+			k,
+			source: Source::Close,
+		}
+	}
+
+	/// [Keltner Channel](crate::indicators::KeltnerChannel): `EMA` of length `period` with `k` average true ranges bounds
+	#[must_use]
+	pub const fn keltner(period: PeriodType, k: ValueType) -> Self {
+		Self {
+			period,
+			method: RegularMethods::EMA,
+			width: ChannelWidth::ATR,
+			width_period: period,
+			width_method: RegularMethods::SMA,
+			k,
+			source: Source::Close,
+		}
+	}
+
+	/// [STARC Bands](crate::indicators::STARCBands): `SMA` of length `ma_period` with `k` average true ranges of length `atr_period` bounds
+	#[must_use]
+	pub const fn starc(ma_period: PeriodType, atr_period: PeriodType, k: ValueType) -> Self {
+		Self {
+			period: ma_period,
+			method: RegularMethods::SMA,
+			width: ChannelWidth::ATR,
+			width_period: atr_period,
+			width_method: RegularMethods::RMA,
+			k,
+			source: Source::Close,
+		}
+	}
+
+	/// [Envelopes](crate::indicators::Envelopes): `SMA` of length `period` with bounds at `k` parts of its value
+	#[must_use]
+	pub const fn envelopes(period: PeriodType, k: ValueType) -> Self {
+		Self {
+			period,
+			method: RegularMethods::SMA,
+			width: ChannelWidth::Percent,
+			width_period: period,
+			width_method: RegularMethods::RMA,
+			k,
+			source: Source::Close,
+		}
+	}
+}
+
+impl IndicatorConfig for Channel {
+	type Instance = ChannelInstance;
+
+	const NAME: &'static str = "Channel";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		let width = match cfg.width {
+			ChannelWidth::StDev => Width::StDev(StDev::new(cfg.width_period, src)?),
+			ChannelWidth::ATR => Width::TrueRange(method(
+				cfg.width_method,
+				cfg.width_period,
+				candle.high() - candle.low(),
+			)?),
+			ChannelWidth::Percent => Width::Percent,
+			ChannelWidth::StdError => Width::StdError(Regression::new(cfg.width_period, src)?),
+		};
+
+		Ok(Self::Instance {
+			prev_close: candle.close(),
+			ma: method(cfg.method, cfg.period, src)?,
+			width,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		let k = match self.width {
+			ChannelWidth::Percent => self.k > 0. && self.k < 1.,
+			_ => self.k > 0. && self.width_period > 1 && self.width_period < PeriodType::MAX,
+		};
+
+		k && self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"width" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.width = value,
+			},
+			"width_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.width_period = value,
+			},
+			"width_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.width_method = value,
+			},
+			"k" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.k = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl Default for Channel {
+	fn default() -> Self {
+		Self::bollinger(20, 2.0)
+	}
+}
+
+#[derive(Debug)]
+enum Width {
+	StDev(StDev),
+	TrueRange(RegularMethod),
+	Percent,
+	StdError(Regression),
+}
+
+#[derive(Debug)]
+pub struct ChannelInstance {
+	cfg: Channel,
+
+	prev_close: ValueType,
+	ma: RegularMethod,
+	width: Width,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for ChannelInstance {
+	type Config = Channel;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+
+		let middle = self.ma.next(src);
+
+		let width = match &mut self.width {
+			Width::StDev(st_dev) => st_dev.next(src),
+			Width::TrueRange(atr) => atr.next(tr),
+			Width::Percent => middle.abs(),
+			Width::StdError(regression) => regression.next(src).std_error,
+		};
+
+		let upper = width.mul_add(self.cfg.k, middle);
+		let lower = width.mul_add(-self.cfg.k, middle);
+
+		let cross = self.cross_under.next((src, lower)) - self.cross_above.next((src, upper));
+		let touch = (candle.low() <= lower) as i8 - (candle.high() >= upper) as i8;
+
+		IndicatorResult::new(&[upper, middle, lower], &[cross, touch.into()])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Channel, ChannelWidth};
+	use crate::core::{IndicatorConfig, IndicatorInstance};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{BollingerBands, Envelopes, STARCBands};
+
+	#[test]
+	fn test_special_cases() {
+		let candles: Vec<_> = RandomCandles::new().take(200).collect();
+
+		let channel = Channel::starc(6, 15, 2.0).over(&candles).unwrap();
+		let starc = STARCBands::default().over(&candles).unwrap();
+		for (a, b) in channel.iter().zip(&starc) {
+			(0..3).for_each(|i| assert_eq_float(a.value(i), b.value(i)));
+		}
+
+		let channel = Channel::envelopes(20, 0.1).over(&candles).unwrap();
+		let envelopes = Envelopes::default().over(&candles).unwrap();
+		for (a, b) in channel.iter().zip(&envelopes) {
+			assert_eq_float(a.value(0), b.value(0));
+			assert_eq_float(a.value(2), b.value(1));
+		}
+
+		let channel = Channel::bollinger(20, 2.0).over(&candles).unwrap();
+		let bollinger = BollingerBands::default().over(&candles).unwrap();
+		for (a, b) in channel.iter().zip(&bollinger) {
+			assert_eq_float(a.value(0), b.value(0));
+			assert_eq_float(a.value(2), b.value(2));
+		}
+	}
+
+	#[test]
+	fn test_signals() {
+		let candles: Vec<_> = RandomCandles::new().take(200).collect();
+
+		for &width in &[
+			ChannelWidth::StDev,
+			ChannelWidth::ATR,
+			ChannelWidth::StdError,
+		] {
+			let cfg = Channel {
+				width,
+				k: 1.0,
+				..Channel::default()
+			};
+
+			let mut instance = cfg.init(&candles[0]).unwrap();
+			for candle in &candles {
+				let result = instance.next(candle);
+				let (upper, lower) = (result.value(0), result.value(2));
+				assert!(upper >= lower);
+
+				let touch = result.signal(1).analog();
+				if touch > 0 {
+					assert!(candle.low <= lower);
+				} else if touch < 0 {
+					assert!(candle.high >= upper);
+				}
+			}
+		}
+	}
+}
@@ -97,10 +97,12 @@ impl IndicatorConfig for CoppockCurve {
 
 	fn validate(&self) -> bool {
 		self.period1 > 1
+			&& self.period1 < PeriodType::MAX
 			&& self.period2 > self.period3
 			&& self.period2 < PeriodType::MAX
 			&& self.period3 > 0
 			&& self.s3_period > 1
+			&& self.s3_period < PeriodType::MAX
 			&& self.s2_left > 0
 			&& self.s2_right > 0
 			&& self.s2_left.saturating_add(self.s2_right) < PeriodType::MAX
@@ -209,3 +211,48 @@ impl IndicatorInstance for CoppockCurveInstance {
 		IndicatorResult::new(&[value1, value2], &[signal1, signal2, signal3])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::CoppockCurve;
+	use crate::core::{Candle, IndicatorConfig, PeriodType};
+
+	#[test]
+	fn test_coppock_curve_config() {
+		let candle = Candle {
+			open: 100.,
+			high: 100.,
+			low: 100.,
+			close: 100.,
+			volume: 1.,
+		};
+
+		let default = CoppockCurve::default();
+		assert!(default.init(&candle).is_ok());
+
+		let configs = [
+			CoppockCurve {
+				period1: PeriodType::MAX,
+				..default
+			},
+			CoppockCurve {
+				period2: PeriodType::MAX,
+				..default
+			},
+			CoppockCurve {
+				s3_period: PeriodType::MAX,
+				..default
+			},
+			CoppockCurve {
+				s2_left: PeriodType::MAX / 2 + 1,
+				s2_right: PeriodType::MAX / 2,
+				..default
+			},
+		];
+
+		for cfg in configs {
+			assert!(!cfg.validate());
+			assert!(cfg.init(&candle).is_err());
+		}
+	}
+}
@@ -0,0 +1,215 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Cumulative Volume Delta
+///
+/// Accumulates [volume delta](crate::core::OHLCV::volume_delta) (buy volume minus sell volume) of every candle.
+///
+/// Candles with known buy/sell volume split (e.g. [`DeltaCandle`](crate::core::DeltaCandle)) give the real order flow.
+/// For regular candles volume split is estimated by the close location value (see [`OHLCV::buy_volume`]).
+///
+/// Divergence signal compares the `close` value and `CVD` with their values over the previous `period` candles:
+/// when price makes new high, but `CVD` does not, buyers are exhausted; when price makes new low, but `CVD` does not, sellers are exhausted.
+/// For pivot-based divergences wrap the indicator into [`Divergence`](crate::indicators::Divergence).
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000725058-cumulative-volume-delta/>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::DeltaCandle;
+/// use yata::indicators::CumulativeVolumeDelta;
+///
+/// let candles = [
+///     DeltaCandle { open: 10.0, high: 11.0, low: 9.0, close: 10.5, buy_volume: 30.0, sell_volume: 10.0 },
+///     DeltaCandle { open: 10.5, high: 12.0, low: 10.0, close: 11.5, buy_volume: 10.0, sell_volume: 25.0 },
+/// ];
+///
+/// let results = CumulativeVolumeDelta::default().over(&candles).unwrap();
+///
+/// assert_eq!(results[0].value(0), 20.0);
+/// assert_eq!(results[1].value(0), 5.0);
+/// assert_eq!(results[1].value(1), -15.0);
+/// ```
+///
+/// # 3 values
+///
+/// * `CVD` value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * Volume delta of the current candle
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * Ratio of the volume delta to the volume of the current candle
+///
+/// Range in \[`-1.0`; `1.0`\]. `0.0` when `volume` is zero.
+///
+/// # 1 signal
+///
+/// * Divergence signal
+///
+/// When `close` value is greater than every `close` value over the previous `period` candles, but `CVD` is not greater than every previous `CVD` value,
+/// returns full sell signal.
+/// When `close` value is less than every `close` value over the previous `period` candles, but `CVD` is not less than every previous `CVD` value,
+/// returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CumulativeVolumeDelta {
+	/// Count of previous candles to search divergence over. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for CumulativeVolumeDelta {
+	type Instance = CumulativeVolumeDeltaInstance;
+
+	const NAME: &'static str = "CumulativeVolumeDelta";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			cvd: 0.,
+			window: Window::new(cfg.period, (candle.close(), 0.)),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for CumulativeVolumeDelta {
+	fn default() -> Self {
+		Self { period: 20 }
+	}
+}
+
+#[derive(Debug)]
+pub struct CumulativeVolumeDeltaInstance {
+	cfg: CumulativeVolumeDelta,
+
+	cvd: ValueType,
+	/// previous `close` and `CVD` values
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl IndicatorInstance for CumulativeVolumeDeltaInstance {
+	type Config = CumulativeVolumeDelta;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let close = candle.close();
+		let volume = candle.volume();
+		let delta = candle.volume_delta();
+		self.cvd += delta;
+
+		let ratio = if volume > 0. { delta / volume } else { 0. };
+
+		let (mut price_high, mut price_low) = (true, true);
+		let (mut cvd_high, mut cvd_low) = (true, true);
+
+		for (prev_close, prev_cvd) in &self.window {
+			price_high &= close > prev_close;
+			price_low &= close < prev_close;
+			cvd_high &= self.cvd > prev_cvd;
+			cvd_low &= self.cvd < prev_cvd;
+		}
+
+		let signal = if price_high && !cvd_high {
+			Action::SELL_ALL
+		} else if price_low && !cvd_low {
+			Action::BUY_ALL
+		} else {
+			Action::None
+		};
+
+		self.window.push((close, self.cvd));
+
+		IndicatorResult::new(&[self.cvd, delta, ratio], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CumulativeVolumeDelta;
+	use crate::core::{Action, DeltaCandle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(close: ValueType, buy_volume: ValueType, sell_volume: ValueType) -> DeltaCandle {
+		DeltaCandle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			buy_volume,
+			sell_volume,
+		}
+	}
+
+	#[test]
+	fn test_cumulative_volume_delta_divergence() {
+		let mut instance = CumulativeVolumeDelta { period: 3 }
+			.init(&candle(10., 0., 0.))
+			.unwrap();
+
+		// price and CVD rise together
+		for close in &[11., 12., 13.] {
+			let result = instance.next(&candle(*close, 10., 5.));
+			assert_eq!(result.signal(0), Action::None);
+		}
+
+		// new price high with selling pressure
+		let result = instance.next(&candle(14., 5., 10.));
+		assert_eq_float(10., result.value(0));
+		assert_eq_float(-5. / 15., result.value(2));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+
+		// new price low, while CVD stays above its low
+		let mut instance = CumulativeVolumeDelta { period: 3 }
+			.init(&candle(10., 0., 0.))
+			.unwrap();
+		for close in &[9., 8., 7.] {
+			instance.next(&candle(*close, 5., 10.));
+		}
+		let result = instance.next(&candle(6., 10., 5.));
+		assert_eq!(result.signal(0), Action::BUY_ALL);
+	}
+}
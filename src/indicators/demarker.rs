@@ -0,0 +1,154 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::ZoneCross;
+
+/// `DeMarker` indicator (`DeM`)
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/d/demarkerindicator.asp>
+/// * <https://www.metatrader5.com/en/terminal/help/indicators/oscillators/dm>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses upper zone upwards, returns full sell signal.
+/// When main value crosses lower zone downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses upper zone downwards, returns full sell signal.
+/// When main value crosses lower zone upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeMarker {
+	/// Main period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Overbought/oversold relative zone. Default is `0.3`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// `DeMax` and `DeMin` moving average method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+}
+
+impl IndicatorConfig for DeMarker {
+	type Instance = DeMarkerInstance;
+
+	const NAME: &'static str = "DeMarker";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_high: candle.high(),
+			prev_low: candle.low(),
+			max_ma: method(cfg.method, cfg.period, 0.)?,
+			min_ma: method(cfg.method, cfg.period, 0.)?,
+			zone_cross: ZoneCross::new((cfg.zone, 1.0 - cfg.zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.zone > 0. && self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for DeMarker {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			zone: 0.3,
+			method: RegularMethods::SMA,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct DeMarkerInstance {
+	cfg: DeMarker,
+
+	prev_high: ValueType,
+	prev_low: ValueType,
+	max_ma: RegularMethod,
+	min_ma: RegularMethod,
+	zone_cross: ZoneCross,
+}
+
+/// Just an alias for `DeMarker`
+pub type DeM = DeMarker;
+
+impl IndicatorInstance for DeMarkerInstance {
+	type Config = DeMarker;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let de_max = (candle.high() - self.prev_high).max(0.);
+		let de_min = (self.prev_low - candle.low()).max(0.);
+
+		self.prev_high = candle.high();
+		self.prev_low = candle.low();
+
+		let max_ma = self.max_ma.next(de_max);
+		let min_ma = self.min_ma.next(de_min);
+
+		let sum = max_ma + min_ma;
+		let value = if sum > 0. { max_ma / sum } else { 0.5 };
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
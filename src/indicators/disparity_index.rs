@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::ZoneCross;
+
+/// Disparity Index
+///
+/// Relative distance of the `source` value from its moving average: (`source` - `MA`) / `MA`.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/d/disparityindex.asp>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \(`-inf`; `+inf`\) (`0.01` is `1%`)
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses `zone` upwards, returns full sell signal.
+/// When main value crosses -`zone` downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses `zone` downwards, returns full sell signal.
+/// When main value crosses -`zone` upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisparityIndex {
+	/// MA period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// MA method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Over-zone relative distance. Default is `0.05`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub zone: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for DisparityIndex {
+	type Instance = DisparityIndexInstance;
+
+	const NAME: &'static str = "DisparityIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, src)?,
+			zone_cross: ZoneCross::new((-cfg.zone, cfg.zone), 0.)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.zone > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for DisparityIndex {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			method: RegularMethods::SMA,
+			zone: 0.05,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct DisparityIndexInstance {
+	cfg: DisparityIndex,
+
+	ma: RegularMethod,
+	zone_cross: ZoneCross,
+}
+
+impl IndicatorInstance for DisparityIndexInstance {
+	type Config = DisparityIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let ma = self.ma.next(src);
+
+		let value = if ma == 0. { 0. } else { (src - ma) / ma };
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
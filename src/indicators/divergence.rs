@@ -0,0 +1,416 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Divergence
+///
+/// Pairs price with any oscillator `indicator` and searches for divergences between them.
+///
+/// Pivots are searched over the oscillator value with index `divergence_index`: pivot low (high) is the lowest (highest) value
+/// among `divergence_left` values before it and `divergence_right` values after it. Every new pivot is matched with the previous pivot
+/// of the same kind, if the previous one is not older than `divergence_lookback` bars:
+///
+/// * regular bullish divergence: oscillator makes higher low while price `low` makes lower low;
+/// * hidden bullish divergence: oscillator makes lower low while price `low` makes higher low;
+/// * regular bearish divergence: oscillator makes lower high while price `high` makes higher high;
+/// * hidden bearish divergence: oscillator makes higher high while price `high` makes lower high.
+///
+/// Pivot is confirmed only after `divergence_right` bars, so are the signals.
+///
+/// Parameters of the inner `indicator` may be changed by [`set`](crate::core::IndicatorConfig::set) the same way as for the `indicator` itself.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/d/divergence.asp>
+/// * <https://www.babypips.com/learn/forex/divergence-cheat-sheet>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{Divergence, RSI};
+///
+/// let divergence = Divergence {
+///     indicator: RSI::default(),
+///     divergence_lookback: 30,
+///     ..Divergence::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = divergence.over(&candles).unwrap();
+///
+/// assert_eq!(results[99].size(), (1, 2));
+/// ```
+///
+/// # Values
+///
+/// The same values as the inner `indicator` has.
+///
+/// # 2 signals
+///
+/// * Regular divergence.
+///
+/// On regular bullish divergence returns full buy signal.
+/// On regular bearish divergence returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// * Hidden divergence.
+///
+/// On hidden bullish divergence returns full buy signal.
+/// On hidden bearish divergence returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Divergence<I> {
+	/// Inner oscillator indicator.
+	pub indicator: I,
+
+	/// Index of the inner indicator's value to search pivots over. Default is `0`.
+	///
+	/// Range in \[`0`; `values count`\)
+	pub divergence_index: u8,
+
+	/// Count of values before pivot. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `divergence_right`\)
+	pub divergence_left: PeriodType,
+
+	/// Count of values after pivot. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) - `divergence_left`\)
+	pub divergence_right: PeriodType,
+
+	/// Maximum distance in bars between two matched pivots. Default is `60`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub divergence_lookback: PeriodType,
+}
+
+impl<I: IndicatorConfig> IndicatorConfig for Divergence<I> {
+	type Instance = DivergenceInstance<I>;
+
+	const NAME: &'static str = "Divergence";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		// first output of the inner indicator is used to fill the pivots window
+		let first = cfg.indicator.clone().init(candle)?.next(candle);
+		let point = Point {
+			osc: first.value(cfg.divergence_index as usize),
+			high: candle.high(),
+			low: candle.low(),
+		};
+
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			window: Window::new(cfg.divergence_left + cfg.divergence_right + 1, point),
+			last_low: None,
+			last_high: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.indicator.validate()
+			&& self.divergence_index < self.indicator.size().0
+			&& self.divergence_left > 0
+			&& self.divergence_right > 0
+			&& self.divergence_left.saturating_add(self.divergence_right) < PeriodType::MAX
+			&& self.divergence_lookback > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"divergence_index" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.divergence_index = value,
+			},
+			"divergence_left" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.divergence_left = value,
+			},
+			"divergence_right" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.divergence_right = value,
+			},
+			"divergence_lookback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.divergence_lookback = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(self.indicator.size().0, 2)
+	}
+}
+
+impl<I: Default> Default for Divergence<I> {
+	fn default() -> Self {
+		Self {
+			indicator: I::default(),
+			divergence_index: 0,
+			divergence_left: 5,
+			divergence_right: 5,
+			divergence_lookback: 60,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+	osc: ValueType,
+	high: ValueType,
+	low: ValueType,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pivot {
+	osc: ValueType,
+	price: ValueType,
+	age: PeriodType,
+}
+
+#[derive(Debug)]
+pub struct DivergenceInstance<I: IndicatorConfig> {
+	cfg: Divergence<I>,
+
+	instance: I::Instance,
+	window: Window<Point>,
+	last_low: Option<Pivot>,
+	last_high: Option<Pivot>,
+}
+
+impl<I: IndicatorConfig> DivergenceInstance<I> {
+	/// Returns (`is pivot low`, `is pivot high`) for the point `divergence_right` bars ago
+	fn pivots(&self, center: Point) -> (bool, bool) {
+		let right = self.cfg.divergence_right as usize;
+
+		self.window
+			.iter()
+			.enumerate()
+			.filter(|&(index, _)| index != right)
+			.fold((true, true), |(is_low, is_high), (index, point)| {
+				// values after pivot must be strictly greater (lower), so flat extremes are counted only once
+				if index < right {
+					(
+						is_low && point.osc > center.osc,
+						is_high && point.osc < center.osc,
+					)
+				} else {
+					(
+						is_low && point.osc >= center.osc,
+						is_high && point.osc <= center.osc,
+					)
+				}
+			})
+	}
+
+	fn matched(
+		last: &mut Option<Pivot>,
+		pivot: Pivot,
+		lookback: PeriodType,
+	) -> Option<(ValueType, ValueType)> {
+		let previous = last.replace(pivot)?;
+
+		if previous.age > lookback {
+			return None;
+		}
+
+		Some((pivot.osc - previous.osc, pivot.price - previous.price))
+	}
+}
+
+impl<I: IndicatorConfig> IndicatorInstance for DivergenceInstance<I> {
+	type Config = Divergence<I>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		self.window.push(Point {
+			osc: result.value(self.cfg.divergence_index as usize),
+			high: candle.high(),
+			low: candle.low(),
+		});
+
+		for pivot in self.last_low.iter_mut().chain(self.last_high.iter_mut()) {
+			pivot.age = pivot.age.saturating_add(1);
+		}
+
+		let center = self.window[self.cfg.divergence_right];
+		let (is_low, is_high) = self.pivots(center);
+		let lookback = self.cfg.divergence_lookback;
+
+		let (mut regular, mut hidden) = (0, 0);
+
+		if is_low {
+			let pivot = Pivot {
+				osc: center.osc,
+				price: center.low,
+				age: 0,
+			};
+
+			if let Some((osc, price)) = Self::matched(&mut self.last_low, pivot, lookback) {
+				regular += (osc > 0. && price < 0.) as i8;
+				hidden += (osc < 0. && price > 0.) as i8;
+			}
+		}
+
+		if is_high {
+			let pivot = Pivot {
+				osc: center.osc,
+				price: center.high,
+				age: 0,
+			};
+
+			if let Some((osc, price)) = Self::matched(&mut self.last_high, pivot, lookback) {
+				regular -= (osc < 0. && price > 0.) as i8;
+				hidden -= (osc > 0. && price < 0.) as i8;
+			}
+		}
+
+		IndicatorResult::new(result.values(), &[regular.into(), hidden.into()])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Divergence;
+	use crate::core::{
+		Candle, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV,
+	};
+
+	// Oscillator which values are just candles' `open` values
+	#[derive(Debug, Clone, Copy, Default)]
+	struct Open;
+
+	#[derive(Debug)]
+	struct OpenInstance(Open);
+
+	impl IndicatorConfig for Open {
+		type Instance = OpenInstance;
+
+		const NAME: &'static str = "Open";
+
+		fn init<T: OHLCV>(self, _candle: &T) -> Result<Self::Instance, Error> {
+			Ok(OpenInstance(self))
+		}
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+			Err(Error::ParameterParse(name.to_string(), value))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(1, 0)
+		}
+	}
+
+	impl IndicatorInstance for OpenInstance {
+		type Config = Open;
+
+		fn config(&self) -> &Self::Config {
+			&self.0
+		}
+
+		fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+			IndicatorResult::new(&[candle.open()], &[])
+		}
+	}
+
+	fn signals(osc: &[ValueType], price: &[ValueType]) -> Vec<(i8, i8)> {
+		let candles: Vec<_> = osc
+			.iter()
+			.zip(price)
+			.map(|(&open, &price)| Candle {
+				open,
+				high: price,
+				low: price,
+				close: price,
+				volume: 1.0,
+			})
+			.collect();
+
+		let divergence = Divergence {
+			indicator: Open,
+			divergence_left: 2,
+			divergence_right: 2,
+			divergence_lookback: 10,
+			..Divergence::default()
+		};
+
+		let mut instance = divergence.init(&candles[0]).unwrap();
+
+		candles
+			.iter()
+			.map(|candle| {
+				let result = instance.next(candle);
+				(result.signal(0).analog(), result.signal(1).analog())
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_divergence_bullish() {
+		let osc = [5., 5., 5., 2., 5., 5., 5., 3., 5., 5., 5.];
+		let regular = [10., 10., 10., 8., 10., 10., 10., 7., 10., 10., 10.];
+		let hidden = [10., 10., 10., 8., 10., 10., 10., 9., 10., 10., 10.];
+
+		let result = signals(&osc, &regular);
+		assert_eq!(result[9], (1, 0));
+		assert_eq!(result.iter().filter(|&&s| s != (0, 0)).count(), 1);
+
+		let osc = [5., 5., 5., 3., 5., 5., 5., 2., 5., 5., 5.];
+		let result = signals(&osc, &hidden);
+		assert_eq!(result[9], (0, 1));
+		assert_eq!(result.iter().filter(|&&s| s != (0, 0)).count(), 1);
+	}
+
+	#[test]
+	fn test_divergence_bearish() {
+		let osc = [5., 5., 5., 8., 5., 5., 5., 7., 5., 5., 5.];
+		let regular = [10., 10., 10., 12., 10., 10., 10., 13., 10., 10., 10.];
+		let hidden = [10., 10., 10., 12., 10., 10., 10., 11., 10., 10., 10.];
+
+		let result = signals(&osc, &regular);
+		assert_eq!(result[9], (-1, 0));
+		assert_eq!(result.iter().filter(|&&s| s != (0, 0)).count(), 1);
+
+		let osc = [5., 5., 5., 7., 5., 5., 5., 8., 5., 5., 5.];
+		let result = signals(&osc, &hidden);
+		assert_eq!(result[9], (0, -1));
+		assert_eq!(result.iter().filter(|&&s| s != (0, 0)).count(), 1);
+	}
+
+	#[test]
+	fn test_divergence_lookback() {
+		let mut osc = vec![5.; 30];
+		let mut price = vec![10.; 30];
+
+		osc[3] = 2.;
+		price[3] = 8.;
+		osc[20] = 3.;
+		price[20] = 7.;
+
+		assert!(signals(&osc, &price).iter().all(|&s| s == (0, 0)));
+	}
+}
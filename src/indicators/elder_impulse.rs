@@ -0,0 +1,204 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::macd::{MACDInstance, MACD};
+use crate::core::{Error, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Elder Impulse System
+///
+/// Combines the direction of the moving average with the direction of the [`MACD`](crate::indicators::MACD) histogram.
+///
+/// When both are rising, the bar is `green`. When both are falling, the bar is `red`. Otherwise the bar is `blue`.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=chart_analysis:elder_impulse_system>
+///
+/// # 3 values
+///
+/// * `state` value
+///
+/// `1.0` for `green`, `-1.0` for `red` and `0.0` for `blue`
+///
+/// * moving average value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `MACD` histogram value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `state` becomes `green`, returns full buy signal.
+/// When `state` becomes `red`, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderImpulse {
+	/// Moving average period. Default is `13`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Moving average type. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// `MACD` fast MA period. Default is `12`.
+	///
+	/// Range in \[`2`; `period2`\)
+	pub period1: PeriodType,
+
+	/// `MACD` slow MA period. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// `MACD` signal line MA period. Default is `9`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl ElderImpulse {
+	const fn macd(self) -> MACD {
+		MACD {
+			period1: self.period1,
+			method1: RegularMethods::EMA,
+			period2: self.period2,
+			method2: RegularMethods::EMA,
+			period3: self.period3,
+			method3: RegularMethods::EMA,
+			source: self.source,
+		}
+	}
+}
+
+impl IndicatorConfig for ElderImpulse {
+	type Instance = ElderImpulseInstance;
+
+	const NAME: &'static str = "ElderImpulse";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, src)?,
+			macd: cfg.macd().init(candle)?,
+			prev_ma: src,
+			prev_histogram: 0.,
+			state: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.macd().validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"period1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for ElderImpulse {
+	fn default() -> Self {
+		Self {
+			period: 13,
+			method: RegularMethods::EMA,
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct ElderImpulseInstance {
+	cfg: ElderImpulse,
+
+	ma: RegularMethod,
+	macd: MACDInstance,
+	prev_ma: ValueType,
+	prev_histogram: ValueType,
+	state: i8,
+}
+
+impl IndicatorInstance for ElderImpulseInstance {
+	type Config = ElderImpulse;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let ma = self.ma.next(src);
+		let macd = self.macd.next(candle);
+		let histogram = macd.value(0) - macd.value(1);
+
+		let ma_direction = (ma > self.prev_ma) as i8 - (ma < self.prev_ma) as i8;
+		let histogram_direction =
+			(histogram > self.prev_histogram) as i8 - (histogram < self.prev_histogram) as i8;
+		self.prev_ma = ma;
+		self.prev_histogram = histogram;
+
+		let state = if ma_direction == histogram_direction {
+			ma_direction
+		} else {
+			0
+		};
+
+		let signal = if state == self.state { 0 } else { state };
+		self.state = state;
+
+		IndicatorResult::new(&[state.into(), ma, histogram], &[signal.into()])
+	}
+}
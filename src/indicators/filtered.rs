@@ -0,0 +1,242 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Confirm, Cooldown, Debounce};
+
+/// Filtered indicator
+///
+/// Wraps any `indicator` and conditions its selected signals to reduce whipsaws. Every selected signal is passed through:
+///
+/// 1. [`Debounce`] of length `filter_debounce`: signal must be sustained for `filter_debounce` bars;
+/// 2. [`Confirm`] of length `filter_confirm`: signal must be repeated within `filter_confirm` bars (when `filter_confirm` is not `0`);
+/// 3. [`Cooldown`] of length `filter_cooldown`: no signals for `filter_cooldown` bars after the last signal.
+///
+/// Parameters of the inner `indicator` may be changed by [`set`](crate::core::IndicatorConfig::set) the same way as for the `indicator` itself.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{Filtered, MACD};
+///
+/// // MACD signals with at least 10 bars between them
+/// let filtered = Filtered {
+///     indicator: MACD::default(),
+///     filter_cooldown: 10,
+///     ..Filtered::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = filtered.over(&candles).unwrap();
+/// ```
+///
+/// # Values
+///
+/// The same values as the inner `indicator` has.
+///
+/// # Signals
+///
+/// The same signals as the inner `indicator` has. Signals selected by `filter_mask` are filtered.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Filtered<I> {
+	/// Inner indicator.
+	pub indicator: I,
+
+	/// Count of bars signal must be sustained for. Default is `1` (no debouncing).
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub filter_debounce: PeriodType,
+
+	/// Count of bars signal must be confirmed within. Default is `0` (no confirmation required).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub filter_confirm: PeriodType,
+
+	/// Count of bars to suppress signals after the last signal. Default is `0` (no cooldown).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub filter_cooldown: PeriodType,
+
+	/// Bit mask of the inner indicator's signals to filter: `n`-th bit selects `n`-th signal. Default is `u16::MAX` (all the signals).
+	pub filter_mask: u16,
+}
+
+impl<I: IndicatorConfig> IndicatorConfig for Filtered<I> {
+	type Instance = FilteredInstance<I>;
+
+	const NAME: &'static str = "Filtered";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		let filters = (0..cfg.indicator.size().1 as usize)
+			.filter(|&index| cfg.filter_mask >> index & 1 == 1)
+			.map(|index| {
+				let confirm = match cfg.filter_confirm {
+					0 => None,
+					period => Some(Confirm::new(period, Action::None)?),
+				};
+
+				Ok(Filter {
+					index,
+					debounce: Debounce::new(cfg.filter_debounce, Action::None)?,
+					confirm,
+					cooldown: Cooldown::new(cfg.filter_cooldown, Action::None)?,
+				})
+			})
+			.collect::<Result<_, Error>>()?;
+
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			filters,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.indicator.validate() && self.filter_debounce > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"filter_debounce" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.filter_debounce = value,
+			},
+			"filter_confirm" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.filter_confirm = value,
+			},
+			"filter_cooldown" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.filter_cooldown = value,
+			},
+			"filter_mask" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.filter_mask = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.indicator.size()
+	}
+}
+
+impl<I: Default> Default for Filtered<I> {
+	fn default() -> Self {
+		Self {
+			indicator: I::default(),
+			filter_debounce: 1,
+			filter_confirm: 0,
+			filter_cooldown: 0,
+			filter_mask: u16::MAX,
+		}
+	}
+}
+
+#[derive(Debug)]
+struct Filter {
+	index: usize,
+	debounce: Debounce,
+	confirm: Option<Confirm>,
+	cooldown: Cooldown,
+}
+
+#[derive(Debug)]
+pub struct FilteredInstance<I: IndicatorConfig> {
+	cfg: Filtered<I>,
+
+	instance: I::Instance,
+	filters: Vec<Filter>,
+}
+
+impl<I: IndicatorConfig> IndicatorInstance for FilteredInstance<I> {
+	type Config = Filtered<I>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		let length = result.signals().len();
+		let mut signals = [Action::None; IndicatorResult::SIZE];
+		signals[..length].copy_from_slice(result.signals());
+
+		for filter in &mut self.filters {
+			let mut signal = filter.debounce.next(signals[filter.index]);
+
+			if let Some(confirm) = &mut filter.confirm {
+				signal = confirm.next(signal);
+			}
+
+			signals[filter.index] = filter.cooldown.next(signal);
+		}
+
+		IndicatorResult::new(result.values(), &signals[..length])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Filtered;
+	use crate::core::{Action, IndicatorConfig};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_filtered_cooldown() {
+		let candles: Vec<_> = RandomCandles::new().take(500).collect();
+
+		let raw = MACD::default().over(&candles).unwrap();
+		let filtered = Filtered {
+			indicator: MACD::default(),
+			filter_cooldown: 20,
+			filter_mask: 0b01,
+			..Filtered::default()
+		}
+		.over(&candles)
+		.unwrap();
+
+		let mut last = None;
+		let mut suppressed = 0;
+
+		for (i, (raw, filtered)) in raw.iter().zip(&filtered).enumerate() {
+			assert_eq!(raw.values(), filtered.values());
+			assert_eq!(raw.signals()[1..], filtered.signals()[1..]);
+
+			let (raw, filtered) = (raw.signal(0), filtered.signal(0));
+			if raw == Action::None {
+				assert_eq!(filtered, Action::None);
+				continue;
+			}
+
+			match last {
+				Some(last) if i - last <= 20 => {
+					assert_eq!(filtered, Action::None);
+					suppressed += 1;
+				}
+				_ => {
+					assert_eq!(filtered, raw);
+					last = Some(i);
+				}
+			}
+		}
+
+		assert!(suppressed > 0);
+	}
+}
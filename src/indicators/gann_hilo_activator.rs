@@ -0,0 +1,155 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Gann `HiLo` Activator
+///
+/// When `source` value goes above previous moving average of `high` values, trend switches to up.
+/// When `source` value goes below previous moving average of `low` values, trend switches to down.
+///
+/// In uptrend activator line is the moving average of `low` values, in downtrend it is the moving average of `high` values.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/script/XNQSLIYb-Gann-High-Low/>
+/// * Robert Krausz, "The New Gann Swing Chartist", 1998
+///
+/// # 2 values
+///
+/// * `activator` line value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `direction`
+///
+/// `1.0` for uptrend, `-1.0` for downtrend.
+///
+/// # 1 signal
+///
+/// When trend switches to up, returns full buy signal.
+/// When trend switches to down, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GannHiLoActivator {
+	/// Moving averages period. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Moving averages method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for GannHiLoActivator {
+	type Instance = GannHiLoActivatorInstance;
+
+	const NAME: &'static str = "GannHiLoActivator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			ma_high: method(cfg.method, cfg.period, candle.high())?,
+			ma_low: method(cfg.method, cfg.period, candle.low())?,
+			prev_high: candle.high(),
+			prev_low: candle.low(),
+			direction: 1,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for GannHiLoActivator {
+	fn default() -> Self {
+		Self {
+			period: 3,
+			method: RegularMethods::SMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct GannHiLoActivatorInstance {
+	cfg: GannHiLoActivator,
+
+	ma_high: RegularMethod,
+	ma_low: RegularMethod,
+	prev_high: ValueType,
+	prev_low: ValueType,
+	direction: i8,
+}
+
+impl IndicatorInstance for GannHiLoActivatorInstance {
+	type Config = GannHiLoActivator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let prev_direction = self.direction;
+		if src > self.prev_high {
+			self.direction = 1;
+		} else if src < self.prev_low {
+			self.direction = -1;
+		}
+
+		self.prev_high = self.ma_high.next(candle.high());
+		self.prev_low = self.ma_low.next(candle.low());
+
+		let activator = if self.direction > 0 {
+			self.prev_low
+		} else {
+			self.prev_high
+		};
+
+		let signal = (self.direction != prev_direction) as i8 * self.direction;
+
+		IndicatorResult::new(&[activator, self.direction as ValueType], &[signal.into()])
+	}
+}
@@ -0,0 +1,392 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use crate::core::{Error, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Reference level of the [`Gaps`] detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum GapReference {
+	/// Gap is measured from the previous `close` value.
+	Close,
+
+	/// Gap is measured from the previous range: from `high` value for gaps up and from `low` value for gaps down.
+	Range,
+}
+
+impl FromStr for GapReference {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"close" => Ok(Self::Close),
+			"range" => Ok(Self::Range),
+
+			value => Err(Error::ParameterParse(
+				"reference".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Opening gaps
+///
+/// Detects gap when `open` value differs from the previous candle's reference level (see [`GapReference`])
+/// by more than `threshold` part of it. Reference level is kept as the gap level, which is tracked until the price fills the gap:
+/// gap up is filled when `low` value reaches its level, gap down is filled when `high` value reaches its level.
+///
+/// Gap may be filled on the same candle it appears on: then it is reported together with its fill and is not tracked.
+///
+/// Gap, which is filled within `breakaway` candles, is a common gap. Gap, which stays unfilled for `breakaway` candles, is a breakaway gap.
+/// Breakaway gaps are still tracked until filled.
+///
+/// Only the last `count` unfilled gaps are tracked.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/gap.asp>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{GapReference, Gaps};
+///
+/// let gaps = Gaps {
+///     reference: GapReference::Range,
+///     threshold: 0.001,
+///     ..Gaps::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = gaps.over(&candles).unwrap();
+///
+/// println!("unfilled gaps: {}", results[99].value(3));
+/// ```
+///
+/// # 4 values
+///
+/// * Relative size of the gap at the current candle
+///
+/// Positive for gaps up, negative for gaps down, `0.0` when there is no gap.
+///
+/// * Nearest unfilled gap level above the `close` value
+/// * Nearest unfilled gap level below the `close` value
+///
+/// Value is `NaN` when there is no such level.
+///
+/// * Count of unfilled gaps
+///
+/// Range in \[`0.0`; `count`\]
+///
+/// # 3 signals
+///
+/// * Gap signal
+///
+/// Returns full buy signal on gap up and full sell signal on gap down. Otherwise returns no signal.
+///
+/// * Breakaway signal
+///
+/// Returns full buy signal when gap up becomes breakaway and full sell signal when gap down becomes breakaway. Otherwise returns no signal.
+///
+/// * Fill signal
+///
+/// Returns full sell signal when gap up is filled and full buy signal when gap down is filled. Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gaps {
+	/// Reference level of the previous candle. Default is [`Close`](GapReference::Close).
+	pub reference: GapReference,
+
+	/// Relative size threshold of the gap. Default is `0.01`.
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub threshold: ValueType,
+
+	/// Count of candles the gap should stay unfilled for to become breakaway gap. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub breakaway: PeriodType,
+
+	/// Maximum count of tracked unfilled gaps. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub count: PeriodType,
+}
+
+impl IndicatorConfig for Gaps {
+	type Instance = GapsInstance;
+
+	const NAME: &'static str = "Gaps";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_high: candle.high(),
+			prev_low: candle.low(),
+			prev_close: candle.close(),
+			gaps: Vec::with_capacity(cfg.count as usize + 1),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		(0. ..1.).contains(&self.threshold) && self.breakaway > 0 && self.count > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"reference" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.reference = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+			"breakaway" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.breakaway = value,
+			},
+			"count" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.count = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 3)
+	}
+}
+
+impl Default for Gaps {
+	fn default() -> Self {
+		Self {
+			reference: GapReference::Close,
+			threshold: 0.01,
+			breakaway: 5,
+			count: 10,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Gap {
+	level: ValueType,
+	/// `1` for gap up, `-1` for gap down
+	direction: i8,
+	age: PeriodType,
+}
+
+impl Gap {
+	fn is_filled<T: OHLCV>(&self, candle: &T) -> bool {
+		if self.direction > 0 {
+			candle.low() <= self.level
+		} else {
+			candle.high() >= self.level
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct GapsInstance {
+	cfg: Gaps,
+
+	prev_high: ValueType,
+	prev_low: ValueType,
+	prev_close: ValueType,
+	gaps: Vec<Gap>,
+}
+
+impl IndicatorInstance for GapsInstance {
+	type Config = Gaps;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let breakaway_age = self.cfg.breakaway;
+		let mut breakaway = 0;
+		let mut fill = 0;
+
+		self.gaps.retain_mut(|gap| {
+			gap.age = gap.age.saturating_add(1);
+
+			if gap.is_filled(candle) {
+				fill -= gap.direction;
+				return false;
+			}
+
+			if gap.age == breakaway_age {
+				breakaway += gap.direction;
+			}
+
+			true
+		});
+
+		let (up_level, down_level) = match self.cfg.reference {
+			GapReference::Close => (self.prev_close, self.prev_close),
+			GapReference::Range => (self.prev_high, self.prev_low),
+		};
+
+		let open = candle.open();
+		let threshold = self.cfg.threshold;
+
+		let (size, direction, level) = if open > up_level * (1. + threshold) {
+			(open / up_level - 1., 1, up_level)
+		} else if open < down_level * (1. - threshold) {
+			(open / down_level - 1., -1, down_level)
+		} else {
+			(0., 0, 0.)
+		};
+
+		let gap = Gap {
+			level,
+			direction,
+			age: 0,
+		};
+
+		if direction != 0 && gap.is_filled(candle) {
+			fill -= direction;
+		} else if direction != 0 {
+			self.gaps.push(gap);
+
+			if self.gaps.len() > self.cfg.count as usize {
+				self.gaps.remove(0);
+			}
+		}
+
+		self.prev_high = candle.high();
+		self.prev_low = candle.low();
+		self.prev_close = candle.close();
+
+		let close = candle.close();
+		let above = self
+			.gaps
+			.iter()
+			.map(|gap| gap.level)
+			.filter(|&level| level > close)
+			.fold(ValueType::NAN, ValueType::min);
+		let below = self
+			.gaps
+			.iter()
+			.map(|gap| gap.level)
+			.filter(|&level| level <= close)
+			.fold(ValueType::NAN, ValueType::max);
+
+		IndicatorResult::new(
+			&[size, above, below, self.gaps.len() as ValueType],
+			&[direction.into(), breakaway.into(), fill.into()],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{GapReference, Gaps};
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(open: ValueType, high: ValueType, low: ValueType, close: ValueType) -> Candle {
+		Candle {
+			open,
+			high,
+			low,
+			close,
+			volume: 1.,
+		}
+	}
+
+	#[test]
+	fn test_gaps() {
+		let mut instance = Gaps {
+			breakaway: 2,
+			..Gaps::default()
+		}
+		.init(&candle(100., 101., 99., 100.))
+		.unwrap();
+
+		// gap up from 100 to 105
+		let result = instance.next(&candle(105., 106., 104., 105.));
+		assert_eq!(result.signal(0), Action::BUY_ALL);
+		assert_eq_float(0.05, result.value(0));
+		assert!(result.value(1).is_nan());
+		assert_eq_float(100., result.value(2));
+		assert_eq_float(1., result.value(3));
+
+		// gap down from 105 to 103 is filled on the next candle
+		let result = instance.next(&candle(103., 104., 102., 103.));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq_float(105., result.value(1));
+		assert_eq_float(2., result.value(3));
+
+		let result = instance.next(&candle(104., 105.5, 103., 104.));
+		assert_eq!(result.signal(0), Action::None);
+		assert_eq!(result.signal(1), Action::BUY_ALL);
+		assert_eq!(result.signal(2), Action::BUY_ALL);
+		assert_eq_float(1., result.value(3));
+
+		// breakaway gap up is filled at last
+		let result = instance.next(&candle(103., 104., 99.5, 100.));
+		assert_eq!(result.signal(2), Action::SELL_ALL);
+		assert_eq_float(0., result.value(3));
+	}
+
+	#[test]
+	fn test_gaps_range() {
+		let mut instance = Gaps {
+			reference: GapReference::Range,
+			..Gaps::default()
+		}
+		.init(&candle(100., 101., 99., 100.))
+		.unwrap();
+
+		// open is above previous close, but within the previous range
+		let result = instance.next(&candle(101., 102., 100., 101.));
+		assert_eq!(result.signal(0), Action::None);
+
+		let result = instance.next(&candle(95., 96., 94., 95.));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq_float(100., result.value(1));
+	}
+
+	#[test]
+	fn test_gaps_filled_on_the_same_candle() {
+		let mut instance = Gaps::default()
+			.init(&candle(100., 101., 99., 100.))
+			.unwrap();
+
+		// gap up from 100 to 105, but the price falls back to 99 within the same candle
+		let result = instance.next(&candle(105., 106., 99., 100.));
+		assert_eq!(result.signal(0), Action::BUY_ALL);
+		assert_eq!(result.signal(2), Action::SELL_ALL);
+		assert_eq_float(0.05, result.value(0));
+		assert!(result.value(1).is_nan() && result.value(2).is_nan());
+		assert_eq_float(0., result.value(3));
+
+		// gap down from 100 to 95 is filled on the same candle too
+		let result = instance.next(&candle(95., 100., 94., 96.));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq!(result.signal(2), Action::BUY_ALL);
+		assert_eq_float(0., result.value(3));
+	}
+}
@@ -0,0 +1,230 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Past;
+
+/// Gator Oscillator
+///
+/// Shows absolute distances between Bill Williams' Alligator lines (`jaw`, `teeth` and `lips`) as two histograms.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/gator-oscillator.asp>
+/// * <https://www.metatrader5.com/en/terminal/help/indicators/bw_indicators/go>
+///
+/// # 2 values
+///
+/// * `upper` histogram value: `|jaw - teeth|`
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `lower` histogram value: `-|teeth - lips|`
+///
+/// Range in \(`-inf`; `0.0`\]
+///
+/// # 2 signals
+///
+/// * Phase signal. When both histograms expand (the Alligator is "eating"), returns full buy signal.
+///   When both histograms contract (the Alligator is "sleeping"), returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * Trend signal. When histograms start to expand and `lips` > `teeth` > `jaw`, returns full buy signal.
+///   When histograms start to expand and `lips` < `teeth` < `jaw`, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GatorOscillator {
+	/// Jaw line period. Default is `13`.
+	///
+	/// Range in \(`teeth_period`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub jaw_period: PeriodType,
+
+	/// Jaw line offset. Default is `8`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub jaw_offset: PeriodType,
+
+	/// Teeth line period. Default is `8`.
+	///
+	/// Range in \(`lips_period`; `jaw_period`\)
+	pub teeth_period: PeriodType,
+
+	/// Teeth line offset. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub teeth_offset: PeriodType,
+
+	/// Lips line period. Default is `5`.
+	///
+	/// Range in \[`2`; `teeth_period`\)
+	pub lips_period: PeriodType,
+
+	/// Lips line offset. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub lips_offset: PeriodType,
+
+	/// Alligator lines moving average type. Default is [`SMMA`](crate::methods::SMMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`HL2`](crate::core::Source::HL2).
+	pub source: Source,
+}
+
+impl IndicatorConfig for GatorOscillator {
+	type Instance = GatorOscillatorInstance;
+
+	const NAME: &'static str = "GatorOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			jaw_ma: method(cfg.method, cfg.jaw_period, src)?,
+			teeth_ma: method(cfg.method, cfg.teeth_period, src)?,
+			lips_ma: method(cfg.method, cfg.lips_period, src)?,
+			jaw: Past::new(cfg.jaw_offset, src)?,
+			teeth: Past::new(cfg.teeth_offset, src)?,
+			lips: Past::new(cfg.lips_offset, src)?,
+			last_upper: 0.,
+			last_lower: 0.,
+			was_expanding: false,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.lips_period > 1
+			&& self.lips_period < self.teeth_period
+			&& self.teeth_period < self.jaw_period
+			&& self.jaw_period < PeriodType::MAX
+			&& self.jaw_offset > 0
+			&& self.jaw_offset < PeriodType::MAX
+			&& self.teeth_offset > 0
+			&& self.teeth_offset < PeriodType::MAX
+			&& self.lips_offset > 0
+			&& self.lips_offset < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"jaw_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.jaw_period = value,
+			},
+			"jaw_offset" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.jaw_offset = value,
+			},
+			"teeth_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.teeth_period = value,
+			},
+			"teeth_offset" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.teeth_offset = value,
+			},
+			"lips_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lips_period = value,
+			},
+			"lips_offset" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lips_offset = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for GatorOscillator {
+	fn default() -> Self {
+		Self {
+			jaw_period: 13,
+			jaw_offset: 8,
+			teeth_period: 8,
+			teeth_offset: 5,
+			lips_period: 5,
+			lips_offset: 3,
+			method: RegularMethods::RMA,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct GatorOscillatorInstance {
+	cfg: GatorOscillator,
+
+	jaw_ma: RegularMethod,
+	teeth_ma: RegularMethod,
+	lips_ma: RegularMethod,
+	jaw: Past<ValueType>,
+	teeth: Past<ValueType>,
+	lips: Past<ValueType>,
+	last_upper: ValueType,
+	last_lower: ValueType,
+	was_expanding: bool,
+}
+
+impl IndicatorInstance for GatorOscillatorInstance {
+	type Config = GatorOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let jaw = self.jaw.next(self.jaw_ma.next(src));
+		let teeth = self.teeth.next(self.teeth_ma.next(src));
+		let lips = self.lips.next(self.lips_ma.next(src));
+
+		let upper = (jaw - teeth).abs();
+		let lower = -(teeth - lips).abs();
+
+		let is_expanding = upper > self.last_upper && lower < self.last_lower;
+		let is_contracting = upper < self.last_upper && lower > self.last_lower;
+
+		self.last_upper = upper;
+		self.last_lower = lower;
+
+		let signal1 = is_expanding as i8 - is_contracting as i8;
+
+		let signal2 = if is_expanding && !self.was_expanding {
+			(lips > teeth && teeth > jaw) as i8 - (lips < teeth && teeth < jaw) as i8
+		} else {
+			0
+		};
+
+		self.was_expanding = is_expanding;
+
+		IndicatorResult::new(&[upper, lower], &[signal1.into(), signal2.into()])
+	}
+}
@@ -0,0 +1,205 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::StDev;
+
+/// Historical (annualized) Volatility
+///
+/// Standard deviation of logarithmic returns over the last `period` bars, multiplied by square root of `annualization factor`.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Volatility_(finance)>
+/// * <https://www.macroption.com/historical-volatility-calculation/>
+///
+/// # 2 values
+///
+/// * `Historical volatility` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `Volatility rank`: relative part of the last `rank_period` volatility values which are lower than the current one
+///
+/// While less than `rank_period` volatility values has been passed, rank is calculated only over the values which are already known.
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # Has no signals
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HistoricalVolatility {
+	/// Log returns period. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Volatility rank period. Default is `100`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub rank_period: PeriodType,
+
+	/// Count of bars per year. Default is `252.0` (trading days).
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub annualization_factor: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for HistoricalVolatility {
+	type Instance = HistoricalVolatilityInstance;
+
+	const NAME: &'static str = "HistoricalVolatility";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			prev_value: src,
+			st_dev: StDev::new(cfg.period, 0.)?,
+			multiplier: cfg.annualization_factor.sqrt(),
+			window: Window::new(cfg.rank_period, ValueType::NAN),
+			count: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.rank_period > 0
+			&& self.rank_period < PeriodType::MAX
+			&& self.annualization_factor > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"rank_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.rank_period = value,
+			},
+			"annualization_factor" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.annualization_factor = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 0)
+	}
+}
+
+impl Default for HistoricalVolatility {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			rank_period: 100,
+			annualization_factor: 252.,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct HistoricalVolatilityInstance {
+	cfg: HistoricalVolatility,
+
+	prev_value: ValueType,
+	st_dev: StDev,
+	multiplier: ValueType,
+	window: Window<ValueType>,
+	count: PeriodType,
+}
+
+impl IndicatorInstance for HistoricalVolatilityInstance {
+	type Config = HistoricalVolatility;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let log_return = if src > 0. && self.prev_value > 0. {
+			(src / self.prev_value).ln()
+		} else {
+			0.
+		};
+		self.prev_value = src;
+
+		let volatility = self.st_dev.next(log_return) * self.multiplier;
+
+		// not yet known values are `NaN`, so they are never counted as lower ones
+		let lower_count = self.window.iter().filter(|&v| v < volatility).count();
+		let rank = if self.count == 0 {
+			0.
+		} else {
+			lower_count as ValueType / self.count as ValueType
+		};
+		self.window.push(volatility);
+		self.count = (self.count + 1).min(self.cfg.rank_period);
+
+		IndicatorResult::new(&[volatility, rank], &[])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::HistoricalVolatility;
+	use crate::core::{Candle, IndicatorConfig, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_historical_volatility_rank_warm_up() {
+		// log returns alternate and fade out, so volatility decreases since the 4th bar
+		let mut close: ValueType = 100.;
+		let candles: Vec<_> = (0..10)
+			.map(|i| {
+				if i > 0 {
+					let log_return = 0.1 / i as ValueType;
+					close *= if i % 2 == 0 { log_return } else { -log_return }.exp();
+				}
+
+				Candle {
+					close,
+					..Candle::default()
+				}
+			})
+			.collect();
+
+		let results = HistoricalVolatility {
+			period: 2,
+			..HistoricalVolatility::default()
+		}
+		.over(&candles)
+		.unwrap();
+
+		assert_eq_float(0., results[0].value(1));
+		// only the very first zero volatility is lower than the current one
+		assert_eq_float(1. / 9., results[9].value(1));
+	}
+}
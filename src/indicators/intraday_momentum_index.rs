@@ -0,0 +1,150 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::ZoneCross;
+
+/// Intraday Momentum Index
+///
+/// [`RSI`](crate::indicators::RSI)-like ratio computed over the `close` - `open` candle bodies instead of `close` changes.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/i/intraday-momentum-index-imi.asp>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses upper zone upwards, returns full sell signal.
+/// When main value crosses lower zone downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses upper zone downwards, returns full sell signal.
+/// When main value crosses lower zone upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntradayMomentumIndex {
+	/// Main period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Overbought/oversell relative zone. Default is `0.3`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Moving average method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+}
+
+/// Just an alias for `IntradayMomentumIndex`
+pub type IMI = IntradayMomentumIndex;
+
+impl IndicatorConfig for IntradayMomentumIndex {
+	type Instance = IntradayMomentumIndexInstance;
+
+	const NAME: &'static str = "IntradayMomentumIndex";
+
+	fn init<T: OHLCV>(self, _candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			posma: method(cfg.method, cfg.period, 0.)?,
+			negma: method(cfg.method, cfg.period, 0.)?,
+			zone_cross: ZoneCross::new((cfg.zone, 1.0 - cfg.zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.zone > 0. && self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for IntradayMomentumIndex {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			zone: 0.3,
+			method: RegularMethods::SMA,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct IntradayMomentumIndexInstance {
+	cfg: IntradayMomentumIndex,
+
+	posma: RegularMethod,
+	negma: RegularMethod,
+	zone_cross: ZoneCross,
+}
+
+impl IndicatorInstance for IntradayMomentumIndexInstance {
+	type Config = IntradayMomentumIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let body = candle.close() - candle.open();
+
+		let pos: ValueType = self.posma.next(body.max(0.));
+		let neg: ValueType = self.negma.next((-body).max(0.));
+
+		let value = if pos + neg > 0. {
+			pos / (pos + neg)
+		} else {
+			0.5
+		};
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
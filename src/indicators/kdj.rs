@@ -0,0 +1,186 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
+
+/// KDJ
+///
+/// Stochastic `%K` and `%D` lines extended with `J` line: `J` = 3 * `%K` - 2 * `%D`.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/k/kdj-indicator.asp>
+///
+/// # 3 values
+///
+/// * `%K` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * `%D` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * `J` value
+///
+/// Range in \[`-2.0`; `3.0`\]
+///
+/// # 2 signals
+///
+/// * When `J` value crosses `0.0` downwards, returns full buy signal.
+///   When `J` value crosses `1.0` upwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `%K` value crosses `%D` value upwards, returns full buy signal.
+///   When `%K` value crosses `%D` value downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KDJ {
+	/// Period for searching highest high and lowest low. Default is `9`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Period for smoothing `%K` value. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smooth_k: PeriodType,
+
+	/// MA method for smoothing `%K` value. Default is [`RMA`](crate::methods::RMA).
+	pub method_k: RegularMethods,
+
+	/// Period for smoothing `%D` value. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smooth_d: PeriodType,
+
+	/// MA method for smoothing `%D` value. Default is [`RMA`](crate::methods::RMA).
+	pub method_d: RegularMethods,
+}
+
+impl IndicatorConfig for KDJ {
+	type Instance = KDJInstance;
+
+	const NAME: &'static str = "KDJ";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			ma_k: method(cfg.method_k, cfg.smooth_k, 0.5)?,
+			ma_d: method(cfg.method_d, cfg.smooth_d, 0.5)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.smooth_k > 0
+			&& self.smooth_k < PeriodType::MAX
+			&& self.smooth_d > 0
+			&& self.smooth_d < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"smooth_k" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_k = value,
+			},
+			"method_k" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method_k = value,
+			},
+			"smooth_d" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_d = value,
+			},
+			"method_d" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method_d = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl Default for KDJ {
+	fn default() -> Self {
+		Self {
+			period: 9,
+			smooth_k: 3,
+			method_k: RegularMethods::RMA,
+			smooth_d: 3,
+			method_d: RegularMethods::RMA,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct KDJInstance {
+	cfg: KDJ,
+
+	highest: Highest,
+	lowest: Lowest,
+	ma_k: RegularMethod,
+	ma_d: RegularMethod,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+	cross: Cross,
+}
+
+impl IndicatorInstance for KDJInstance {
+	type Config = KDJ;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let highest = self.highest.next(candle.high());
+		let lowest = self.lowest.next(candle.low());
+
+		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		let rsv = if highest == lowest {
+			0.5
+		} else {
+			(candle.close() - lowest) / (highest - lowest)
+		};
+
+		let k = self.ma_k.next(rsv);
+		let d = self.ma_d.next(k);
+		let j = k.mul_add(3., -2. * d);
+
+		let signal1 = self.cross_under.next((j, 0.)) - self.cross_above.next((j, 1.));
+		let signal2 = self.cross.next((k, d));
+
+		IndicatorResult::new(&[k, d, j], &[signal1, signal2])
+	}
+}
@@ -3,11 +3,21 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::helpers::{method, sign, RegularMethod, RegularMethods};
+use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
 /// Klinger Volume Oscillator
 ///
+/// `trend` is `+1` when typical price rises and `-1` when it falls (and stays the same when typical price doesn't change).
+///
+/// `dm` = `high` - `low`
+///
+/// `cm` = `cm[1]` + `dm`, when `trend` is the same as at the previous bar, otherwise `cm` = `dm[1]` + `dm`
+///
+/// `volume force` = `volume` * |2 * (`dm` / `cm` - 1)| * `trend` * 100
+///
+/// `main` value is the difference between fast and slow moving averages of the `volume force`.
+///
 /// ## Links
 ///
 /// * <https://en.wikipedia.org/wiki/Volume_analysis#Klinger_Volume_Oscillator>
@@ -69,6 +79,9 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 			cross1: Cross::default(),
 			cross2: Cross::default(),
 			last_tp: candle.tp(),
+			trend: 0.,
+			dm: candle.high() - candle.low(),
+			cm: 0.,
 			cfg,
 		})
 	}
@@ -135,6 +148,9 @@ pub struct KlingerVolumeOscillatorInstance {
 	cross1: Cross,
 	cross2: Cross,
 	last_tp: ValueType,
+	trend: ValueType,
+	dm: ValueType,
+	cm: ValueType,
 }
 
 impl IndicatorInstance for KlingerVolumeOscillatorInstance {
@@ -150,15 +166,33 @@ impl IndicatorInstance for KlingerVolumeOscillatorInstance {
 		let d = tp - self.last_tp;
 		self.last_tp = tp;
 
-		// let vol = if d > 0. {
-		// 	candle.volume()
-		// } else if d < 0. {
-		// 	-candle.volume()
-		// } else {
-		// 	0.
-		// };
+		let trend = if d > 0. {
+			1.
+		} else if d < 0. {
+			-1.
+		} else {
+			self.trend
+		};
 
-		let vol = sign(d) * candle.volume();
+		let dm = candle.high() - candle.low();
+
+		#[allow(clippy::float_cmp)]
+		let cm = if trend == self.trend {
+			self.cm + dm
+		} else {
+			self.dm + dm
+		};
+
+		self.trend = trend;
+		self.dm = dm;
+		self.cm = cm;
+
+		let ratio = if cm > 0. {
+			(2. * (dm / cm - 1.)).abs()
+		} else {
+			0.
+		};
+		let vol = candle.volume() * ratio * trend * 100.;
 
 		let ma1: ValueType = self.ma1.next(vol);
 		let ma2: ValueType = self.ma2.next(vol);
@@ -172,3 +206,69 @@ impl IndicatorInstance for KlingerVolumeOscillatorInstance {
 		IndicatorResult::new(&[ko, ma3], &[s1, s2])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::KlingerVolumeOscillator;
+	use crate::core::{Candle, IndicatorConfig, ValueType};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+
+	#[test]
+	fn test_klinger_volume_oscillator() {
+		let candles: Vec<_> = [
+			(10., 8., 9., 100.),
+			(11., 9., 10.5, 200.),
+			(12., 10., 11., 150.),
+			(11., 9., 9.5, 300.),
+			(10.5, 8.5, 9., 250.),
+			(12., 9., 11.5, 400.),
+			(13., 10., 12.5, 350.),
+		]
+		.iter()
+		.map(
+			|&(high, low, close, volume): &(ValueType, ValueType, ValueType, ValueType)| Candle {
+				open: close,
+				high,
+				low,
+				close,
+				volume,
+			},
+		)
+		.collect();
+
+		// calculated by hand: volume force is `[0, 20000, 20000, -30000, -100_000 / 3, 32000, 43750]`
+		let main = [
+			0.,
+			10000. / 3.,
+			20000. / 3.,
+			-25000. / 3.,
+			-155_000. / 9.,
+			88000. / 9.,
+			854_500. / 36.,
+		];
+		let signal = [
+			0.,
+			5000. / 3.,
+			5000.,
+			-2500. / 3.,
+			-115_000. / 9.,
+			-33500. / 9.,
+			603_250. / 36.,
+		];
+
+		let results = KlingerVolumeOscillator {
+			period1: 2,
+			period2: 3,
+			period3: 2,
+			method1: RegularMethods::SMA,
+			method2: RegularMethods::SMA,
+		}
+		.over(&candles)
+		.unwrap();
+
+		for ((result, &main), &signal) in results.iter().zip(&main).zip(&signal) {
+			assert_eq_float(main, result.value(0));
+			assert_eq_float(signal, result.value(1));
+		}
+	}
+}
@@ -0,0 +1,159 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::ZoneCross;
+
+/// John Ehlers' Laguerre RSI
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/TimeWarp.pdf>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses upper zone upwards, returns full sell signal.
+/// When main value crosses lower zone downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses upper zone downwards, returns full sell signal.
+/// When main value crosses lower zone upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LaguerreRSI {
+	/// Laguerre filter damping factor. Default is `0.5`.
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub gamma: ValueType,
+
+	/// Overbought/oversold relative zone. Default is `0.2`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for LaguerreRSI {
+	type Instance = LaguerreRSIInstance;
+
+	const NAME: &'static str = "LaguerreRSI";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			l: [src; 4],
+			zone_cross: ZoneCross::new((cfg.zone, 1.0 - cfg.zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.gamma >= 0. && self.gamma < 1. && self.zone > 0. && self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"gamma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.gamma = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for LaguerreRSI {
+	fn default() -> Self {
+		Self {
+			gamma: 0.5,
+			zone: 0.2,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LaguerreRSIInstance {
+	cfg: LaguerreRSI,
+
+	l: [ValueType; 4],
+	zone_cross: ZoneCross,
+}
+
+impl LaguerreRSIInstance {
+	/// Four-stage Laguerre filter
+	#[allow(clippy::suboptimal_flops)]
+	fn filter(&mut self, value: ValueType) -> [ValueType; 4] {
+		let g = self.cfg.gamma;
+		let prev = self.l;
+
+		let l0 = (1. - g) * value + g * prev[0];
+		let l1 = -g * l0 + prev[0] + g * prev[1];
+		let l2 = -g * l1 + prev[1] + g * prev[2];
+		let l3 = -g * l2 + prev[2] + g * prev[3];
+
+		self.l = [l0, l1, l2, l3];
+		self.l
+	}
+}
+
+impl IndicatorInstance for LaguerreRSIInstance {
+	type Config = LaguerreRSI;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let l = self.filter(src);
+
+		let (cu, cd) = l.windows(2).fold((0., 0.), |(cu, cd), pair| {
+			let delta = pair[0] - pair[1];
+			(cu + delta.max(0.), cd - delta.min(0.))
+		});
+
+		let value = if cu + cd > 0. { cu / (cu + cd) } else { 0. };
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
@@ -0,0 +1,298 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Cross, LinReg};
+
+/// Linear regression statistics at the newest value
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Fit {
+	/// Regression line value at the newest point
+	pub value: ValueType,
+	/// Regression line slope per bar
+	pub slope: ValueType,
+	/// Standard error of the estimate
+	pub std_error: ValueType,
+	/// Standard deviation of the values
+	pub std_dev: ValueType,
+}
+
+/// Rolling linear regression over the last `length` values
+///
+/// Regression line is taken from [`LinReg`], sums of the values and of their squares are updated in O(1) as well.
+/// Sums are kept relative to the initial value to reduce round-off errors for values far from zero.
+#[derive(Debug, Clone)]
+pub(super) struct Regression {
+	lin_reg: LinReg,
+	window: Window<ValueType>,
+	base: ValueType,
+	sum: ValueType,
+	sum_sq: ValueType,
+	length: ValueType,
+	/// Sum of squared deviations of the bar indexes from their mean
+	sum_sq_x: ValueType,
+}
+
+impl Regression {
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		let float_length = length as ValueType;
+
+		Ok(Self {
+			lin_reg: LinReg::new(length, value)?,
+			window: Window::new(length, 0.),
+			base: value,
+			sum: 0.,
+			sum_sq: 0.,
+			length: float_length,
+			sum_sq_x: float_length * float_length.mul_add(float_length, -1.) / 12.,
+		})
+	}
+
+	/// Pushes the `value` into the regression window and fits the line
+	pub fn next(&mut self, value: ValueType) -> Fit {
+		let delta = value - self.base;
+		let past = self.window.push(delta);
+
+		self.sum += delta - past;
+		self.sum_sq += (delta - past) * (delta + past);
+
+		let value = self.lin_reg.next(value);
+		let slope = self.lin_reg.tan();
+
+		let squared_deltas = self
+			.sum
+			.mul_add(-self.sum / self.length, self.sum_sq)
+			.max(0.);
+		let squared_errors = (slope * slope)
+			.mul_add(-self.sum_sq_x, squared_deltas)
+			.max(0.);
+
+		let std_error = if self.length > 2. {
+			(squared_errors / (self.length - 2.)).sqrt()
+		} else {
+			0.
+		};
+
+		Fit {
+			value,
+			slope,
+			std_error,
+			std_dev: (squared_deltas / self.length).sqrt(),
+		}
+	}
+}
+
+/// Linear Regression Channel
+///
+/// Rolling linear regression line over the last `period` values with the channel bounds at
+/// `k` standard errors of the estimate (or `k` standard deviations of the values when `use_std_dev` is `true`).
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000502266-linear-regression-channel/>
+/// * <https://en.wikipedia.org/wiki/Standard_error#Standard_error_of_the_regression>
+///
+/// # 4 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `middle line` (regression line value)
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `slope` of the regression line per bar
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When `source` value touches or goes below `lower bound`, returns full buy signal.
+///   When `source` value touches or goes above `upper bound`, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `slope` turns positive, returns full buy signal.
+///   When `slope` turns negative, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearRegressionChannel {
+	/// Regression period. Default is `50`.
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Channel width multiplier. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub k: ValueType,
+
+	/// Use standard deviation of the values instead of standard error of the estimate. Default is `false`.
+	pub use_std_dev: bool,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for LinearRegressionChannel {
+	type Instance = LinearRegressionChannelInstance;
+
+	const NAME: &'static str = "LinearRegressionChannel";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			regression: Regression::new(cfg.period, src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 2 && self.period < PeriodType::MAX && self.k > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"k" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.k = value,
+			},
+			"use_std_dev" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.use_std_dev = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 2)
+	}
+}
+
+impl Default for LinearRegressionChannel {
+	fn default() -> Self {
+		Self {
+			period: 50,
+			k: 2.0,
+			use_std_dev: false,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct LinearRegressionChannelInstance {
+	cfg: LinearRegressionChannel,
+
+	regression: Regression,
+	cross: Cross,
+}
+
+impl IndicatorInstance for LinearRegressionChannelInstance {
+	type Config = LinearRegressionChannel;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let regression = self.regression.next(src);
+
+		let deviation = if self.cfg.use_std_dev {
+			regression.std_dev
+		} else {
+			regression.std_error
+		};
+
+		let middle = regression.value;
+		let upper = deviation.mul_add(self.cfg.k, middle);
+		let lower = deviation.mul_add(-self.cfg.k, middle);
+
+		let signal1 = (src <= lower) as i8 - (src >= upper) as i8;
+		let signal2 = self.cross.next((regression.slope, 0.));
+
+		IndicatorResult::new(
+			&[upper, middle, lower, regression.slope],
+			&[signal1.into(), signal2],
+		)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::similar_names, clippy::suboptimal_flops)]
+mod tests {
+	use super::Regression;
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_regression() {
+		let src: Vec<ValueType> = RandomCandles::new().take(300).map(|x| x.close).collect();
+
+		for &length in &[2, 3, 5, 14, 50, 100] {
+			let mut regression = Regression::new(length, src[0]).unwrap();
+			let length = length as usize;
+			let n = length as ValueType;
+
+			for i in 0..src.len() {
+				let fit = regression.next(src[i]);
+
+				// x = 0 for the newest value, x = -1 for the previous one and so on
+				let points: Vec<(ValueType, ValueType)> = (0..length)
+					.map(|j| (-(j as ValueType), src[i.saturating_sub(j)]))
+					.collect();
+
+				let mean_x = points.iter().map(|p| p.0).sum::<ValueType>() / n;
+				let mean_y = points.iter().map(|p| p.1).sum::<ValueType>() / n;
+				let s_xy: ValueType = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+				let s_xx: ValueType = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+				let s_yy: ValueType = points.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+
+				let slope = s_xy / s_xx;
+				let value = mean_y - slope * mean_x;
+				let squared_errors: ValueType = points
+					.iter()
+					.map(|p| (p.1 - (value + slope * p.0)).powi(2))
+					.sum();
+
+				assert_eq_float(value, fit.value);
+				assert_eq_float(slope, fit.slope);
+				assert_eq_float((s_yy / n).sqrt(), fit.std_dev);
+
+				if length > 2 {
+					// sum of squared errors is a difference of sums for nearly perfect fits,
+					// so it's precision is relative to the sum of squared deltas
+					let fit_squared_errors = fit.std_error.powi(2) * (n - 2.);
+					assert_eq_float(s_yy + squared_errors, s_yy + fit_squared_errors);
+				}
+			}
+		}
+	}
+}
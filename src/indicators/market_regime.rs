@@ -0,0 +1,358 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::average_directional_index::{AverageDirectionalIndex, AverageDirectionalIndexInstance};
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::PercentRank;
+
+/// Market regime of the [`MarketRegime`] indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Regime {
+	/// Strong trend up. Value is `1.0`.
+	TrendingUp,
+
+	/// Strong trend down. Value is `-1.0`.
+	TrendingDown,
+
+	/// No strong trend. Value is `0.0`.
+	Ranging,
+
+	/// Unusually high volatility. Value is `2.0`.
+	Volatile,
+}
+
+impl Regime {
+	/// Returns value of the regime as it is returned by the [`MarketRegime`] indicator
+	#[must_use]
+	pub const fn value(self) -> ValueType {
+		match self {
+			Self::TrendingUp => 1.,
+			Self::TrendingDown => -1.,
+			Self::Ranging => 0.,
+			Self::Volatile => 2.,
+		}
+	}
+
+	/// Returns regime by its value
+	#[must_use]
+	#[allow(clippy::float_cmp)]
+	pub fn from_value(value: ValueType) -> Option<Self> {
+		[
+			Self::TrendingUp,
+			Self::TrendingDown,
+			Self::Ranging,
+			Self::Volatile,
+		]
+		.iter()
+		.copied()
+		.find(|regime| regime.value() == value)
+	}
+}
+
+/// Market Regime
+///
+/// Classifies every candle into one of the discrete [`Regime`]s:
+///
+/// * [`Volatile`](Regime::Volatile), when the [percent rank](crate::methods::PercentRank) of the current [`ATR`](crate::methods::TR)
+///   of length `period` among the previous `lookback` `ATR` values is at least `volatility`.
+///   Volatile regime is not reported until the first `lookback` `ATR` values has been passed;
+/// * otherwise [`TrendingUp`](Regime::TrendingUp) or [`TrendingDown`](Regime::TrendingDown), when [`ADX`](crate::indicators::AverageDirectionalIndex)
+///   of length `period` is at least `trend`. Direction is the sign of `+DI` - `-DI`;
+/// * otherwise [`Ranging`](Regime::Ranging).
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/articles/trading/07/adx-trend-indicator.asp>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{MarketRegime, Regime};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(200).collect();
+/// let results = MarketRegime::default().over(&candles).unwrap();
+///
+/// match Regime::from_value(results[199].value(0)) {
+///     Some(Regime::TrendingUp) | Some(Regime::TrendingDown) => println!("use trend following strategy"),
+///     Some(Regime::Ranging) => println!("use mean reversion strategy"),
+///     _ => println!("stay aside"),
+/// }
+/// ```
+///
+/// # 4 values
+///
+/// * Regime [`value`](Regime::value)
+///
+/// One of `-1.0`, `0.0`, `1.0`, `2.0`
+///
+/// * `ATR` percentile
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * `ADX` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * `+DI` - `-DI`
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// When regime changes to [`TrendingUp`](Regime::TrendingUp), returns full buy signal.
+/// When regime changes to [`TrendingDown`](Regime::TrendingDown), returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarketRegime {
+	/// `ATR` and `ADX` period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Count of `ATR` values to calculate percentile over. Default is `100`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub lookback: PeriodType,
+
+	/// `ATR` percentile threshold of the volatile regime. Default is `0.9`.
+	///
+	/// Range in \[`0.0`; `1.0`\]
+	pub volatility: ValueType,
+
+	/// `ADX` threshold of the trending regimes. Default is `0.25`.
+	///
+	/// Range in \[`0.0`; `1.0`\]
+	pub trend: ValueType,
+}
+
+impl MarketRegime {
+	fn adx(self) -> AverageDirectionalIndex {
+		AverageDirectionalIndex {
+			di_length: self.period,
+			adx_smoothing: self.period,
+			zone: self.trend,
+			..AverageDirectionalIndex::default()
+		}
+	}
+}
+
+impl IndicatorConfig for MarketRegime {
+	type Instance = MarketRegimeInstance;
+
+	const NAME: &'static str = "MarketRegime";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let tr = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			atr: method(RegularMethods::RMA, cfg.period, tr)?,
+			adx: cfg.adx().init(candle)?,
+			percent_rank: PercentRank::new(cfg.lookback, tr)?,
+			count: 0,
+			prev_close: candle.close(),
+			regime: Regime::Ranging,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.lookback > 0
+			&& (0. ..=1.).contains(&self.volatility)
+			&& (0. ..=1.).contains(&self.trend)
+			&& self.adx().validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"lookback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lookback = value,
+			},
+			"volatility" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.volatility = value,
+			},
+			"trend" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.trend = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for MarketRegime {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			lookback: 100,
+			volatility: 0.9,
+			trend: 0.25,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct MarketRegimeInstance {
+	cfg: MarketRegime,
+
+	atr: RegularMethod,
+	adx: AverageDirectionalIndexInstance,
+	percent_rank: PercentRank,
+	count: PeriodType,
+	prev_close: ValueType,
+	regime: Regime,
+}
+
+impl IndicatorInstance for MarketRegimeInstance {
+	type Config = MarketRegime;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let atr = self.atr.next(candle.tr_close(self.prev_close));
+		self.prev_close = candle.close();
+
+		// percentile is taken into account only when every ranked value is a real one
+		let percentile = self.percent_rank.next(atr);
+		let is_ranked = self.count == self.cfg.lookback;
+		if !is_ranked {
+			self.count += 1;
+		}
+
+		let adx = self.adx.next(candle);
+		let strength = adx.value(0);
+		let direction = adx.value(1) - adx.value(2);
+
+		let regime = if is_ranked && percentile >= self.cfg.volatility {
+			Regime::Volatile
+		} else if strength >= self.cfg.trend && direction > 0. {
+			Regime::TrendingUp
+		} else if strength >= self.cfg.trend && direction < 0. {
+			Regime::TrendingDown
+		} else {
+			Regime::Ranging
+		};
+
+		let signal = match regime {
+			Regime::TrendingUp if self.regime != regime => Action::BUY_ALL,
+			Regime::TrendingDown if self.regime != regime => Action::SELL_ALL,
+			_ => Action::None,
+		};
+		self.regime = regime;
+
+		IndicatorResult::new(
+			&[regime.value(), percentile, strength, direction],
+			&[signal],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MarketRegime, Regime};
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorResult, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(close: ValueType, range: ValueType) -> Candle {
+		Candle {
+			open: close,
+			high: range.mul_add(0.5, close),
+			low: range.mul_add(-0.5, close),
+			close,
+			volume: 1.,
+		}
+	}
+
+	fn regimes(results: &[IndicatorResult]) -> Vec<Regime> {
+		results
+			.iter()
+			.map(|result| Regime::from_value(result.value(0)).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_market_regime_flat() {
+		let candles = vec![candle(100., 1.); 100];
+		let results = MarketRegime::default().over(&candles).unwrap();
+
+		assert!(regimes(&results).iter().all(|&x| x == Regime::Ranging));
+		for result in &results {
+			assert_eq_float(0., result.value(1));
+		}
+	}
+
+	#[test]
+	fn test_market_regime_volatile() {
+		let cfg = MarketRegime {
+			lookback: 50,
+			..MarketRegime::default()
+		};
+
+		// calm sideways market followed by wide swings
+		let candles: Vec<_> = (0..120)
+			.map(|i| {
+				if i < 100 {
+					candle(100., 1.)
+				} else {
+					candle(if i % 2 == 0 { 105. } else { 95. }, 10.)
+				}
+			})
+			.collect();
+
+		let regimes = regimes(&cfg.over(&candles).unwrap());
+
+		assert!(!regimes[..100].contains(&Regime::Volatile));
+		assert!(regimes[100..].contains(&Regime::Volatile));
+	}
+
+	#[test]
+	fn test_market_regime_trend() {
+		let candles: Vec<_> = (0..100)
+			.map(|i| candle(100. + i as ValueType, 1.))
+			.collect();
+
+		let results = MarketRegime::default().over(&candles).unwrap();
+
+		assert_eq!(regimes(&results)[99], Regime::TrendingUp);
+		assert_eq!(
+			results
+				.iter()
+				.filter(|result| result.signal(0) == Action::BUY_ALL)
+				.count(),
+			1
+		);
+		assert!(results
+			.iter()
+			.all(|result| result.signal(0) != Action::SELL_ALL));
+	}
+}
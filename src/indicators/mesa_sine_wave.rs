@@ -0,0 +1,316 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::Cross;
+
+const MIN_CYCLE_PERIOD: ValueType = 6.0;
+const MAX_CYCLE_PERIOD: ValueType = 50.0;
+const CYCLE_BUFFER_SIZE: PeriodType = 50;
+const HILBERT_SIZE: PeriodType = 7;
+
+/// John Ehlers' MESA Sine Wave
+///
+/// Dominant cycle period is measured by the Hilbert transform homodyne discriminator.
+/// Then the phase of the dominant cycle is used to calculate `sine` and `lead sine` values.
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/SineWaveIndicator.pdf>
+/// * John F. Ehlers, "Rocket Science for Traders", 2001
+///
+/// # 4 values
+///
+/// * `sine` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `lead sine` value (`sine` shifted by `45` degrees)
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `dominant cycle period`
+///
+/// Range in \[`6.0`; `50.0`\]
+///
+/// * `trend mode` flag
+///
+/// `1.0` when market is in trend mode, `0.0` when market is in cycle mode
+///
+/// # 2 signals
+///
+/// * When `sine` crosses `lead sine` upwards (cycle turns up), returns full buy signal.
+///   When `sine` crosses `lead sine` downwards (cycle turns down), returns full sell signal.
+///   Otherwise returns no signal.
+/// * The same as signal #1, but appears only when market is in cycle mode.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MESASineWave {
+	/// Trend mode threshold: relative distance between `source` value and the instantaneous trendline. Default is `0.015`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub trend_threshold: ValueType,
+
+	/// Source value type. Default is [`HL2`](crate::core::Source::HL2).
+	pub source: Source,
+}
+
+impl IndicatorConfig for MESASineWave {
+	type Instance = MESASineWaveInstance;
+
+	const NAME: &'static str = "MESASineWave";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			prices: Window::new(CYCLE_BUFFER_SIZE, src),
+			smooth: Window::new(CYCLE_BUFFER_SIZE, src),
+			detrender: Window::new(HILBERT_SIZE, 0.),
+			i1: Window::new(HILBERT_SIZE, 0.),
+			q1: Window::new(HILBERT_SIZE, 0.),
+			itrend: Window::new(4, src),
+			i2: 0.,
+			q2: 0.,
+			re: 0.,
+			im: 0.,
+			period: 0.,
+			smooth_period: 0.,
+			dc_phase: 0.,
+			days_in_trend: 0,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.trend_threshold > 0. && self.trend_threshold < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"trend_threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.trend_threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 2)
+	}
+}
+
+impl Default for MESASineWave {
+	fn default() -> Self {
+		Self {
+			trend_threshold: 0.015,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct MESASineWaveInstance {
+	cfg: MESASineWave,
+
+	prices: Window<ValueType>,
+	smooth: Window<ValueType>,
+	detrender: Window<ValueType>,
+	i1: Window<ValueType>,
+	q1: Window<ValueType>,
+	itrend: Window<ValueType>,
+	i2: ValueType,
+	q2: ValueType,
+	re: ValueType,
+	im: ValueType,
+	period: ValueType,
+	smooth_period: ValueType,
+	dc_phase: ValueType,
+	days_in_trend: usize,
+	cross: Cross,
+}
+
+#[allow(clippy::suboptimal_flops)]
+fn hilbert(w: &Window<ValueType>, adjustment: ValueType) -> ValueType {
+	(0.0962 * w[0] + 0.5769 * w[2] - 0.5769 * w[4] - 0.0962 * w[6]) * adjustment
+}
+
+#[allow(clippy::suboptimal_flops)]
+fn wma4(w: &Window<ValueType>) -> ValueType {
+	(4. * w[0] + 3. * w[1] + 2. * w[2] + w[3]) * 0.1
+}
+
+#[allow(clippy::suboptimal_flops)]
+impl MESASineWaveInstance {
+	/// Measures dominant cycle period using homodyne discriminator
+	fn cycle_period(&mut self, src: ValueType) -> ValueType {
+		self.prices.push(src);
+		self.smooth.push(wma4(&self.prices));
+
+		let adjustment = 0.075 * self.period + 0.54;
+
+		self.detrender.push(hilbert(&self.smooth, adjustment));
+		let q1 = hilbert(&self.detrender, adjustment);
+		let i1 = self.detrender[3];
+		self.q1.push(q1);
+		self.i1.push(i1);
+
+		// advance the phase of I1 and Q1 by 90 degrees
+		let j_i = hilbert(&self.i1, adjustment);
+		let j_q = hilbert(&self.q1, adjustment);
+
+		let i2 = 0.2 * (i1 - j_q) + 0.8 * self.i2;
+		let q2 = 0.2 * (q1 + j_i) + 0.8 * self.q2;
+
+		let re = i2 * self.i2 + q2 * self.q2;
+		let im = i2 * self.q2 - q2 * self.i2;
+		self.i2 = i2;
+		self.q2 = q2;
+
+		self.re = 0.2 * re + 0.8 * self.re;
+		self.im = 0.2 * im + 0.8 * self.im;
+
+		let prev_period = self.period;
+		let period = if self.im != 0. && self.re != 0. {
+			360. / (self.im / self.re).atan().to_degrees()
+		} else {
+			prev_period
+		};
+
+		let period = period
+			.min(1.5 * prev_period)
+			.max(0.67 * prev_period)
+			.clamp(MIN_CYCLE_PERIOD, MAX_CYCLE_PERIOD);
+
+		self.period = 0.2 * period + 0.8 * prev_period;
+		self.smooth_period = 0.33 * self.period + 0.67 * self.smooth_period;
+
+		self.smooth_period
+	}
+
+	/// Calculates dominant cycle phase in degrees
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn phase(&self, dc_period: PeriodType) -> ValueType {
+		let (real_part, imag_part) = (0..dc_period).fold((0., 0.), |(re, im), i| {
+			let angle = (360. * i as ValueType / dc_period as ValueType).to_radians();
+			let value = self.smooth[i];
+
+			(re + angle.sin() * value, im + angle.cos() * value)
+		});
+
+		let mut phase = self.dc_phase;
+
+		if imag_part.abs() > 0. {
+			phase = (real_part / imag_part).atan().to_degrees();
+		} else if real_part < 0. {
+			phase -= 90.;
+		} else if real_part > 0. {
+			phase += 90.;
+		}
+
+		phase += 90.;
+
+		// compensate one bar lag of the smoothed price
+		if self.smooth_period > 0. {
+			phase += 360. / self.smooth_period;
+		}
+
+		if imag_part < 0. {
+			phase += 180.;
+		}
+
+		if phase > 315. {
+			phase -= 360.;
+		}
+
+		phase
+	}
+}
+
+impl IndicatorInstance for MESASineWaveInstance {
+	type Config = MESASineWave;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		clippy::suboptimal_flops
+	)]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let smooth_period = self.cycle_period(src);
+		let dc_period = ((smooth_period + 0.5) as PeriodType).clamp(1, CYCLE_BUFFER_SIZE);
+
+		let prev_phase = self.dc_phase;
+		self.dc_phase = self.phase(dc_period);
+
+		let sine = self.dc_phase.to_radians().sin();
+		let lead_sine = (self.dc_phase + 45.).to_radians().sin();
+
+		let signal1 = self.cross.next((sine, lead_sine));
+
+		// instantaneous trendline
+		let itrend = self
+			.prices
+			.iter()
+			.take(dc_period as usize)
+			.sum::<ValueType>()
+			/ dc_period as ValueType;
+		self.itrend.push(itrend);
+		let trendline = wma4(&self.itrend);
+
+		// cycle mode starts each time when `sine` crosses `lead sine`
+		if signal1.is_some() {
+			self.days_in_trend = 0;
+		}
+
+		let mut is_trend = signal1.is_none();
+
+		self.days_in_trend += 1;
+		if (self.days_in_trend as ValueType) < 0.5 * smooth_period {
+			is_trend = false;
+		}
+
+		let delta_phase = self.dc_phase - prev_phase;
+		if smooth_period > 0.
+			&& delta_phase > 0.67 * 360. / smooth_period
+			&& delta_phase < 1.5 * 360. / smooth_period
+		{
+			is_trend = false;
+		}
+
+		if trendline != 0.
+			&& ((self.smooth[0] - trendline) / trendline).abs() >= self.cfg.trend_threshold
+		{
+			is_trend = true;
+		}
+
+		let signal2 = if is_trend { Action::None } else { signal1 };
+
+		IndicatorResult::new(
+			&[sine, lead_sine, smooth_period, is_trend as i8 as ValueType],
+			&[signal1, signal2],
+		)
+	}
+}
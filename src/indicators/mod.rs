@@ -46,9 +46,15 @@ impl OHLCV for HLC {
 	}
 }
 
+mod anomaly_detector;
+pub use anomaly_detector::{AnomalyDetector, AnomalyResponse};
+
 mod aroon;
 pub use aroon::Aroon;
 
+mod aroon_oscillator;
+pub use aroon_oscillator::AroonOscillator;
+
 mod average_directional_index;
 pub use average_directional_index::AverageDirectionalIndex;
 
@@ -58,51 +64,105 @@ pub use awesome_oscillator::AwesomeOscillator;
 mod bollinger_bands;
 pub use bollinger_bands::BollingerBands;
 
+mod bollinger_percent_b;
+pub use bollinger_percent_b::BollingerPercentB;
+
+mod center_of_gravity;
+pub use center_of_gravity::{CenterOfGravity, CG};
+
 mod chaikin_money_flow;
 pub use chaikin_money_flow::ChaikinMoneyFlow;
 
 mod chaikin_oscillator;
 pub use chaikin_oscillator::ChaikinOscillator;
 
+mod chaikin_volatility;
+pub use chaikin_volatility::ChaikinVolatility;
+
+mod chande_forecast_oscillator;
+pub use chande_forecast_oscillator::ChandeForecastOscillator;
+
 mod chande_kroll_stop;
 pub use chande_kroll_stop::ChandeKrollStop;
 
 mod chande_momentum_oscillator;
 pub use chande_momentum_oscillator::ChandeMomentumOscillator;
 
+mod chandelier_exit;
+pub use chandelier_exit::ChandelierExit;
+
+mod channel;
+pub use channel::{Channel, ChannelWidth};
+
 mod commodity_channel_index;
 pub use commodity_channel_index::CommodityChannelIndex;
 
 mod coppock_curve;
 pub use coppock_curve::CoppockCurve;
 
+mod cumulative_volume_delta;
+pub use cumulative_volume_delta::CumulativeVolumeDelta;
+
+mod demarker;
+pub use demarker::{DeM, DeMarker};
+
 mod detrended_price_oscillator;
 pub use detrended_price_oscillator::DetrendedPriceOscillator;
 
+mod disparity_index;
+pub use disparity_index::DisparityIndex;
+
+mod divergence;
+pub use divergence::Divergence;
+
 mod donchian_channel;
 pub use donchian_channel::DonchianChannel;
 
 mod ease_of_movement;
 pub use ease_of_movement::EaseOfMovement;
 
+mod elder_impulse;
+pub use elder_impulse::ElderImpulse;
+
 mod elders_force_index;
 pub use elders_force_index::EldersForceIndex;
 
 mod envelopes;
 pub use envelopes::Envelopes;
 
+mod filtered;
+pub use filtered::Filtered;
+
 mod fisher_transform;
 pub use fisher_transform::FisherTransform;
 
+mod gann_hilo_activator;
+pub use gann_hilo_activator::GannHiLoActivator;
+
+mod gaps;
+pub use gaps::{GapReference, Gaps};
+
+mod gator_oscillator;
+pub use gator_oscillator::GatorOscillator;
+
+mod historical_volatility;
+pub use historical_volatility::HistoricalVolatility;
+
 mod hull_moving_average;
 pub use hull_moving_average::HullMovingAverage;
 
 mod ichimoku_cloud;
 pub use ichimoku_cloud::IchimokuCloud;
 
+mod intraday_momentum_index;
+pub use intraday_momentum_index::{IntradayMomentumIndex, IMI};
+
 mod kaufman;
 pub use kaufman::{Kaufman, KAMA};
 
+mod kdj;
+pub use kdj::KDJ;
+
 mod keltner_channel;
 pub use keltner_channel::KeltnerChannel;
 
@@ -112,36 +172,123 @@ pub use klinger_volume_oscillator::KlingerVolumeOscillator;
 mod know_sure_thing;
 pub use know_sure_thing::KnowSureThing;
 
+mod laguerre_rsi;
+pub use laguerre_rsi::LaguerreRSI;
+
+mod linear_regression_channel;
+pub use linear_regression_channel::LinearRegressionChannel;
+
 mod macd;
 pub use macd::{MovingAverageConvergenceDivergence, MACD};
 
+mod market_regime;
+pub use market_regime::{MarketRegime, Regime};
+
+mod mesa_sine_wave;
+pub use mesa_sine_wave::MESASineWave;
+
 mod momentum_index;
 pub use momentum_index::MomentumIndex;
 
 mod money_flow_index;
 pub use money_flow_index::MoneyFlowIndex;
 
+mod moving_average_ribbon;
+pub use moving_average_ribbon::MovingAverageRibbon;
+
+mod negative_volume_index;
+pub use negative_volume_index::{NegativeVolumeIndex, NVI};
+
+mod normalized;
+pub use normalized::{NormalizationKind, Normalized};
+
+mod open_interest_change;
+pub use open_interest_change::OpenInterestChange;
+
+mod open_interest_divergence;
+pub use open_interest_divergence::OpenInterestDivergence;
+
 mod parabolic_sar;
 pub use parabolic_sar::{ParabolicSAR, ParabolicStopAndReverse};
 
+mod percent_rank;
+pub use percent_rank::PercentRank;
+
+mod percentage_price_oscillator;
+pub use percentage_price_oscillator::{PercentagePriceOscillator, PPO};
+
+mod percentage_volume_oscillator;
+pub use percentage_volume_oscillator::{PercentageVolumeOscillator, PVO};
+
+mod pivot_points;
+pub use pivot_points::{PivotPoints, PivotPointsKind};
+
 mod pivot_reversal_strategy;
 pub use pivot_reversal_strategy::PivotReversalStrategy;
 
+mod positive_volume_index;
+pub use positive_volume_index::{PositiveVolumeIndex, PVI};
+
+mod premier_stochastic;
+pub use premier_stochastic::PremierStochastic;
+
+mod pretty_good_oscillator;
+pub use pretty_good_oscillator::PrettyGoodOscillator;
+
 mod price_channel_strategy;
 pub use price_channel_strategy::PriceChannelStrategy;
 
+mod price_volume_trend;
+pub use price_volume_trend::{PriceVolumeTrend, PVT};
+
+mod psychological_line;
+pub use psychological_line::{PsychologicalLine, PSY};
+
+mod rainbow_oscillator;
+pub use rainbow_oscillator::RainbowOscillator;
+
+mod relative_momentum_index;
+pub use relative_momentum_index::{RelativeMomentumIndex, RMI};
+
 mod relative_strength_index;
 pub use relative_strength_index::{RelativeStrengthIndex, RSI};
 
 mod relative_vigor_index;
 pub use relative_vigor_index::RelativeVigorIndex;
 
+mod relative_volatility_index;
+pub use relative_volatility_index::RelativeVolatilityIndex;
+
 mod smi_ergodic_indicator;
 pub use smi_ergodic_indicator::SMIErgodicIndicator;
 
+mod smoothed;
+pub use smoothed::Smoothed;
+
+mod standard_error_bands;
+pub use standard_error_bands::StandardErrorBands;
+
+mod starc_bands;
+pub use starc_bands::STARCBands;
+
+mod stochastic_momentum_index;
+pub use stochastic_momentum_index::{StochasticMomentumIndex, SMI};
+
 mod stochastic_oscillator;
 pub use stochastic_oscillator::StochasticOscillator;
 
+mod stop_target;
+pub use stop_target::{StopMode, StopTarget};
+
+mod support_resistance;
+pub use support_resistance::SupportResistance;
+
+mod trend_intensity_index;
+pub use trend_intensity_index::TrendIntensityIndex;
+
+mod trendlines;
+pub use trendlines::Trendlines;
+
 mod trix;
 pub use trix::Trix;
 
@@ -151,5 +298,34 @@ pub use trend_strength_index::TrendStrengthIndex;
 mod true_strength_index;
 pub use true_strength_index::TrueStrengthIndex;
 
+mod ulcer_index;
+pub use ulcer_index::UlcerIndex;
+
+mod vertical_horizontal_filter;
+pub use vertical_horizontal_filter::VerticalHorizontalFilter;
+
+mod volatility_stop;
+pub use volatility_stop::VolatilityStop;
+
+mod volume_index;
+
+mod volume_oscillator;
+pub use volume_oscillator::VolumeOscillator;
+
+mod volume_profile;
+pub use volume_profile::VolumeProfile;
+
+mod voting;
+pub use voting::{Voting, VotingLogic};
+
+mod wave_trend;
+pub use wave_trend::WaveTrend;
+
+mod williams_fractals;
+pub use williams_fractals::WilliamsFractals;
+
 mod woodies_cci;
 pub use woodies_cci::WoodiesCCI;
+
+mod zig_zag;
+pub use zig_zag::ZigZag;
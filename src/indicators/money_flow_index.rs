@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::Candle;
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::Cross;
+use crate::methods::ZoneCross;
 
 /// Money Flow Index
 ///
@@ -67,8 +67,7 @@ impl IndicatorConfig for MoneyFlowIndex {
 			last_prev_candle: static_candle,
 			pmf: 0.,
 			nmf: 0.,
-			cross_lower: Cross::default(),
-			cross_upper: Cross::default(),
+			zone_cross: ZoneCross::new((cfg.zone, 1. - cfg.zone), 0.5)?,
 			cfg,
 		})
 	}
@@ -119,8 +118,7 @@ pub struct MoneyFlowIndexInstance {
 	last_prev_candle: Candle,
 	pmf: ValueType,
 	nmf: ValueType,
-	cross_lower: Cross,
-	cross_upper: Cross,
+	zone_cross: ZoneCross,
 }
 
 #[inline]
@@ -164,15 +162,47 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		let upper = 1. - self.cfg.zone;
 		let lower = self.cfg.zone;
 
-		let cross_upper: i8 = self.cross_upper.next((value, upper)).into();
-		let cross_lower: i8 = self.cross_lower.next((value, lower)).into();
+		let signals = self.zone_cross.next(value);
 
-		let enters_zone = (cross_lower < 0) as i8 - (cross_upper > 0) as i8;
-		let leaves_zone = (cross_lower > 0) as i8 - (cross_upper < 0) as i8;
+		IndicatorResult::new(&[upper, value, lower], &signals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MoneyFlowIndex;
+	use crate::core::{IndicatorConfig, Method, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::methods::Cross;
+
+	#[test]
+	fn test_money_flow_index_zone_signals() {
+		let candles: Vec<_> = RandomCandles::new().take(500).collect();
+
+		for &zone in &[0.0, 0.2, 0.35, 0.5] {
+			let cfg = MoneyFlowIndex { period: 5, zone };
+			let results = cfg.over(&candles).unwrap();
+
+			let (upper, lower) = (1. - zone, zone);
+			let mut cross_upper = Cross::default();
+			let mut cross_lower = Cross::default();
+			let mut signals = 0;
 
-		IndicatorResult::new(
-			&[upper, value, lower],
-			&[enters_zone.into(), leaves_zone.into()],
-		)
+			for (i, result) in results.iter().enumerate() {
+				let value: ValueType = result.value(1);
+				let overbought = cross_upper.next((value, upper)).analog();
+				let oversold = cross_lower.next((value, lower)).analog();
+
+				let enters = (oversold < 0) as i8 - (overbought > 0) as i8;
+				let leaves = (oversold > 0) as i8 - (overbought < 0) as i8;
+
+				assert_eq!(result.signal(0).analog(), enters, "enters at index {i}");
+				assert_eq!(result.signal(1).analog(), leaves, "leaves at index {i}");
+
+				signals += (enters != 0) as usize + (leaves != 0) as usize;
+			}
+
+			assert!(zone < 0.1 || signals > 0);
+		}
 	}
 }
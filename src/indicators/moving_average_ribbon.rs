@@ -0,0 +1,214 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Moving Average Ribbon
+///
+/// A set of `count` moving averages with periods `period`, `period` + `step`, `period` + 2 * `step` and so on.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/m/movingaverageribbon.asp>
+///
+/// # `count` + 2 values
+///
+/// * `count` moving averages values from the shortest to the longest period
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `alignment` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// `1.0` means every shorter moving average is above the longer one (bullish alignment),
+/// `-1.0` means every shorter moving average is below the longer one (bearish alignment).
+///
+/// * `compression` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// Distance between the highest and the lowest moving averages relative to the shortest one.
+///
+/// # 1 signal
+///
+/// When `alignment` becomes fully bullish, returns full buy signal.
+/// When `alignment` becomes fully bearish, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MovingAverageRibbon {
+	/// Count of moving averages. Default is `8`.
+	///
+	/// Range in \[`2`; [`MAX_COUNT`](Self::MAX_COUNT)\]
+	pub count: u8,
+
+	/// Period of the shortest moving average. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Period increment between the neighbouring moving averages. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	///
+	/// The longest period `period` + (`count` - 1) * `step` must be less than [`PeriodType::MAX`](crate::core::PeriodType).
+	pub step: PeriodType,
+
+	/// Moving averages type. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl MovingAverageRibbon {
+	/// Maximum count of moving averages, so all the values fit into [`IndicatorResult`]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const MAX_COUNT: u8 = (IndicatorResult::SIZE - 2) as u8;
+
+	fn periods(self) -> impl Iterator<Item = PeriodType> {
+		(0..self.count).map(move |i| self.period + PeriodType::from(i) * self.step)
+	}
+}
+
+impl IndicatorConfig for MovingAverageRibbon {
+	type Instance = MovingAverageRibbonInstance;
+
+	const NAME: &'static str = "MovingAverageRibbon";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			mas: cfg
+				.periods()
+				.map(|period| method(cfg.method, period, src))
+				.collect::<Result<_, _>>()?,
+			values: vec![src; cfg.count as usize + 2],
+			alignment: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		let longest = (self.count as usize)
+			.saturating_sub(1)
+			.saturating_mul(self.step as usize)
+			.saturating_add(self.period as usize);
+
+		self.count > 1
+			&& self.count <= Self::MAX_COUNT
+			&& self.period > 0
+			&& self.step > 0
+			&& longest < PeriodType::MAX as usize
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"count" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.count = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"step" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.step = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(self.count + 2, 1)
+	}
+}
+
+impl Default for MovingAverageRibbon {
+	fn default() -> Self {
+		Self {
+			count: 8,
+			period: 5,
+			step: 5,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct MovingAverageRibbonInstance {
+	cfg: MovingAverageRibbon,
+
+	mas: Vec<RegularMethod>,
+	values: Vec<ValueType>,
+	alignment: isize,
+}
+
+impl IndicatorInstance for MovingAverageRibbonInstance {
+	type Config = MovingAverageRibbon;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let count = self.mas.len();
+
+		self.values
+			.iter_mut()
+			.zip(self.mas.iter_mut())
+			.for_each(|(value, ma)| *value = ma.next(src));
+
+		let mas = &self.values[..count];
+
+		let alignment: isize = mas
+			.windows(2)
+			.map(|pair| (pair[0] > pair[1]) as isize - (pair[0] < pair[1]) as isize)
+			.sum();
+
+		let (lowest, highest) = mas.iter().fold((mas[0], mas[0]), |(lowest, highest), &ma| {
+			(lowest.min(ma), highest.max(ma))
+		});
+
+		let compression = if mas[0] == 0. {
+			0.
+		} else {
+			(highest - lowest) / mas[0].abs()
+		};
+
+		let full = self.cfg.count as isize - 1;
+		let signal = (alignment == full && self.alignment != full) as i8
+			- (alignment == -full && self.alignment != -full) as i8;
+		self.alignment = alignment;
+
+		self.values[count] = alignment as ValueType / full as ValueType;
+		self.values[count + 1] = compression;
+
+		IndicatorResult::new(&self.values, &[signal.into()])
+	}
+}
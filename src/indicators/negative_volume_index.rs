@@ -0,0 +1,138 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::volume_index::{VolumeDirection, VolumeIndex};
+use crate::core::{Error, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::RegularMethods;
+
+/// Negative Volume Index
+///
+/// Starts from `1000.0` and changes by the `source` value relative change only when `volume` decreases comparing to the previous bar.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/n/nvi.asp>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:negative_volume_inde>
+///
+/// # 2 values
+///
+/// * `NVI` value
+///
+/// Range in \(`0.0`; `+inf`\)
+///
+/// * `signal line` value
+///
+/// Range in \(`0.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `NVI` crosses `signal line` upwards, returns full buy signal.
+/// When `NVI` crosses `signal line` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NegativeVolumeIndex {
+	/// Signal line period. Default is `250`.
+	///
+	/// Commonly used period is `255`, but it is out of range for the default `u8` [`PeriodType`](crate::core::PeriodType),
+	/// so the nearest round period is used instead.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+
+	/// Signal line moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for NegativeVolumeIndex {
+	type Instance = NegativeVolumeIndexInstance;
+
+	const NAME: &'static str = "NegativeVolumeIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			volume_index: VolumeIndex::new(
+				VolumeDirection::Decrease,
+				cfg.signal_period,
+				cfg.method,
+				candle.source(cfg.source),
+				candle.volume(),
+			)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.signal_period > 1 && self.signal_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for NegativeVolumeIndex {
+	fn default() -> Self {
+		Self {
+			signal_period: 250,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct NegativeVolumeIndexInstance {
+	cfg: NegativeVolumeIndex,
+
+	volume_index: VolumeIndex,
+}
+
+/// Just an alias for `NegativeVolumeIndex`
+pub type NVI = NegativeVolumeIndex;
+
+impl IndicatorInstance for NegativeVolumeIndexInstance {
+	type Config = NegativeVolumeIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.volume_index
+			.next(candle.source(self.cfg.source), candle.volume())
+	}
+}
@@ -0,0 +1,251 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Highest, Lowest, StDev, SMA};
+
+/// Normalization formulas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum NormalizationKind {
+	/// (`value` - `lowest`) / (`highest` - `lowest`) over the last `normalize_period` values.
+	///
+	/// Range in \[`0.0`; `1.0`\]. When `highest` equals `lowest`, returns `0.5`.
+	MinMax,
+
+	/// (`value` - `SMA`) / `StDev` over the last `normalize_period` values.
+	///
+	/// Range in \(`-inf`; `+inf`\). When `StDev` equals `0.0`, returns `0.0`.
+	ZScore,
+}
+
+impl FromStr for NormalizationKind {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"minmax" | "min_max" => Ok(Self::MinMax),
+			"zscore" | "z_score" => Ok(Self::ZScore),
+
+			value => Err(Error::ParameterParse(
+				"normalize_kind".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Normalized indicator
+///
+/// Wraps any `indicator` and normalizes its selected output values over the last `normalize_period` values,
+/// so outputs of different indicators may be compared or combined on a common scale.
+///
+/// Parameters of the inner `indicator` may be changed by [`set`](crate::core::IndicatorConfig::set) the same way as for the `indicator` itself.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{NormalizationKind, Normalized, MACD};
+///
+/// // MACD values scaled to [0.0; 1.0] over the last 50 values
+/// let normalized = Normalized {
+///     indicator: MACD::default(),
+///     normalize_kind: NormalizationKind::MinMax,
+///     normalize_period: 50,
+///     ..Normalized::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = normalized.over(&candles).unwrap();
+///
+/// results.iter().for_each(|result| {
+///     assert!(result.values().iter().all(|&x| x >= 0.0 && x <= 1.0));
+/// });
+/// ```
+///
+/// # Values
+///
+/// The same values as the inner `indicator` has. Values selected by `normalize_mask` are normalized.
+///
+/// Range depends on [`NormalizationKind`].
+///
+/// # Signals
+///
+/// The same signals as the inner `indicator` has.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Normalized<I> {
+	/// Inner indicator.
+	pub indicator: I,
+
+	/// Normalization formula. Default is [`MinMax`](NormalizationKind::MinMax).
+	pub normalize_kind: NormalizationKind,
+
+	/// Normalization period. Default is `50`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub normalize_period: PeriodType,
+
+	/// Bit mask of the inner indicator's values to normalize: `n`-th bit selects `n`-th value. Default is `u16::MAX` (all the values).
+	pub normalize_mask: u16,
+}
+
+impl<I: IndicatorConfig> IndicatorConfig for Normalized<I> {
+	type Instance = NormalizedInstance<I>;
+
+	const NAME: &'static str = "Normalized";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		// first output of the inner indicator is used as initial value for normalization windows
+		let first = cfg.indicator.clone().init(candle)?.next(candle);
+		let normalizers = first
+			.values()
+			.iter()
+			.enumerate()
+			.filter(|&(index, _)| cfg.normalize_mask >> index & 1 == 1)
+			.map(|(index, &value)| {
+				Ok((
+					index,
+					Normalizer::new(cfg.normalize_kind, cfg.normalize_period, value)?,
+				))
+			})
+			.collect::<Result<_, Error>>()?;
+
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			normalizers,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.indicator.validate()
+			&& self.normalize_period > 1
+			&& self.normalize_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"normalize_kind" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.normalize_kind = value,
+			},
+			"normalize_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.normalize_period = value,
+			},
+			"normalize_mask" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.normalize_mask = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.indicator.size()
+	}
+}
+
+impl<I: Default> Default for Normalized<I> {
+	fn default() -> Self {
+		Self {
+			indicator: I::default(),
+			normalize_kind: NormalizationKind::MinMax,
+			normalize_period: 50,
+			normalize_mask: u16::MAX,
+		}
+	}
+}
+
+#[derive(Debug)]
+enum Normalizer {
+	MinMax(Highest, Lowest),
+	ZScore(SMA, StDev),
+}
+
+impl Normalizer {
+	fn new(kind: NormalizationKind, period: PeriodType, value: ValueType) -> Result<Self, Error> {
+		match kind {
+			NormalizationKind::MinMax => Ok(Self::MinMax(
+				Highest::new(period, value)?,
+				Lowest::new(period, value)?,
+			)),
+			NormalizationKind::ZScore => Ok(Self::ZScore(
+				SMA::new(period, value)?,
+				StDev::new(period, value)?,
+			)),
+		}
+	}
+
+	fn next(&mut self, value: ValueType) -> ValueType {
+		match self {
+			Self::MinMax(highest, lowest) => {
+				let highest = highest.next(value);
+				let lowest = lowest.next(value);
+				let delta = highest - lowest;
+
+				if delta > 0. {
+					(value - lowest) / delta
+				} else {
+					0.5
+				}
+			}
+			Self::ZScore(sma, st_dev) => {
+				let mean = sma.next(value);
+				let st_dev = st_dev.next(value);
+
+				if st_dev > 0. {
+					(value - mean) / st_dev
+				} else {
+					0.
+				}
+			}
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct NormalizedInstance<I: IndicatorConfig> {
+	cfg: Normalized<I>,
+
+	instance: I::Instance,
+	normalizers: Vec<(usize, Normalizer)>,
+}
+
+impl<I: IndicatorConfig> IndicatorInstance for NormalizedInstance<I> {
+	type Config = Normalized<I>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		let length = result.values().len();
+		let mut values = [0 as ValueType; IndicatorResult::SIZE];
+		values[..length].copy_from_slice(result.values());
+
+		for (index, normalizer) in &mut self.normalizers {
+			values[*index] = normalizer.next(values[*index]);
+		}
+
+		IndicatorResult::new(&values[..length], result.signals())
+	}
+}
@@ -0,0 +1,208 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Momentum, RateOfChange};
+
+/// Open Interest Change
+///
+/// Measures relative change of [open interest](crate::core::OHLCV::open_interest) and `close` value over `period` candles
+/// and classifies the market by the combination of both:
+///
+/// * price rises and open interest rises: new long positions, bullish trend confirmation;
+/// * price falls and open interest rises: new short positions, bearish trend confirmation;
+/// * open interest falls: positions are closed (short covering or long liquidation), trend is not confirmed.
+///
+/// Requires candles with open interest (e.g. [`FuturesCandle`](crate::core::FuturesCandle)), otherwise all the values are `NaN` and there are no signals.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/o/openinterest.asp>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Action, FuturesCandle, ValueType};
+/// use yata::indicators::OpenInterestChange;
+///
+/// let candles: Vec<_> = (0..20)
+///     .map(|i| FuturesCandle {
+///         close: 100.0 + i as ValueType,
+///         open_interest: 1000.0 + 10.0 * i as ValueType,
+///         ..FuturesCandle::default()
+///     })
+///     .collect();
+///
+/// let results = OpenInterestChange { period: 5 }.over(&candles).unwrap();
+///
+/// assert!(results[19].value(0) > 0.);
+/// assert_eq!(results[19].signal(0), Action::BUY_ALL);
+/// ```
+///
+/// # 3 values
+///
+/// * Relative change of open interest over `period` candles
+///
+/// Range in \[`-1.0`; `+inf`\)
+///
+/// * Relative change of `close` value over `period` candles
+///
+/// Range in \[`-1.0`; `+inf`\)
+///
+/// * Absolute change of open interest over `period` candles
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When open interest rises together with price, returns full buy signal.
+/// When open interest rises while price falls, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpenInterestChange {
+	/// Count of candles to measure change over. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for OpenInterestChange {
+	type Instance = OpenInterestChangeInstance;
+
+	const NAME: &'static str = "OpenInterestChange";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			open_interest: RateOfChange::new(cfg.period, candle.open_interest())?,
+			oi_momentum: Momentum::new(cfg.period, candle.open_interest())?,
+			price: RateOfChange::new(cfg.period, candle.close())?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for OpenInterestChange {
+	fn default() -> Self {
+		Self { period: 14 }
+	}
+}
+
+#[derive(Debug)]
+pub struct OpenInterestChangeInstance {
+	cfg: OpenInterestChange,
+
+	open_interest: RateOfChange,
+	oi_momentum: Momentum,
+	price: RateOfChange,
+}
+
+impl IndicatorInstance for OpenInterestChangeInstance {
+	type Config = OpenInterestChange;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let open_interest = candle.open_interest();
+		let oi_change = self.open_interest.next(open_interest);
+		let oi_delta = self.oi_momentum.next(open_interest);
+		let price_change = self.price.next(candle.close());
+
+		let signal = if oi_change > 0. && price_change > 0. {
+			Action::BUY_ALL
+		} else if oi_change > 0. && price_change < 0. {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[oi_change, price_change, oi_delta], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::OpenInterestChange;
+	use crate::core::{Action, Candle, FuturesCandle, IndicatorConfig, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	fn candles(price_step: ValueType, oi_step: ValueType) -> Vec<FuturesCandle> {
+		(0..10)
+			.map(|i| FuturesCandle {
+				close: price_step.mul_add(i as ValueType, 100.),
+				open_interest: oi_step.mul_add(i as ValueType, 1000.),
+				..FuturesCandle::default()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_open_interest_change() {
+		let cfg = OpenInterestChange { period: 3 };
+
+		// new long positions
+		let results = cfg.over(candles(1., 10.)).unwrap();
+		assert_eq!(results[9].signal(0), Action::BUY_ALL);
+		assert_eq_float(1090. / 1060. - 1., results[9].value(0));
+		assert_eq_float(109. / 106. - 1., results[9].value(1));
+		assert_eq_float(30., results[9].value(2));
+
+		// new short positions
+		let results = cfg.over(candles(-1., 10.)).unwrap();
+		assert_eq!(results[9].signal(0), Action::SELL_ALL);
+		assert_eq_float(91. / 94. - 1., results[9].value(1));
+
+		// short covering and long liquidation do not confirm the trend
+		for price_step in [1., -1.] {
+			let results = cfg.over(candles(price_step, -10.)).unwrap();
+			assert_eq!(results[9].signal(0), Action::None);
+			assert_eq_float(-30., results[9].value(2));
+		}
+	}
+
+	#[test]
+	fn test_open_interest_change_without_open_interest() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let results = OpenInterestChange { period: 3 }.over(&candles).unwrap();
+
+		assert!(results.iter().all(|result| {
+			result.value(0).is_nan()
+				&& result.value(2).is_nan()
+				&& !result.value(1).is_nan()
+				&& result.signal(0) == Action::None
+		}));
+	}
+}
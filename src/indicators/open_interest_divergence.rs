@@ -0,0 +1,184 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Price / Open Interest Divergence
+///
+/// Searches for divergences between `close` value and [open interest](crate::core::OHLCV::open_interest):
+///
+/// * bearish divergence: `close` value makes new high over the previous `period` candles, while open interest is lower than `period` candles ago.
+///   Rally is driven by short covering, not by new long positions;
+/// * bullish divergence: `close` value makes new low over the previous `period` candles, while open interest is lower than `period` candles ago.
+///   Decline is driven by long liquidation, not by new short positions.
+///
+/// Requires candles with open interest (e.g. [`FuturesCandle`](crate::core::FuturesCandle)), otherwise there are no signals.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/o/openinterest.asp>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Action, FuturesCandle, ValueType};
+/// use yata::indicators::OpenInterestDivergence;
+///
+/// // price rises, while positions are being closed
+/// let candles: Vec<_> = (0..20)
+///     .map(|i| FuturesCandle {
+///         close: 100.0 + i as ValueType,
+///         open_interest: 1000.0 - 10.0 * i as ValueType,
+///         ..FuturesCandle::default()
+///     })
+///     .collect();
+///
+/// let results = OpenInterestDivergence { period: 5 }.over(&candles).unwrap();
+///
+/// assert_eq!(results[19].signal(0), Action::SELL_ALL);
+/// ```
+///
+/// # 2 values
+///
+/// * Relative change of open interest over `period` candles
+///
+/// Range in \[`-1.0`; `+inf`\)
+///
+/// * Relative change of `close` value over `period` candles
+///
+/// Range in \[`-1.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// On bullish divergence returns full buy signal.
+/// On bearish divergence returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpenInterestDivergence {
+	/// Count of previous candles to search divergence over. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for OpenInterestDivergence {
+	type Instance = OpenInterestDivergenceInstance;
+
+	const NAME: &'static str = "OpenInterestDivergence";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			window: Window::new(cfg.period, (candle.close(), candle.open_interest())),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for OpenInterestDivergence {
+	fn default() -> Self {
+		Self { period: 20 }
+	}
+}
+
+#[derive(Debug)]
+pub struct OpenInterestDivergenceInstance {
+	cfg: OpenInterestDivergence,
+
+	/// previous `close` and open interest values
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl IndicatorInstance for OpenInterestDivergenceInstance {
+	type Config = OpenInterestDivergence;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let close = candle.close();
+		let open_interest = candle.open_interest();
+
+		let (mut is_high, mut is_low) = (true, true);
+		for (prev_close, _) in &self.window {
+			is_high &= close > prev_close;
+			is_low &= close < prev_close;
+		}
+
+		let (past_close, past_open_interest) = self.window.push((close, open_interest));
+		let oi_change = (open_interest - past_open_interest) / past_open_interest;
+		let price_change = (close - past_close) / past_close;
+
+		let signal = if oi_change < 0. && is_high {
+			Action::SELL_ALL
+		} else if oi_change < 0. && is_low {
+			Action::BUY_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[oi_change, price_change], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::OpenInterestDivergence;
+	use crate::core::{Action, Candle, FuturesCandle, IndicatorConfig, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_open_interest_divergence() {
+		// price falls, while positions are being closed
+		let candles: Vec<_> = (0..10)
+			.map(|i| FuturesCandle {
+				close: 100. - i as ValueType,
+				open_interest: ValueType::mul_add(-10., i as ValueType, 1000.),
+				..FuturesCandle::default()
+			})
+			.collect();
+
+		let results = OpenInterestDivergence { period: 3 }.over(&candles).unwrap();
+		assert_eq!(results[9].signal(0), Action::BUY_ALL);
+		assert_eq_float(910. / 940. - 1., results[9].value(0));
+
+		// candles without open interest never produce signals
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let results = OpenInterestDivergence { period: 3 }.over(&candles).unwrap();
+		assert!(results
+			.iter()
+			.all(|result| result.signal(0) == Action::None));
+	}
+}
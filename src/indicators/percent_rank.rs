@@ -0,0 +1,152 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{PercentRank as PercentRankMethod, ZoneCross};
+
+/// Percent Rank
+///
+/// Part of the previous `period` values, which are less than the current `source` value.
+///
+/// Commonly used to normalize volume or volatility values and as a part of Connors RSI.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/pine-script-reference/v5/#fun_ta.percentrank>
+/// * <https://en.wikipedia.org/wiki/Percentile_rank>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses `upper_zone` upwards, returns full sell signal.
+/// When main value crosses `lower_zone` downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses `upper_zone` downwards, returns full sell signal.
+/// When main value crosses `lower_zone` upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentRank {
+	/// Main period. Default is `100`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Overbought zone. Default is `0.9`.
+	///
+	/// Range in \(`lower_zone`; `1.0`\)
+	pub upper_zone: ValueType,
+
+	/// Oversold zone. Default is `0.1`.
+	///
+	/// Range in \(`0.0`; `upper_zone`\)
+	pub lower_zone: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for PercentRank {
+	type Instance = PercentRankInstance;
+
+	const NAME: &'static str = "PercentRank";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			percent_rank: PercentRankMethod::new(cfg.period, candle.source(cfg.source))?,
+			zone_cross: ZoneCross::new((cfg.lower_zone, cfg.upper_zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.lower_zone > 0.
+			&& self.lower_zone < self.upper_zone
+			&& self.upper_zone < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"upper_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.upper_zone = value,
+			},
+			"lower_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lower_zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for PercentRank {
+	fn default() -> Self {
+		Self {
+			period: 100,
+			upper_zone: 0.9,
+			lower_zone: 0.1,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PercentRankInstance {
+	cfg: PercentRank,
+
+	percent_rank: PercentRankMethod,
+	zone_cross: ZoneCross,
+}
+
+impl IndicatorInstance for PercentRankInstance {
+	type Config = PercentRank;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = self.percent_rank.next(candle.source(self.cfg.source));
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
@@ -0,0 +1,198 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+
+/// Percentage Price Oscillator
+///
+/// The same as [`MACD`](crate::indicators::MACD), but the difference between fast and slow moving averages
+/// is divided by the slow moving average value.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:price_oscillators_ppo>
+/// * <https://www.investopedia.com/terms/p/ppo.asp>
+///
+/// # 3 values
+///
+/// * `PPO` value
+///
+/// Range in \(`-inf`; `+inf`\) (`0.01` is `1%`)
+///
+/// * `Signal line` value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * `Histogram` value (`PPO` - `Signal line`)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When `PPO` crosses `Signal line` upwards, returns full buy signal.
+///   When `PPO` crosses `Signal line` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `PPO` crosses zero line upwards, returns full buy signal.
+///   When `PPO` crosses zero line downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentagePriceOscillator {
+	/// Fast MA period. Default is `12`.
+	///
+	/// Range in \[`2`; `period2`\)
+	pub period1: PeriodType,
+
+	/// Fast MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method1: RegularMethods,
+
+	/// Slow MA period. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// Slow MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method2: RegularMethods,
+
+	/// Signal line MA period. Default is `9`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+
+	/// Signal line MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method3: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for PercentagePriceOscillator {
+	type Instance = PercentagePriceOscillatorInstance;
+
+	const NAME: &'static str = "PercentagePriceOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma1: method(cfg.method1, cfg.period1, src)?,
+			ma2: method(cfg.method2, cfg.period2, src)?,
+			ma3: method(cfg.method3, cfg.period3, 0.)?,
+			cross1: Cross::default(),
+			cross2: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 1
+			&& self.period3 < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"method1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method1 = value,
+			},
+			"method2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method2 = value,
+			},
+			"method3" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method3 = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl Default for PercentagePriceOscillator {
+	fn default() -> Self {
+		Self {
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			method1: RegularMethods::EMA,
+			method2: RegularMethods::EMA,
+			method3: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PercentagePriceOscillatorInstance {
+	cfg: PercentagePriceOscillator,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	ma3: RegularMethod,
+	cross1: Cross,
+	cross2: Cross,
+}
+
+/// Just an alias for `PercentagePriceOscillator`
+pub type PPO = PercentagePriceOscillator;
+
+impl IndicatorInstance for PercentagePriceOscillatorInstance {
+	type Config = PercentagePriceOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let ma1 = self.ma1.next(src);
+		let ma2 = self.ma2.next(src);
+
+		let ppo = if ma2 == 0. { 0. } else { (ma1 - ma2) / ma2 };
+		let sigline = self.ma3.next(ppo);
+		let histogram = ppo - sigline;
+
+		let signal1 = self.cross1.next((ppo, sigline));
+		let signal2 = self.cross2.next((ppo, 0.0));
+
+		IndicatorResult::new(&[ppo, sigline, histogram], &[signal1, signal2])
+	}
+}
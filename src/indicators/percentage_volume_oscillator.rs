@@ -0,0 +1,179 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::percentage_price_oscillator::{
+	PercentagePriceOscillator, PercentagePriceOscillatorInstance,
+};
+use crate::core::{Error, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::RegularMethods;
+
+/// Percentage Volume Oscillator
+///
+/// The same as [`PercentagePriceOscillator`](crate::indicators::PercentagePriceOscillator), but calculated over `volume` values.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:percentage_volume_oscillator_pvo>
+///
+/// # 3 values
+///
+/// * `PVO` value
+///
+/// Range in \[`-1.0`; `+inf`\) (`0.01` is `1%`)
+///
+/// * `Signal line` value
+///
+/// Range in \[`-1.0`; `+inf`\)
+///
+/// * `Histogram` value (`PVO` - `Signal line`)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When `PVO` crosses `Signal line` upwards, returns full buy signal.
+///   When `PVO` crosses `Signal line` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `PVO` crosses zero line upwards, returns full buy signal.
+///   When `PVO` crosses zero line downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentageVolumeOscillator {
+	/// Fast MA period. Default is `12`.
+	///
+	/// Range in \[`2`; `period2`\)
+	pub period1: PeriodType,
+
+	/// Fast MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method1: RegularMethods,
+
+	/// Slow MA period. Default is `26`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// Slow MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method2: RegularMethods,
+
+	/// Signal line MA period. Default is `9`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+
+	/// Signal line MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method3: RegularMethods,
+}
+
+impl PercentageVolumeOscillator {
+	const fn ppo(self) -> PercentagePriceOscillator {
+		PercentagePriceOscillator {
+			period1: self.period1,
+			method1: self.method1,
+			period2: self.period2,
+			method2: self.method2,
+			period3: self.period3,
+			method3: self.method3,
+			source: Source::Volume,
+		}
+	}
+}
+
+impl IndicatorConfig for PercentageVolumeOscillator {
+	type Instance = PercentageVolumeOscillatorInstance;
+
+	const NAME: &'static str = "PercentageVolumeOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			ppo: cfg.ppo().init(candle)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ppo().validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period3 = value,
+			},
+			"method1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method1 = value,
+			},
+			"method2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method2 = value,
+			},
+			"method3" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method3 = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl Default for PercentageVolumeOscillator {
+	fn default() -> Self {
+		Self {
+			period1: 12,
+			period2: 26,
+			period3: 9,
+			method1: RegularMethods::EMA,
+			method2: RegularMethods::EMA,
+			method3: RegularMethods::EMA,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PercentageVolumeOscillatorInstance {
+	cfg: PercentageVolumeOscillator,
+
+	ppo: PercentagePriceOscillatorInstance,
+}
+
+/// Just an alias for `PercentageVolumeOscillator`
+pub type PVO = PercentageVolumeOscillator;
+
+impl IndicatorInstance for PercentageVolumeOscillatorInstance {
+	type Config = PercentageVolumeOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.ppo.next(candle)
+	}
+}
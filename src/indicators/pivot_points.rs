@@ -0,0 +1,401 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use super::HLC;
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{Session, SessionPeriod};
+use crate::methods::Cross;
+
+/// Pivot points calculation formulas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PivotPointsKind {
+	/// `P` = (`H` + `L` + `C`) / 3, `R1` = 2 * `P` - `L`, `S1` = 2 * `P` - `H`,
+	/// `R2` = `P` + (`H` - `L`), `S2` = `P` - (`H` - `L`), `R3` = `H` + 2 * (`P` - `L`), `S3` = `L` - 2 * (`H` - `P`)
+	Classic,
+
+	/// `P` = (`H` + `L` + `C`) / 3, `R1..R3` = `P` + (`H` - `L`) * \[`0.382`, `0.618`, `1.0`\], `S1..S3` are mirrored
+	Fibonacci,
+
+	/// `P` = (`H` + `L` + `C`) / 3, `R1..R3` = `C` + (`H` - `L`) * `1.1` / \[`12`, `6`, `4`\], `S1..S3` are mirrored
+	Camarilla,
+
+	/// The same as [`Classic`](PivotPointsKind::Classic), but `P` = (`H` + `L` + 2 * `C`) / 4
+	Woodie,
+}
+
+impl FromStr for PivotPointsKind {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"classic" | "standard" => Ok(Self::Classic),
+			"fibonacci" | "fib" => Ok(Self::Fibonacci),
+			"camarilla" => Ok(Self::Camarilla),
+			"woodie" => Ok(Self::Woodie),
+
+			value => Err(Error::ParameterParse("kind".to_string(), value.to_string())),
+		}
+	}
+}
+
+/// Pivot Points
+///
+/// Support and resistance levels calculated over `high`, `low` and `close` values of the previous session.
+/// Session is a group of `period` consecutive bars (f.e. `24` hourly bars for daily pivot points),
+/// the same way as [`collapse_timeframe`](crate::core::Sequence::collapse_timeframe) groups them.
+///
+/// Until the first session is complete, all the levels are calculated over the initial candle.
+///
+/// Sessions of the exchange hours in it's timezone (f.e. regular NYSE session instead of UTC days) are taken by
+/// [`over_session`](PivotPoints::over_session) and [`next_session`](PivotPointsInstance::next_session) instead of `period` bars.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis)>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:pivot_points>
+///
+/// # 7 values
+///
+/// * `P` (pivot) value
+/// * `R1`, `R2`, `R3` resistance levels
+/// * `S1`, `S2`, `S3` support levels
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 2 signals
+///
+/// * When `source` value crosses `P` upwards, returns full buy signal.
+///   When `source` value crosses `P` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `source` value crosses `R1` upwards, returns full buy signal.
+///   When `source` value crosses `S1` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotPoints {
+	/// Count of bars in the session. Default is `24`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub period: PeriodType,
+
+	/// Pivot points formula. Default is [`Classic`](PivotPointsKind::Classic).
+	pub kind: PivotPointsKind,
+
+	/// Source value type for level crosses. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for PivotPoints {
+	type Instance = PivotPointsInstance;
+
+	const NAME: &'static str = "PivotPoints";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let session = HLC::from(candle);
+
+		Ok(Self::Instance {
+			levels: cfg.levels(&session),
+			session,
+			bars: 0,
+			key: None,
+			cross_pivot: Cross::default(),
+			cross_resistance: Cross::default(),
+			cross_support: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"kind" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.kind = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(7, 2)
+	}
+}
+
+impl PivotPoints {
+	/// Evaluates indicator over `candles` with corresponding `timestamps`, taking sessions of the `period` from the `session`
+	///
+	/// Every timestamp is the open time of the candle. See [`next_session`](PivotPointsInstance::next_session).
+	///
+	/// Returns an error when indicator config or session parameters are invalid, `candles` are empty
+	/// or count of `timestamps` differs from count of `candles`.
+	pub fn over_session<T: OHLCV>(
+		self,
+		candles: &[T],
+		timestamps: &[i64],
+		session: &Session,
+		period: SessionPeriod,
+	) -> Result<Vec<IndicatorResult>, Error> {
+		if !session.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		if candles.len() != timestamps.len() {
+			return Err(Error::Other(
+				"count of timestamps must be equal to count of candles".to_string(),
+			));
+		}
+
+		let mut instance = self.init(candles.first().ok_or(Error::InvalidCandles)?)?;
+
+		Ok(candles
+			.iter()
+			.zip(timestamps)
+			.map(|(candle, &timestamp)| {
+				instance.next_session(candle, session.key(timestamp, period))
+			})
+			.collect())
+	}
+
+	/// Returns `[P, R1, R2, R3, S1, S2, S3]` for the session
+	#[allow(clippy::suboptimal_flops)]
+	fn levels(self, session: &HLC) -> [ValueType; 7] {
+		let (high, low, close) = (session.high, session.low, session.close);
+		let range = high - low;
+
+		match self.kind {
+			PivotPointsKind::Classic | PivotPointsKind::Woodie => {
+				let pivot = if self.kind == PivotPointsKind::Woodie {
+					(high + low + 2. * close) * 0.25
+				} else {
+					(high + low + close) / 3.
+				};
+
+				[
+					pivot,
+					2. * pivot - low,
+					pivot + range,
+					high + 2. * (pivot - low),
+					2. * pivot - high,
+					pivot - range,
+					low - 2. * (high - pivot),
+				]
+			}
+			PivotPointsKind::Fibonacci => {
+				let pivot = (high + low + close) / 3.;
+
+				[
+					pivot,
+					pivot + 0.382 * range,
+					pivot + 0.618 * range,
+					pivot + range,
+					pivot - 0.382 * range,
+					pivot - 0.618 * range,
+					pivot - range,
+				]
+			}
+			PivotPointsKind::Camarilla => {
+				let pivot = (high + low + close) / 3.;
+				let range = range * 1.1;
+
+				[
+					pivot,
+					close + range / 12.,
+					close + range / 6.,
+					close + range / 4.,
+					close - range / 12.,
+					close - range / 6.,
+					close - range / 4.,
+				]
+			}
+		}
+	}
+}
+
+impl Default for PivotPoints {
+	fn default() -> Self {
+		Self {
+			period: 24,
+			kind: PivotPointsKind::Classic,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PivotPointsInstance {
+	cfg: PivotPoints,
+
+	levels: [ValueType; 7],
+	session: HLC,
+	bars: PeriodType,
+	key: Option<i64>,
+	cross_pivot: Cross,
+	cross_resistance: Cross,
+	cross_support: Cross,
+}
+
+impl IndicatorInstance for PivotPointsInstance {
+	type Config = PivotPoints;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		if self.bars == self.cfg.period {
+			self.levels = self.cfg.levels(&self.session);
+			self.bars = 0;
+		}
+
+		self.push(candle, self.bars == 0);
+		self.bars += 1;
+
+		self.signals(candle)
+	}
+}
+
+impl PivotPointsInstance {
+	/// Evaluates the **State** over the `candle`, which belongs to the session with the `key` instead of counting `period` bars
+	///
+	/// `key` is the [session key](Session::key) of the candle's timestamp. Levels are recalculated, when the `key` changes.
+	/// Candles out of trading hours (`None` key) do not change the session values, but produce signals.
+	pub fn next_session<T: OHLCV>(&mut self, candle: &T, key: Option<i64>) -> IndicatorResult {
+		if let Some(key) = key {
+			match self.key {
+				Some(current) if current == key => self.push(candle, false),
+				current => {
+					if current.is_some() {
+						self.levels = self.cfg.levels(&self.session);
+					}
+
+					self.push(candle, true);
+					self.key = Some(key);
+				}
+			}
+		}
+
+		self.signals(candle)
+	}
+
+	fn push<T: OHLCV>(&mut self, candle: &T, starts: bool) {
+		if starts {
+			self.session = HLC::from(candle);
+		} else {
+			self.session.high = self.session.high.max(candle.high());
+			self.session.low = self.session.low.min(candle.low());
+			self.session.close = candle.close();
+		}
+	}
+
+	fn signals<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let levels = self.levels;
+
+		let signal1 = self.cross_pivot.next((src, levels[0]));
+		let signal2 = self.cross_resistance.next((src, levels[1])).analog().max(0)
+			+ self.cross_support.next((src, levels[4])).analog().min(0);
+
+		IndicatorResult::new(&levels, &[signal1, signal2.into()])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::PivotPoints;
+	use crate::core::{Candle, IndicatorConfig, ValueType};
+	use crate::helpers::{assert_eq_float, Session, SessionPeriod};
+
+	// 2021-03-01 00:00:00 UTC, Monday
+	const START: i64 = 1_614_556_800;
+
+	#[test]
+	fn test_pivot_points_session() {
+		let timestamps: Vec<i64> = (0..24 * 3).map(|i| START + i * 3600).collect();
+		let candles: Vec<_> = (0..timestamps.len())
+			.map(|i| {
+				let price = i as ValueType;
+				Candle {
+					open: price,
+					high: price + 1.,
+					low: price - 1.,
+					close: price + 0.5,
+					volume: 1.,
+				}
+			})
+			.collect();
+
+		let session = Session::nyse();
+		let results = PivotPoints::default()
+			.over_session(&candles, &timestamps, &session, SessionPeriod::Daily)
+			.unwrap();
+
+		// 09:30 - 16:00 EST is 14:30 - 21:00 UTC, so Monday's session is made of candles 15 - 20
+		let levels = |high: ValueType, low: ValueType, close: ValueType| {
+			PivotPoints::default()
+				.over([Candle {
+					high,
+					low,
+					close,
+					..Candle::default()
+				}])
+				.unwrap()[0]
+		};
+
+		let initial = levels(1., -1., 0.5);
+		let monday = levels(21., 14., 20.5);
+
+		for (i, result) in results.iter().enumerate() {
+			let expected = if i < 24 + 15 { initial } else { monday };
+			for (&a, &b) in expected.values().iter().zip(result.values()) {
+				assert_eq_float(a, b);
+			}
+		}
+
+		// the same sessions as aggregated bars give
+		let bars = session
+			.aggregate(&candles, &timestamps, SessionPeriod::Daily)
+			.unwrap();
+		let (_, bar) = bars[0];
+		assert_eq_float(21., bar.high);
+		assert_eq_float(14., bar.low);
+		assert_eq_float(20.5, bar.close);
+
+		assert!(PivotPoints::default()
+			.over_session(&candles, &timestamps[1..], &session, SessionPeriod::Daily)
+			.is_err());
+		assert!(PivotPoints::default()
+			.over_session(
+				&candles[..0],
+				&timestamps[..0],
+				&session,
+				SessionPeriod::Daily
+			)
+			.is_err());
+	}
+}
@@ -0,0 +1,137 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::volume_index::{VolumeDirection, VolumeIndex};
+use crate::core::{Error, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::RegularMethods;
+
+/// Positive Volume Index
+///
+/// Starts from `1000.0` and changes by the `source` value relative change only when `volume` increases comparing to the previous bar.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/p/pvi.asp>
+///
+/// # 2 values
+///
+/// * `PVI` value
+///
+/// Range in \(`0.0`; `+inf`\)
+///
+/// * `signal line` value
+///
+/// Range in \(`0.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `PVI` crosses `signal line` upwards, returns full buy signal.
+/// When `PVI` crosses `signal line` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PositiveVolumeIndex {
+	/// Signal line period. Default is `250`.
+	///
+	/// Commonly used period is `255`, but it is out of range for the default `u8` [`PeriodType`](crate::core::PeriodType),
+	/// so the nearest round period is used instead.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+
+	/// Signal line moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for PositiveVolumeIndex {
+	type Instance = PositiveVolumeIndexInstance;
+
+	const NAME: &'static str = "PositiveVolumeIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			volume_index: VolumeIndex::new(
+				VolumeDirection::Increase,
+				cfg.signal_period,
+				cfg.method,
+				candle.source(cfg.source),
+				candle.volume(),
+			)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.signal_period > 1 && self.signal_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for PositiveVolumeIndex {
+	fn default() -> Self {
+		Self {
+			signal_period: 250,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PositiveVolumeIndexInstance {
+	cfg: PositiveVolumeIndex,
+
+	volume_index: VolumeIndex,
+}
+
+/// Just an alias for `PositiveVolumeIndex`
+pub type PVI = PositiveVolumeIndex;
+
+impl IndicatorInstance for PositiveVolumeIndexInstance {
+	type Config = PositiveVolumeIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.volume_index
+			.next(candle.source(self.cfg.source), candle.volume())
+	}
+}
@@ -0,0 +1,238 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Highest, Lowest, ZoneCross};
+
+/// Premier Stochastic Oscillator
+///
+/// Raw stochastic value over `period` bars is normalized to \[`-5.0`; `5.0`\], smoothed twice by `method` of length `smooth_period`
+/// and then exponentially normalized by `(exp(x) - 1) / (exp(x) + 1)`.
+///
+/// ## Links
+///
+/// * Lee Leibfarth, "Premier Stochastic Oscillator", Technical Analysis of Stocks & Commodities, 2008
+/// * <https://toslc.thinkorswim.com/center/reference/Tech-Indicators/studies-library/M-N/PremierStochasticOscillator>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \(`-1.0`; `1.0`\)
+///
+/// # 2 signals
+///
+/// * Signal #1 on leaves outer zone.
+///
+/// When main value crosses `outer_zone` downwards, returns full sell signal.
+/// When main value crosses -`outer_zone` upwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves inner zone.
+///
+/// When main value crosses `inner_zone` downwards, returns full sell signal.
+/// When main value crosses -`inner_zone` upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PremierStochastic {
+	/// Period for searching highest high and lowest low. Default is `8`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Smoothing period. Default is `5`.
+	///
+	/// Leibfarth smooths by EMA of length `round(sqrt(25)) = 5`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smooth_period: PeriodType,
+
+	/// Smoothing method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Outer zone. Default is `0.9`.
+	///
+	/// Range in \(`inner_zone`; `1.0`\)
+	pub outer_zone: ValueType,
+
+	/// Inner zone. Default is `0.2`.
+	///
+	/// Range in \(`0.0`; `outer_zone`\)
+	pub inner_zone: ValueType,
+}
+
+impl IndicatorConfig for PremierStochastic {
+	type Instance = PremierStochasticInstance;
+
+	const NAME: &'static str = "PremierStochastic";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let value = normalize(candle.close(), candle.high(), candle.low());
+
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			ma1: method(cfg.method, cfg.smooth_period, value)?,
+			ma2: method(cfg.method, cfg.smooth_period, value)?,
+			outer_cross: ZoneCross::new((-cfg.outer_zone, cfg.outer_zone), (value * 0.5).tanh())?,
+			inner_cross: ZoneCross::new((-cfg.inner_zone, cfg.inner_zone), (value * 0.5).tanh())?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.smooth_period > 1
+			&& self.smooth_period < PeriodType::MAX
+			&& self.inner_zone > 0.
+			&& self.inner_zone < self.outer_zone
+			&& self.outer_zone < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"smooth_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"outer_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.outer_zone = value,
+			},
+			"inner_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.inner_zone = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for PremierStochastic {
+	fn default() -> Self {
+		Self {
+			period: 8,
+			smooth_period: 5,
+			method: RegularMethods::EMA,
+			outer_zone: 0.9,
+			inner_zone: 0.2,
+		}
+	}
+}
+
+/// Raw stochastic value mapped from \[`0.0`; `1.0`\] to \[`-5.0`; `5.0`\]
+fn normalize(close: ValueType, highest: ValueType, lowest: ValueType) -> ValueType {
+	// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+	#[allow(clippy::float_cmp)]
+	let k = if highest == lowest {
+		0.5
+	} else {
+		(close - lowest) / (highest - lowest)
+	};
+
+	k.mul_add(10., -5.)
+}
+
+#[derive(Debug)]
+pub struct PremierStochasticInstance {
+	cfg: PremierStochastic,
+
+	highest: Highest,
+	lowest: Lowest,
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	outer_cross: ZoneCross,
+	inner_cross: ZoneCross,
+}
+
+impl IndicatorInstance for PremierStochasticInstance {
+	type Config = PremierStochastic;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let highest = self.highest.next(candle.high());
+		let lowest = self.lowest.next(candle.low());
+
+		let smoothed = self
+			.ma2
+			.next(self.ma1.next(normalize(candle.close(), highest, lowest)));
+
+		// (exp(x) - 1) / (exp(x) + 1) = tanh(x / 2)
+		let value = (smoothed * 0.5).tanh();
+
+		let [_, outer] = self.outer_cross.next(value);
+		let [_, inner] = self.inner_cross.next(value);
+
+		IndicatorResult::new(&[value], &[outer, inner])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::PremierStochastic;
+	use crate::core::{Candle, IndicatorConfig, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_premier_stochastic() {
+		let candles: Vec<Candle> = [
+			(10., 8., 9.),
+			(12., 9., 12.),
+			(13., 11., 11.),
+			(12., 9., 9.),
+			(11., 7., 8.),
+		]
+		.iter()
+		.map(|&(high, low, close)| Candle {
+			high,
+			low,
+			close,
+			..Candle::default()
+		})
+		.collect();
+
+		let cfg = PremierStochastic {
+			period: 3,
+			..PremierStochastic::default()
+		};
+
+		// normalized stochastic: 0, 5, 1, -5, -10/3
+		// EMA(5) of EMA(5) of it: 0, 5/9, 23/27, 1/3, -74/243
+		let smoothed: [ValueType; 5] = [0., 5. / 9., 23. / 27., 1. / 3., -74. / 243.];
+
+		let results = cfg.over(&candles).unwrap();
+
+		for (result, x) in results.iter().zip(&smoothed) {
+			assert_eq_float((x.exp() - 1.) / (x.exp() + 1.), result.value(0));
+		}
+	}
+}
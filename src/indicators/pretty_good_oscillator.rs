@@ -0,0 +1,144 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{CrossAbove, CrossUnder, EMA, SMA};
+
+/// Pretty Good Oscillator
+///
+/// Distance of the `source` value from its simple moving average measured in average true ranges:
+/// (`source` - `SMA`) / `EMA`(`true range`).
+///
+/// ## Links
+///
+/// * Mark Johnson, "Pretty Good Oscillator"
+/// * <https://www.metastock.com/customer/resources/taaz/?p=109>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `main` value crosses `threshold` upwards, returns full buy signal.
+/// When `main` value crosses -`threshold` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrettyGoodOscillator {
+	/// Main period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Breakout threshold. Default is `3.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub threshold: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for PrettyGoodOscillator {
+	type Instance = PrettyGoodOscillatorInstance;
+
+	const NAME: &'static str = "PrettyGoodOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			prev_close: candle.close(),
+			sma: SMA::new(cfg.period, src)?,
+			atr: EMA::new(cfg.period, candle.high() - candle.low())?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX && self.threshold > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for PrettyGoodOscillator {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			threshold: 3.0,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct PrettyGoodOscillatorInstance {
+	cfg: PrettyGoodOscillator,
+
+	prev_close: ValueType,
+	sma: SMA,
+	atr: EMA,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for PrettyGoodOscillatorInstance {
+	type Config = PrettyGoodOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+
+		let sma = self.sma.next(src);
+		let atr = self.atr.next(tr);
+
+		let value = if atr > 0. { (src - sma) / atr } else { 0. };
+
+		let signal = self.cross_above.next((value, self.cfg.threshold))
+			- self.cross_under.next((value, -self.cfg.threshold));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, Integral};
+
+/// Price Volume Trend
+///
+/// Cumulative sum of `volume` * (`source` - `source[1]`) / `source[1]`.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Volume%E2%80%93price_trend>
+/// * <https://www.investopedia.com/terms/v/vptindicator.asp>
+///
+/// # 2 values
+///
+/// * `PVT` value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * `signal line` value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `PVT` crosses `signal line` upwards, returns full buy signal.
+/// When `PVT` crosses `signal line` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PriceVolumeTrend {
+	/// Cumulative sum size. Default is `0` (windowless).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub window: PeriodType,
+
+	/// Signal line period. Default is `21`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+
+	/// Signal line moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for PriceVolumeTrend {
+	type Instance = PriceVolumeTrendInstance;
+
+	const NAME: &'static str = "PriceVolumeTrend";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_value: candle.source(cfg.source),
+			integral: Integral::new(cfg.window, 0.)?,
+			ma: method(cfg.method, cfg.signal_period, 0.)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.signal_period > 1 && self.signal_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"window" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.window = value,
+			},
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for PriceVolumeTrend {
+	fn default() -> Self {
+		Self {
+			window: 0,
+			signal_period: 21,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PriceVolumeTrendInstance {
+	cfg: PriceVolumeTrend,
+
+	prev_value: ValueType,
+	integral: Integral,
+	ma: RegularMethod,
+	cross: Cross,
+}
+
+/// Just an alias for `PriceVolumeTrend`
+pub type PVT = PriceVolumeTrend;
+
+impl IndicatorInstance for PriceVolumeTrendInstance {
+	type Config = PriceVolumeTrend;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let change = if self.prev_value == 0. {
+			0.
+		} else {
+			(src - self.prev_value) / self.prev_value
+		};
+		self.prev_value = src;
+
+		let pvt = self.integral.next(change * candle.volume());
+		let signal_line = self.ma.next(pvt);
+
+		let signal = self.cross.next((pvt, signal_line));
+
+		IndicatorResult::new(&[pvt, signal_line], &[signal])
+	}
+}
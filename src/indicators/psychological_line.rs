@@ -0,0 +1,205 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::ZoneCross;
+
+/// Psychological Line (`PSY`)
+///
+/// Ratio of bars closed higher than the previous bar over the last `period` bars.
+///
+/// While less than `period` bars has been passed, ratio is calculated only over the bars which are already known.
+/// So there is no bias towards zero on the first `period` bars.
+/// Signals are held until the first `period` bars has been passed.
+///
+/// ## Links
+///
+/// * <https://www.marketvolume.com/technicalanalysis/psychologicalline.asp>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses `upper_zone` upwards, returns full sell signal.
+/// When main value crosses `lower_zone` downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses `upper_zone` downwards, returns full sell signal.
+/// When main value crosses `lower_zone` upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PsychologicalLine {
+	/// Main period. Default is `12`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Overbought zone. Default is `0.75`.
+	///
+	/// Range in \(`lower_zone`; `1.0`\)
+	pub upper_zone: ValueType,
+
+	/// Oversold zone. Default is `0.25`.
+	///
+	/// Range in \(`0.0`; `upper_zone`\)
+	pub lower_zone: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for PsychologicalLine {
+	type Instance = PsychologicalLineInstance;
+
+	const NAME: &'static str = "PsychologicalLine";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_value: candle.source(cfg.source),
+			window: Window::new(cfg.period, false),
+			ups: 0,
+			count: 0,
+			zone_cross: ZoneCross::new((cfg.lower_zone, cfg.upper_zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.lower_zone > 0.
+			&& self.lower_zone < self.upper_zone
+			&& self.upper_zone < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"upper_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.upper_zone = value,
+			},
+			"lower_zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.lower_zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for PsychologicalLine {
+	fn default() -> Self {
+		Self {
+			period: 12,
+			upper_zone: 0.75,
+			lower_zone: 0.25,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PsychologicalLineInstance {
+	cfg: PsychologicalLine,
+
+	prev_value: ValueType,
+	window: Window<bool>,
+	ups: PeriodType,
+	count: PeriodType,
+	zone_cross: ZoneCross,
+}
+
+/// Just an alias for `PsychologicalLine`
+pub type PSY = PsychologicalLine;
+
+impl IndicatorInstance for PsychologicalLineInstance {
+	type Config = PsychologicalLine;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let is_up = src > self.prev_value;
+		self.prev_value = src;
+
+		let was_up = self.window.push(is_up);
+		self.ups = self.ups + is_up as PeriodType - was_up as PeriodType;
+		self.count = (self.count + 1).min(self.cfg.period);
+
+		let value = self.ups as ValueType / self.count as ValueType;
+
+		let signals = self.zone_cross.next(value);
+
+		// ratio over the first few bars is too rough to produce signals
+		if self.count < self.cfg.period {
+			return IndicatorResult::new(&[value], &[Action::None; 2]);
+		}
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::PsychologicalLine;
+	use crate::core::{Action, Candle, IndicatorConfig, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_psychological_line_warm_up() {
+		let candles: Vec<_> = (0..10)
+			.map(|i| Candle {
+				close: 100. + i as ValueType,
+				..Candle::default()
+			})
+			.collect();
+
+		let results = PsychologicalLine {
+			period: 4,
+			..PsychologicalLine::default()
+		}
+		.over(&candles)
+		.unwrap();
+
+		assert!(results[..3]
+			.iter()
+			.all(|r| r.signal(0) == Action::None && r.signal(1) == Action::None));
+		assert!(results.iter().all(|r| r.value(0) <= 1.));
+		assert_eq_float(1., results[9].value(0));
+	}
+}
@@ -0,0 +1,231 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{CrossAbove, CrossUnder, HighestLowestDelta};
+
+/// Rainbow Oscillator
+///
+/// `count` moving averages are calculated recursively: the first one over the `source` values,
+/// every next one over the previous moving average values.
+///
+/// `oscillator` = (`source` - average of all the moving averages) / (highest `source` - lowest `source`)
+///
+/// `bandwidth` = (highest moving average - lowest moving average) / (highest `source` - lowest `source`)
+///
+/// where highest and lowest `source` values are taken over the last `hl_period` values.
+///
+/// ## Links
+///
+/// * Mel Widner, "Rainbow Charts", Technical Analysis of Stocks & Commodities, 1997
+///
+/// # `count` + 2 values
+///
+/// * `oscillator` value
+///
+/// Range in \(`-inf`; `+inf`\), mostly in \[`-1.0`; `1.0`\]
+///
+/// * `bandwidth` value
+///
+/// Range in \[`0.0`; `+inf`\), mostly in \[`0.0`; `1.0`\]
+///
+/// * `count` moving averages values from the first to the last one
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 2 signals
+///
+/// * When `oscillator` crosses `threshold` upwards, returns full buy signal.
+///   When `oscillator` crosses -`threshold` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `oscillator` crosses `bandwidth` upwards, returns full buy signal.
+///   When `oscillator` crosses -`bandwidth` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RainbowOscillator {
+	/// Count of recursively smoothed moving averages. Default is `10`.
+	///
+	/// Range in \[`2`; [`MAX_COUNT`](Self::MAX_COUNT)\]
+	pub count: u8,
+
+	/// Period of every moving average. Default is `2`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Moving averages type. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Period for searching the highest and the lowest `source` values. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub hl_period: PeriodType,
+
+	/// Signal threshold. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub threshold: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl RainbowOscillator {
+	/// Maximum count of moving averages, so all the values fit into [`IndicatorResult`]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const MAX_COUNT: u8 = (IndicatorResult::SIZE - 2) as u8;
+}
+
+impl IndicatorConfig for RainbowOscillator {
+	type Instance = RainbowOscillatorInstance;
+
+	const NAME: &'static str = "RainbowOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			mas: (0..cfg.count)
+				.map(|_| method(cfg.method, cfg.period, src))
+				.collect::<Result<_, _>>()?,
+			delta: HighestLowestDelta::new(cfg.hl_period, src)?,
+			values: vec![src; cfg.count as usize + 2],
+			cross_above_threshold: CrossAbove::default(),
+			cross_under_threshold: CrossUnder::default(),
+			cross_above_bandwidth: CrossAbove::default(),
+			cross_under_bandwidth: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.count > 1
+			&& self.count <= Self::MAX_COUNT
+			&& self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.hl_period > 1
+			&& self.hl_period < PeriodType::MAX
+			&& self.threshold > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"count" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.count = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"hl_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.hl_period = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(self.count + 2, 2)
+	}
+}
+
+impl Default for RainbowOscillator {
+	fn default() -> Self {
+		Self {
+			count: 10,
+			period: 2,
+			method: RegularMethods::SMA,
+			hl_period: 10,
+			threshold: 0.5,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct RainbowOscillatorInstance {
+	cfg: RainbowOscillator,
+
+	mas: Vec<RegularMethod>,
+	delta: HighestLowestDelta,
+	values: Vec<ValueType>,
+	cross_above_threshold: CrossAbove,
+	cross_under_threshold: CrossUnder,
+	cross_above_bandwidth: CrossAbove,
+	cross_under_bandwidth: CrossUnder,
+}
+
+impl IndicatorInstance for RainbowOscillatorInstance {
+	type Config = RainbowOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let range = self.delta.next(src);
+
+		let mut value = src;
+		self.values[2..]
+			.iter_mut()
+			.zip(self.mas.iter_mut())
+			.for_each(|(ma_value, ma)| {
+				value = ma.next(value);
+				*ma_value = value;
+			});
+
+		let mas = &self.values[2..];
+		let average = mas.iter().sum::<ValueType>() / mas.len() as ValueType;
+		let (lowest, highest) = mas.iter().fold((mas[0], mas[0]), |(lowest, highest), &ma| {
+			(lowest.min(ma), highest.max(ma))
+		});
+
+		let (oscillator, bandwidth) = if range == 0. {
+			(0., 0.)
+		} else {
+			((src - average) / range, (highest - lowest) / range)
+		};
+
+		let signal1 = self
+			.cross_above_threshold
+			.next((oscillator, self.cfg.threshold))
+			- self
+				.cross_under_threshold
+				.next((oscillator, -self.cfg.threshold));
+		let signal2 = self.cross_above_bandwidth.next((oscillator, bandwidth))
+			- self.cross_under_bandwidth.next((oscillator, -bandwidth));
+
+		self.values[0] = oscillator;
+		self.values[1] = bandwidth;
+
+		IndicatorResult::new(&self.values, &[signal1, signal2])
+	}
+}
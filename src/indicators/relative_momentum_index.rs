@@ -0,0 +1,178 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Change, ZoneCross};
+
+/// Relative Momentum Index
+///
+/// [`RSI`](crate::indicators::RSI) generalized to `source` changes over the last `momentum` values.
+/// When `momentum` is `1`, it is the same as [`RSI`](crate::indicators::RSI).
+///
+/// ## Links
+///
+/// * Roger Altman, "Relative Momentum Index", Technical Analysis of Stocks & Commodities, 1993
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses upper zone upwards, returns full sell signal.
+/// When main value crosses lower zone downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses upper zone downwards, returns full sell signal.
+/// When main value crosses lower zone upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeMomentumIndex {
+	/// Smoothing period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Momentum lookback period. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub momentum: PeriodType,
+
+	/// Overbought/oversell relative zone. Default is `0.3`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+/// Just an alias for `RelativeMomentumIndex`
+pub type RMI = RelativeMomentumIndex;
+
+impl IndicatorConfig for RelativeMomentumIndex {
+	type Instance = RelativeMomentumIndexInstance;
+
+	const NAME: &'static str = "RelativeMomentumIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			change: Change::new(cfg.momentum, src)?,
+			posma: method(cfg.method, cfg.period, 0.)?,
+			negma: method(cfg.method, cfg.period, 0.)?,
+			zone_cross: ZoneCross::new((cfg.zone, 1.0 - cfg.zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.momentum > 0
+			&& self.momentum < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"momentum" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.momentum = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for RelativeMomentumIndex {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			momentum: 3,
+			zone: 0.3,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct RelativeMomentumIndexInstance {
+	cfg: RelativeMomentumIndex,
+
+	change: Change,
+	posma: RegularMethod,
+	negma: RegularMethod,
+	zone_cross: ZoneCross,
+}
+
+impl IndicatorInstance for RelativeMomentumIndexInstance {
+	type Config = RelativeMomentumIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let change = self.change.next(src);
+
+		let pos: ValueType = self.posma.next(change.max(0.));
+		let neg: ValueType = self.negma.next((-change).max(0.));
+
+		let value = if pos + neg > 0. {
+			pos / (pos + neg)
+		} else {
+			0.5
+		};
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Change, Cross};
+use crate::methods::{Change, ZoneCross};
 
 /// Relative Strength Index
 ///
@@ -68,8 +68,7 @@ impl IndicatorConfig for RelativeStrengthIndex {
 			change: Change::new(1, src)?,
 			posma: method(cfg.method, cfg.period, 0.)?,
 			negma: method(cfg.method, cfg.period, 0.)?,
-			cross_upper: Cross::new((), (0.5, 1.0 - cfg.zone))?,
-			cross_lower: Cross::new((), (0.5, cfg.zone))?,
+			zone_cross: ZoneCross::new((cfg.zone, 1.0 - cfg.zone), 0.5)?,
 			cfg,
 		})
 	}
@@ -128,8 +127,7 @@ pub struct RelativeStrengthIndexInstance {
 	change: Change,
 	posma: RegularMethod,
 	negma: RegularMethod,
-	cross_upper: Cross,
-	cross_lower: Cross,
+	zone_cross: ZoneCross,
 }
 
 /// Just an alias for `RelativeStrengthIndex`
@@ -156,12 +154,8 @@ impl IndicatorInstance for RelativeStrengthIndexInstance {
 			0.
 		};
 
-		let oversold = self.cross_lower.next((value, self.cfg.zone)).analog();
-		let overbought = self.cross_upper.next((value, 1. - self.cfg.zone)).analog();
+		let signals = self.zone_cross.next(value);
 
-		let signal1 = (oversold < 0) as i8 - (overbought > 0) as i8;
-		let signal2 = (oversold > 0) as i8 - (overbought < 0) as i8;
-
-		IndicatorResult::new(&[value], &[signal1.into(), signal2.into()])
+		IndicatorResult::new(&[value], &signals)
 	}
 }
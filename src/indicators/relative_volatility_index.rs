@@ -0,0 +1,181 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{StDev, ZoneCross};
+
+/// Relative Volatility Index
+///
+/// It's like [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex), but uses standard deviation of the `source` values instead of the absolute changes.
+///
+/// Not to be confused with [`RelativeVigorIndex`](crate::indicators::RelativeVigorIndex).
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/r/relative_vol_index.asp>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses upper zone upwards, returns full sell signal.
+/// When main value crosses lower zone downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses upper zone downwards, returns full sell signal.
+/// When main value crosses lower zone upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeVolatilityIndex {
+	/// Standard deviation period. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub std_dev_period: PeriodType,
+
+	/// Smoothing period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Overbought/oversold relative zone. Default is `0.3`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Smoothing moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for RelativeVolatilityIndex {
+	type Instance = RelativeVolatilityIndexInstance;
+
+	const NAME: &'static str = "RelativeVolatilityIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			prev_value: src,
+			st_dev: StDev::new(cfg.std_dev_period, src)?,
+			posma: method(cfg.method, cfg.period, 0.)?,
+			negma: method(cfg.method, cfg.period, 0.)?,
+			zone_cross: ZoneCross::new((cfg.zone, 1.0 - cfg.zone), 0.5)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.std_dev_period > 1
+			&& self.std_dev_period < PeriodType::MAX
+			&& self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"std_dev_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.std_dev_period = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for RelativeVolatilityIndex {
+	fn default() -> Self {
+		Self {
+			std_dev_period: 10,
+			period: 14,
+			zone: 0.3,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct RelativeVolatilityIndexInstance {
+	cfg: RelativeVolatilityIndex,
+
+	prev_value: ValueType,
+	st_dev: StDev,
+	posma: RegularMethod,
+	negma: RegularMethod,
+	zone_cross: ZoneCross,
+}
+
+impl IndicatorInstance for RelativeVolatilityIndexInstance {
+	type Config = RelativeVolatilityIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let st_dev = self.st_dev.next(src);
+
+		let change = src - self.prev_value;
+		self.prev_value = src;
+
+		let pos = self.posma.next(st_dev * (change > 0.) as u8 as ValueType);
+		let neg = self.negma.next(st_dev * (change < 0.) as u8 as ValueType);
+
+		let value = if pos + neg > 0. {
+			pos / (pos + neg)
+		} else {
+			0.5
+		};
+
+		let signals = self.zone_cross.next(value);
+
+		IndicatorResult::new(&[value], &signals)
+	}
+}
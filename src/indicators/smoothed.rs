@@ -0,0 +1,163 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Smoothed indicator
+///
+/// Wraps any `indicator` and smooths its selected output values by moving average `smooth_method` of length `smooth_period`.
+///
+/// Parameters of the inner `indicator` may be changed by [`set`](crate::core::IndicatorConfig::set) the same way as for the `indicator` itself.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{RandomCandles, RegularMethods};
+/// use yata::indicators::{Smoothed, RSI};
+///
+/// // RSI smoothed by EMA of length 5
+/// let mut smoothed = Smoothed {
+///     indicator: RSI::default(),
+///     smooth_method: RegularMethods::EMA,
+///     smooth_period: 5,
+///     ..Smoothed::default()
+/// };
+///
+/// smoothed.set("period", "21".to_string()).unwrap();
+/// assert_eq!(smoothed.indicator.period, 21);
+///
+/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+/// let results = smoothed.over(&candles).unwrap();
+///
+/// assert_eq!(results[9].size(), (1, 2));
+/// ```
+///
+/// # Values
+///
+/// The same values as the inner `indicator` has. Values selected by `smooth_mask` are smoothed.
+///
+/// # Signals
+///
+/// The same signals as the inner `indicator` has.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Smoothed<I> {
+	/// Inner indicator.
+	pub indicator: I,
+
+	/// Smoothing method. Default is [`EMA`](crate::methods::EMA).
+	pub smooth_method: RegularMethods,
+
+	/// Smoothing period. Default is `5`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smooth_period: PeriodType,
+
+	/// Bit mask of the inner indicator's values to smooth: `n`-th bit selects `n`-th value. Default is `u16::MAX` (all the values).
+	pub smooth_mask: u16,
+}
+
+impl<I: IndicatorConfig> IndicatorConfig for Smoothed<I> {
+	type Instance = SmoothedInstance<I>;
+
+	const NAME: &'static str = "Smoothed";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		// first output of the inner indicator is used as initial value for smoothing methods
+		let first = cfg.indicator.clone().init(candle)?.next(candle);
+		let mas = first
+			.values()
+			.iter()
+			.enumerate()
+			.filter(|&(index, _)| cfg.smooth_mask >> index & 1 == 1)
+			.map(|(index, &value)| {
+				Ok((index, method(cfg.smooth_method, cfg.smooth_period, value)?))
+			})
+			.collect::<Result<_, Error>>()?;
+
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			mas,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.indicator.validate() && self.smooth_period > 1 && self.smooth_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"smooth_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_method = value,
+			},
+			"smooth_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_period = value,
+			},
+			"smooth_mask" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_mask = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.indicator.size()
+	}
+}
+
+impl<I: Default> Default for Smoothed<I> {
+	fn default() -> Self {
+		Self {
+			indicator: I::default(),
+			smooth_method: RegularMethods::EMA,
+			smooth_period: 5,
+			smooth_mask: u16::MAX,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct SmoothedInstance<I: IndicatorConfig> {
+	cfg: Smoothed<I>,
+
+	instance: I::Instance,
+	mas: Vec<(usize, RegularMethod)>,
+}
+
+impl<I: IndicatorConfig> IndicatorInstance for SmoothedInstance<I> {
+	type Config = Smoothed<I>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		let length = result.values().len();
+		let mut values = [0 as ValueType; IndicatorResult::SIZE];
+		values[..length].copy_from_slice(result.values());
+
+		for (index, ma) in &mut self.mas {
+			values[*index] = ma.next(values[*index]);
+		}
+
+		IndicatorResult::new(&values[..length], result.signals())
+	}
+}
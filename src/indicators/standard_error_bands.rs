@@ -0,0 +1,177 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::linear_regression_channel::Regression;
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{CrossAbove, CrossUnder};
+
+/// Standard Error Bands
+///
+/// Middle line is the linear regression value over the last `period` values.
+/// Bands are placed at `k` standard errors of the estimate around it.
+/// All three lines are smoothed by the moving average.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/standard-error-bands.asp>
+/// * Jon Andersen, "Standard Error Bands", Technical Analysis of Stocks & Commodities, 1996
+///
+/// # 3 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `middle line`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// When `source` value crosses `lower bound` downwards, returns full buy signal.
+/// When `source` value crosses `upper bound` upwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardErrorBands {
+	/// Linear regression period. Default is `21`.
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Standard error multiplier. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub k: ValueType,
+
+	/// Smoothing period. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smooth_period: PeriodType,
+
+	/// Smoothing method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for StandardErrorBands {
+	type Instance = StandardErrorBandsInstance;
+
+	const NAME: &'static str = "StandardErrorBands";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			regression: Regression::new(cfg.period, src)?,
+			ma_upper: method(cfg.method, cfg.smooth_period, src)?,
+			ma_middle: method(cfg.method, cfg.smooth_period, src)?,
+			ma_lower: method(cfg.method, cfg.smooth_period, src)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 2
+			&& self.period < PeriodType::MAX
+			&& self.k > 0.
+			&& self.smooth_period > 0
+			&& self.smooth_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"k" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.k = value,
+			},
+			"smooth_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.smooth_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for StandardErrorBands {
+	fn default() -> Self {
+		Self {
+			period: 21,
+			k: 2.0,
+			smooth_period: 3,
+			method: RegularMethods::SMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct StandardErrorBandsInstance {
+	cfg: StandardErrorBands,
+
+	regression: Regression,
+	ma_upper: RegularMethod,
+	ma_middle: RegularMethod,
+	ma_lower: RegularMethod,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for StandardErrorBandsInstance {
+	type Config = StandardErrorBands;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let regression = self.regression.next(src);
+		let width = regression.std_error * self.cfg.k;
+
+		let upper = self.ma_upper.next(regression.value + width);
+		let middle = self.ma_middle.next(regression.value);
+		let lower = self.ma_lower.next(regression.value - width);
+
+		let signal = self.cross_under.next((src, lower)) - self.cross_above.next((src, upper));
+
+		IndicatorResult::new(&[upper, middle, lower], &[signal])
+	}
+}
@@ -0,0 +1,169 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::RMA;
+
+/// STARC Bands (Stoller Average Range Channel)
+///
+/// Moving average of the `source` values with the bounds at `multiplier` average true ranges around it.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/starc.asp>
+///
+/// # 3 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `middle line`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// When `source` value touches or goes below `lower bound`, returns full buy signal.
+/// When `source` value touches or goes above `upper bound`, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct STARCBands {
+	/// Middle moving average period. Default is `6`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub ma_period: PeriodType,
+
+	/// Middle moving average type. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Average true range period. Default is `15`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_period: PeriodType,
+
+	/// Average true range multiplier. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub multiplier: ValueType,
+
+	/// Middle moving average source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for STARCBands {
+	type Instance = STARCBandsInstance;
+
+	const NAME: &'static str = "STARCBands";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			prev_close: candle.close(),
+			ma: method(cfg.method, cfg.ma_period, src)?,
+			atr: RMA::new(cfg.atr_period, candle.high() - candle.low())?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ma_period > 1
+			&& self.ma_period < PeriodType::MAX
+			&& self.atr_period > 1
+			&& self.atr_period < PeriodType::MAX
+			&& self.multiplier > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ma_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.ma_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"atr_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_period = value,
+			},
+			"multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.multiplier = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for STARCBands {
+	fn default() -> Self {
+		Self {
+			ma_period: 6,
+			method: RegularMethods::SMA,
+			atr_period: 15,
+			multiplier: 2.0,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct STARCBandsInstance {
+	cfg: STARCBands,
+
+	prev_close: ValueType,
+	ma: RegularMethod,
+	atr: RMA,
+}
+
+impl IndicatorInstance for STARCBandsInstance {
+	type Config = STARCBands;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+
+		let middle = self.ma.next(src);
+		let atr = self.atr.next(tr);
+
+		let upper = atr.mul_add(self.cfg.multiplier, middle);
+		let lower = atr.mul_add(-self.cfg.multiplier, middle);
+
+		let signal = (src <= lower) as i8 - (src >= upper) as i8;
+
+		IndicatorResult::new(&[upper, middle, lower], &[signal.into()])
+	}
+}
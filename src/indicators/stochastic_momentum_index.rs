@@ -0,0 +1,206 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, Highest, Lowest};
+
+/// Stochastic Momentum Index
+///
+/// Distance of the `close` value from the midpoint of the highest high and the lowest low range,
+/// double smoothed and divided by the double smoothed half of the range.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/stochasticmomentumindex.asp>
+/// * William Blau, "Stochastic Momentum", Technical Analysis of Stocks & Commodities, 1993
+///
+/// # 2 values
+///
+/// * `main` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `signal line` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * When `main` value crosses `signal line` upwards, returns full buy signal.
+///   When `main` value crosses `signal line` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `main` value crosses `0.0` upwards, returns full buy signal.
+///   When `main` value crosses `0.0` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StochasticMomentumIndex {
+	/// Period for searching highest high and lowest low. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// First smoothing period. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period1: PeriodType,
+
+	/// Second smoothing period. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// Smoothing method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Signal line period. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+
+	/// Signal line method. Default is [`EMA`](crate::methods::EMA).
+	pub signal_method: RegularMethods,
+}
+
+/// Just an alias for `StochasticMomentumIndex`
+pub type SMI = StochasticMomentumIndex;
+
+impl IndicatorConfig for StochasticMomentumIndex {
+	type Instance = StochasticMomentumIndexInstance;
+
+	const NAME: &'static str = "StochasticMomentumIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let range = candle.high() - candle.low();
+		let distance = (candle.high() + candle.low()).mul_add(-0.5, candle.close());
+
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			ma1: method(cfg.method, cfg.period1, distance)?,
+			ma2: method(cfg.method, cfg.period2, distance)?,
+			ma3: method(cfg.method, cfg.period1, range)?,
+			ma4: method(cfg.method, cfg.period2, range)?,
+			ma5: method(cfg.signal_method, cfg.signal_period, 0.)?,
+			cross1: Cross::default(),
+			cross2: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.period1 > 0
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 > 0
+			&& self.period2 < PeriodType::MAX
+			&& self.signal_period > 1
+			&& self.signal_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"period1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_period = value,
+			},
+			"signal_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_method = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for StochasticMomentumIndex {
+	fn default() -> Self {
+		Self {
+			period: 10,
+			period1: 3,
+			period2: 3,
+			method: RegularMethods::EMA,
+			signal_period: 10,
+			signal_method: RegularMethods::EMA,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct StochasticMomentumIndexInstance {
+	cfg: StochasticMomentumIndex,
+
+	highest: Highest,
+	lowest: Lowest,
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	ma3: RegularMethod,
+	ma4: RegularMethod,
+	ma5: RegularMethod,
+	cross1: Cross,
+	cross2: Cross,
+}
+
+impl IndicatorInstance for StochasticMomentumIndexInstance {
+	type Config = StochasticMomentumIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let highest = self.highest.next(candle.high());
+		let lowest = self.lowest.next(candle.low());
+
+		let distance = (highest + lowest).mul_add(-0.5, candle.close());
+		let range = highest - lowest;
+
+		let smoothed_distance = self.ma2.next(self.ma1.next(distance));
+		let smoothed_range = self.ma4.next(self.ma3.next(range));
+
+		let value: ValueType = if smoothed_range > 0. {
+			smoothed_distance / (smoothed_range * 0.5)
+		} else {
+			0.
+		};
+		let signal_line = self.ma5.next(value);
+
+		let signal1 = self.cross1.next((value, signal_line));
+		let signal2 = self.cross2.next((value, 0.));
+
+		IndicatorResult::new(&[value, signal_line], &[signal1, signal2])
+	}
+}
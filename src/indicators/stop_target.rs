@@ -0,0 +1,373 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use crate::core::{Action, Error, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Distance mode of the [`StopTarget`] levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum StopMode {
+	/// Distance is a multiple of [`ATR`](crate::methods::TR) at the moment of entry.
+	ATR,
+
+	/// Distance is a fixed part of the entry price.
+	Percent,
+}
+
+impl FromStr for StopMode {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"atr" => Ok(Self::ATR),
+			"percent" => Ok(Self::Percent),
+
+			value => Err(Error::ParameterParse(
+				"stop_mode".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Stop-loss and take-profit levels
+///
+/// Wraps any `indicator` and uses its signal with index `stop_signal` as entry signal: buy signal opens long position,
+/// sell signal opens short position at the `close` value. Opposite entry signal reverses the position.
+///
+/// For every open position stop-loss and take-profit levels are set at `stop_loss` and `stop_target` distance from the entry price.
+/// Distance is measured in [`ATR`](crate::methods::TR) of length `stop_period` or in parts of the entry price,
+/// according to the `stop_mode`.
+///
+/// Levels are checked intrabar on every next candle: for long position stop-loss is hit when `low` reaches it
+/// and take-profit is hit when `high` reaches it (and vice versa for short position). When some level is hit,
+/// exit signal is returned and position is closed.
+///
+/// Parameters of the inner `indicator` may be changed by [`set`](crate::core::IndicatorConfig::set) the same way as for the `indicator` itself.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/stop-lossorder.asp>
+/// * <https://www.investopedia.com/terms/t/take-profitorder.asp>
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{StopMode, StopTarget, RSI};
+///
+/// // exit RSI entries at 2% loss or at 5% profit
+/// let stops = StopTarget {
+///     indicator: RSI::default(),
+///     stop_mode: StopMode::Percent,
+///     stop_loss: 0.02,
+///     stop_target: 0.05,
+///     ..StopTarget::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = stops.over(&candles).unwrap();
+///
+/// assert_eq!(results[99].size(), (2, 2));
+/// ```
+///
+/// # 2 values
+///
+/// * Stop-loss level of the open position
+/// * Take-profit level of the open position
+///
+/// Both values are `NaN` when there is no open position or when the level is disabled.
+///
+/// # 2 signals
+///
+/// * Entry signal of the inner `indicator`.
+///
+/// * Exit signal.
+///
+/// When long position hits any level, returns full sell signal.
+/// When short position hits any level, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StopTarget<I> {
+	/// Inner indicator.
+	pub indicator: I,
+
+	/// Index of the inner indicator's signal used as entry signal. Default is `0`.
+	///
+	/// Range in \[`0`; `signals count`\)
+	pub stop_signal: u8,
+
+	/// Distance mode of the levels. Default is [`ATR`](StopMode::ATR).
+	pub stop_mode: StopMode,
+
+	/// Period of the `ATR`. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub stop_period: PeriodType,
+
+	/// Distance to the stop-loss level. Default is `2.0`. `0.0` disables stop-loss.
+	///
+	/// Range in \[`0.0`; `+inf`\) for [`ATR`](StopMode::ATR) mode and \[`0.0`; `1.0`\) for [`Percent`](StopMode::Percent) mode
+	pub stop_loss: ValueType,
+
+	/// Distance to the take-profit level. Default is `3.0`. `0.0` disables take-profit.
+	///
+	/// Range in \[`0.0`; `+inf`\)
+	pub stop_target: ValueType,
+}
+
+impl<I: IndicatorConfig> IndicatorConfig for StopTarget<I> {
+	type Instance = StopTargetInstance<I>;
+
+	const NAME: &'static str = "StopTarget";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			atr: method(
+				RegularMethods::RMA,
+				cfg.stop_period,
+				candle.high() - candle.low(),
+			)?,
+			prev_close: candle.close(),
+			position: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		let stop_loss = match self.stop_mode {
+			StopMode::ATR => self.stop_loss >= 0.,
+			StopMode::Percent => (0. ..1.).contains(&self.stop_loss),
+		};
+
+		self.indicator.validate()
+			&& self.stop_signal < self.indicator.size().1
+			&& self.stop_period > 0
+			&& stop_loss
+			&& self.stop_target >= 0.
+			&& self.stop_target.is_finite()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"stop_signal" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.stop_signal = value,
+			},
+			"stop_mode" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.stop_mode = value,
+			},
+			"stop_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.stop_period = value,
+			},
+			"stop_loss" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.stop_loss = value,
+			},
+			"stop_target" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.stop_target = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl<I: Default> Default for StopTarget<I> {
+	fn default() -> Self {
+		Self {
+			indicator: I::default(),
+			stop_signal: 0,
+			stop_mode: StopMode::ATR,
+			stop_period: 14,
+			stop_loss: 2.0,
+			stop_target: 3.0,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+	/// `1` for long position and `-1` for short position
+	sign: i8,
+	stop: ValueType,
+	target: ValueType,
+}
+
+impl Position {
+	fn is_hit<T: OHLCV>(&self, candle: &T) -> bool {
+		if self.sign > 0 {
+			candle.low() <= self.stop || candle.high() >= self.target
+		} else {
+			candle.high() >= self.stop || candle.low() <= self.target
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct StopTargetInstance<I: IndicatorConfig> {
+	cfg: StopTarget<I>,
+
+	instance: I::Instance,
+	atr: RegularMethod,
+	prev_close: ValueType,
+	position: Option<Position>,
+}
+
+impl<I: IndicatorConfig> StopTargetInstance<I> {
+	/// Returns the level at the `distance` from the `price` towards the `sign` direction, or `NaN` when `distance` is `0.0`
+	fn level(
+		&self,
+		price: ValueType,
+		sign: ValueType,
+		distance: ValueType,
+		atr: ValueType,
+	) -> ValueType {
+		if distance == 0. {
+			return ValueType::NAN;
+		}
+
+		let distance = match self.cfg.stop_mode {
+			StopMode::ATR => distance * atr,
+			StopMode::Percent => distance * price,
+		};
+
+		distance.mul_add(sign, price)
+	}
+}
+
+impl<I: IndicatorConfig> IndicatorInstance for StopTargetInstance<I> {
+	type Config = StopTarget<I>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		let atr = self.atr.next(candle.tr_close(self.prev_close));
+		self.prev_close = candle.close();
+
+		let exit = match self.position {
+			Some(position) if position.is_hit(candle) => {
+				self.position = None;
+				Action::from(-position.sign)
+			}
+			_ => Action::None,
+		};
+
+		let entry = result.signal(self.cfg.stop_signal as usize);
+		let sign = entry.analog();
+		let current = self.position.map_or(0, |position| position.sign);
+
+		if sign != 0 && sign != current {
+			let price = candle.close();
+
+			self.position = Some(Position {
+				sign,
+				stop: self.level(price, -sign as ValueType, self.cfg.stop_loss, atr),
+				target: self.level(price, sign as ValueType, self.cfg.stop_target, atr),
+			});
+		}
+
+		let values = self.position.map_or([ValueType::NAN; 2], |position| {
+			[position.stop, position.target]
+		});
+
+		IndicatorResult::new(&values, &[entry, exit])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{StopMode, StopTarget};
+	use crate::core::{Action, IndicatorConfig, OHLCV};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::RSI;
+
+	#[test]
+	fn test_stop_target_exits() {
+		let candles: Vec<_> = RandomCandles::new().take(500).collect();
+
+		for &mode in &[StopMode::ATR, StopMode::Percent] {
+			let stops = StopTarget {
+				indicator: RSI::default(),
+				stop_mode: mode,
+				stop_loss: 0.01,
+				stop_target: 0.02,
+				..StopTarget::default()
+			};
+			let results = stops.over(&candles).unwrap();
+
+			let mut exits = 0;
+			for (pair, candle) in results.windows(2).zip(&candles[1..]) {
+				let (stop, target) = (pair[0].value(0), pair[0].value(1));
+
+				match pair[1].signal(1) {
+					Action::None => {}
+					exit => {
+						exits += 1;
+
+						// long position is opened at a buy signal and closed by a sell signal
+						if exit.analog() < 0 {
+							assert!(stop < target);
+							assert!(candle.low() <= stop || candle.high() >= target);
+						} else {
+							assert!(stop > target);
+							assert!(candle.high() >= stop || candle.low() <= target);
+						}
+					}
+				}
+			}
+
+			assert!(exits > 0);
+		}
+	}
+
+	#[test]
+	fn test_stop_target_disabled() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		let stops = StopTarget {
+			indicator: RSI::default(),
+			stop_loss: 0.,
+			stop_target: 0.,
+			..StopTarget::default()
+		};
+		let results = stops.over(&candles).unwrap();
+
+		assert!(results.iter().all(|x| x.signal(1) == Action::None));
+		assert!(results.iter().all(|x| x.value(0).is_nan()));
+
+		let stops = StopTarget {
+			indicator: RSI::default(),
+			stop_signal: 2,
+			..StopTarget::default()
+		};
+		assert!(!stops.validate());
+	}
+}
@@ -0,0 +1,452 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::cmp::Ordering;
+
+use super::{VolumeProfile, ZigZag};
+use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Support and Resistance levels
+///
+/// Clusters the last `count` swing points of [`ZigZag`] into horizontal levels. Swing points are put into the same cluster while they differ
+/// from the lowest point of the cluster by not more than `tolerance` (relative). Level value is the average of the cluster's points,
+/// and level strength is the count of points (touches) in the cluster. Only levels with at least `min_touches` touches are used.
+///
+/// When `volume_period` is not `0`, `POC` of the [`VolumeProfile`] over the last `volume_period` bars is also used as a level point
+/// with the weight of `min_touches` touches.
+///
+/// Nearest support is the strongest enough level below the `close` value, nearest resistance is such level above the `close` value.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/trading/support-and-resistance-basics/>
+///
+/// # 4 values
+///
+/// * `support` level value
+///
+/// When there is no level below the `close` value, equals to the `close` value.
+///
+/// * `resistance` level value
+///
+/// When there is no level above the `close` value, equals to the `close` value.
+///
+/// Range of values is the same as the range of the `high`/`low` values.
+///
+/// * `support` strength
+/// * `resistance` strength
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * Break signal.
+///
+/// When `close` value breaks the previous `resistance` level upwards, returns full buy signal.
+/// When `close` value breaks the previous `support` level downwards, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// * Retest signal.
+///
+/// When `low` value returns to the broken `resistance` level (within `tolerance`) and `close` value stays above it, returns full buy signal.
+/// When `high` value returns to the broken `support` level (within `tolerance`) and `close` value stays below it, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// Broken level waits for the retest until `close` value returns beyond it.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupportResistance {
+	/// [`ZigZag`] relative reversal threshold. Default is `0.05`.
+	///
+	/// Range in \[`0.0`; `1.0`\). When `0.0`, only ATR-based threshold is used.
+	pub deviation: ValueType,
+
+	/// [`ZigZag`] ATR multiplier for reversal threshold. Default is `0.0`.
+	///
+	/// Range in \[`0.0`; `+inf`\). When `0.0`, only relative threshold is used.
+	pub atr_multiplier: ValueType,
+
+	/// [`ZigZag`] ATR period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_period: PeriodType,
+
+	/// Count of the last swing points to cluster. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub count: PeriodType,
+
+	/// Relative size of the cluster. Default is `0.01`.
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub tolerance: ValueType,
+
+	/// Minimum count of touches for the level. Default is `2`.
+	///
+	/// Range in \[`1`; `count`\]
+	pub min_touches: PeriodType,
+
+	/// [`VolumeProfile`] period. Default is `0` (volume is not used).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub volume_period: PeriodType,
+}
+
+impl SupportResistance {
+	const fn zigzag(self) -> ZigZag {
+		ZigZag {
+			deviation: self.deviation,
+			atr_multiplier: self.atr_multiplier,
+			atr_period: self.atr_period,
+		}
+	}
+}
+
+impl IndicatorConfig for SupportResistance {
+	type Instance = SupportResistanceInstance;
+
+	const NAME: &'static str = "SupportResistance";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		let volume_profile = if cfg.volume_period > 0 {
+			let volume_profile = VolumeProfile {
+				period: cfg.volume_period,
+				..VolumeProfile::default()
+			};
+
+			Some(volume_profile.init(candle)?)
+		} else {
+			None
+		};
+
+		Ok(Self::Instance {
+			zigzag: cfg.zigzag().init(candle)?,
+			volume_profile,
+			pivots: Window::new(cfg.count, candle.close()),
+			filled: 0,
+			points: Vec::with_capacity(cfg.count as usize + 1),
+			support: (candle.close(), 0.),
+			resistance: (candle.close(), 0.),
+			broken_resistance: None,
+			broken_support: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.zigzag().validate()
+			&& self.count > 0
+			&& self.count < PeriodType::MAX
+			&& self.tolerance >= 0.
+			&& self.tolerance < 1.
+			&& self.min_touches > 0
+			&& self.min_touches <= self.count
+			&& self.volume_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"deviation" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.deviation = value,
+			},
+			"atr_multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_multiplier = value,
+			},
+			"atr_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_period = value,
+			},
+			"count" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.count = value,
+			},
+			"tolerance" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.tolerance = value,
+			},
+			"min_touches" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.min_touches = value,
+			},
+			"volume_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.volume_period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 2)
+	}
+}
+
+impl Default for SupportResistance {
+	fn default() -> Self {
+		let zigzag = ZigZag::default();
+
+		Self {
+			deviation: zigzag.deviation,
+			atr_multiplier: zigzag.atr_multiplier,
+			atr_period: zigzag.atr_period,
+			count: 10,
+			tolerance: 0.01,
+			min_touches: 2,
+			volume_period: 0,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct SupportResistanceInstance {
+	cfg: SupportResistance,
+
+	zigzag: <ZigZag as IndicatorConfig>::Instance,
+	volume_profile: Option<<VolumeProfile as IndicatorConfig>::Instance>,
+	pivots: Window<ValueType>,
+	filled: PeriodType,
+	/// level points and their weights
+	points: Vec<(ValueType, ValueType)>,
+	/// nearest levels with their strengths
+	support: (ValueType, ValueType),
+	resistance: (ValueType, ValueType),
+	broken_resistance: Option<ValueType>,
+	broken_support: Option<ValueType>,
+}
+
+impl SupportResistanceInstance {
+	/// Returns nearest support and resistance levels with their strengths
+	fn levels(&self, close: ValueType) -> ((ValueType, ValueType), (ValueType, ValueType)) {
+		let min_touches = self.cfg.min_touches as ValueType;
+		let mut support = (close, 0.);
+		let mut resistance = (close, 0.);
+
+		let mut update = |(sum, weight): (ValueType, ValueType)| {
+			if weight < min_touches {
+				return;
+			}
+
+			let level = sum / weight;
+
+			if level <= close && (support.1 == 0. || level > support.0) {
+				support = (level, weight);
+			} else if level > close && (resistance.1 == 0. || level < resistance.0) {
+				resistance = (level, weight);
+			}
+		};
+
+		let mut cluster = (0., 0.);
+		let mut start = ValueType::NAN;
+
+		for &(point, weight) in &self.points {
+			if start.is_nan() || point - start > self.cfg.tolerance * start.abs() {
+				update(cluster);
+				cluster = (0., 0.);
+				start = point;
+			}
+
+			cluster = (weight.mul_add(point, cluster.0), cluster.1 + weight);
+		}
+
+		update(cluster);
+
+		(support, resistance)
+	}
+}
+
+impl IndicatorInstance for SupportResistanceInstance {
+	type Config = SupportResistance;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let (high, low, close) = (candle.high(), candle.low(), candle.close());
+		let tolerance = self.cfg.tolerance;
+
+		let zigzag = self.zigzag.next(candle);
+		if zigzag.signal(0).analog() != 0 {
+			self.pivots.push(zigzag.value(0));
+			self.filled = self.filled.saturating_add(1).min(self.cfg.count);
+		}
+
+		self.points.clear();
+		self.points.extend(
+			self.pivots
+				.iter()
+				.take(self.filled as usize)
+				.map(|x| (x, 1.)),
+		);
+
+		if let Some(volume_profile) = &mut self.volume_profile {
+			let poc = volume_profile.next(candle).value(0);
+			self.points.push((poc, self.cfg.min_touches as ValueType));
+		}
+
+		self.points
+			.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+		let (support, resistance) = (self.support, self.resistance);
+		let breaks = (resistance.1 > 0. && close > resistance.0) as i8
+			- (support.1 > 0. && close < support.0) as i8;
+
+		let mut retest = 0;
+
+		if let Some(level) = self.broken_resistance {
+			if close < level {
+				self.broken_resistance = None;
+			} else if low <= level * (1. + tolerance) {
+				self.broken_resistance = None;
+				retest += 1;
+			}
+		}
+
+		if let Some(level) = self.broken_support {
+			if close > level {
+				self.broken_support = None;
+			} else if high >= level * (1. - tolerance) {
+				self.broken_support = None;
+				retest -= 1;
+			}
+		}
+
+		match breaks {
+			1 => self.broken_resistance = Some(resistance.0),
+			-1 => self.broken_support = Some(support.0),
+			_ => {}
+		}
+
+		let (support, resistance) = self.levels(close);
+		self.support = support;
+		self.resistance = resistance;
+
+		IndicatorResult::new(
+			&[support.0, resistance.0, support.1, resistance.1],
+			&[breaks.into(), retest.into()],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SupportResistance;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(high: ValueType, low: ValueType, close: ValueType) -> Candle {
+		Candle {
+			open: close,
+			high,
+			low,
+			close,
+			volume: 1.,
+		}
+	}
+
+	/// Swings between `100` and `110` twice, so each level is touched twice
+	fn range() -> Vec<Candle> {
+		[105., 110., 104., 100., 106., 110., 104.]
+			.iter()
+			.map(|&price| candle(price, price, price))
+			.collect()
+	}
+
+	#[test]
+	fn test_support_resistance_levels() {
+		let mut instance = SupportResistance::default()
+			.init(&candle(100., 100., 100.))
+			.unwrap();
+
+		let results: Vec<_> = range().iter().map(|c| instance.next(c)).collect();
+
+		// no level has enough touches yet
+		let result = results[3];
+		assert_eq_float(100., result.value(0));
+		assert_eq_float(100., result.value(1));
+		assert_eq_float(0., result.value(2));
+		assert_eq_float(0., result.value(3));
+
+		// second swing low at `100` confirmed
+		let result = results[4];
+		assert_eq_float(100., result.value(0));
+		assert_eq_float(2., result.value(2));
+		assert_eq_float(0., result.value(3));
+
+		// second swing high at `110` confirmed
+		let result = results[6];
+		assert_eq_float(100., result.value(0));
+		assert_eq_float(110., result.value(1));
+		assert_eq_float(2., result.value(2));
+		assert_eq_float(2., result.value(3));
+
+		assert!(results.iter().all(|r| r.signals() == [Action::None; 2]));
+	}
+
+	#[test]
+	fn test_support_resistance_breaks() {
+		let cfg = SupportResistance::default();
+
+		// resistance break and retest
+		let mut instance = cfg.init(&candle(100., 100., 100.)).unwrap();
+		for candle in &range() {
+			instance.next(candle);
+		}
+
+		let result = instance.next(&candle(112., 112., 112.));
+		assert_eq!(result.signal(0), Action::BUY_ALL);
+		assert_eq!(result.signal(1), Action::None);
+		// broken resistance turns into support
+		assert_eq_float(110., result.value(0));
+
+		let result = instance.next(&candle(113., 110.5, 112.));
+		assert_eq!(result.signal(0), Action::None);
+		assert_eq!(result.signal(1), Action::BUY_ALL);
+
+		// retest happens only once
+		let result = instance.next(&candle(113., 110.5, 112.));
+		assert_eq!(result.signal(1), Action::None);
+
+		// support break and retest
+		let mut instance = cfg.init(&candle(100., 100., 100.)).unwrap();
+		for candle in &range() {
+			instance.next(candle);
+		}
+
+		let result = instance.next(&candle(98., 98., 98.));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+		assert_eq!(result.signal(1), Action::None);
+
+		let result = instance.next(&candle(99.5, 97., 98.));
+		assert_eq!(result.signal(0), Action::None);
+		assert_eq!(result.signal(1), Action::SELL_ALL);
+
+		// broken level is not retested after `close` returns beyond it
+		let mut instance = cfg.init(&candle(100., 100., 100.)).unwrap();
+		for candle in &range() {
+			instance.next(candle);
+		}
+
+		assert_eq!(
+			instance.next(&candle(112., 112., 112.)).signal(0),
+			Action::BUY_ALL
+		);
+		instance.next(&candle(112., 108., 108.));
+		let result = instance.next(&candle(113., 110.5, 112.));
+		assert_eq!(result.signal(1), Action::None);
+	}
+}
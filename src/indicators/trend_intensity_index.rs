@@ -0,0 +1,182 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, CrossAbove, CrossUnder};
+
+/// Trend Intensity Index
+///
+/// Share of the positive deviations of the `source` value from its moving average
+/// in the sum of all absolute deviations over the last `period` values.
+///
+/// ## Links
+///
+/// * M.H. Pee, "Trend Intensity Index", Technical Analysis of Stocks & Commodities, 2002
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * When `main` value crosses `0.5` upwards, returns full buy signal.
+///   When `main` value crosses `0.5` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `main` value crosses `1.0` - `zone` upwards, returns full buy signal.
+///   When `main` value crosses `zone` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrendIntensityIndex {
+	/// Moving average period. Default is `60`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub ma_period: PeriodType,
+
+	/// Moving average type. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Deviations summing period. Default is `30`.
+	///
+	/// Usually it is a half of `ma_period`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Strong trend zone. Default is `0.2`.
+	///
+	/// Range in \(`0.0`; `0.5`\)
+	pub zone: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for TrendIntensityIndex {
+	type Instance = TrendIntensityIndexInstance;
+
+	const NAME: &'static str = "TrendIntensityIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.ma_period, src)?,
+			window: Window::new(cfg.period, 0.),
+			positive: 0.,
+			negative: 0.,
+			cross: Cross::default(),
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ma_period > 1
+			&& self.ma_period < PeriodType::MAX
+			&& self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone < 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ma_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.ma_period = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for TrendIntensityIndex {
+	fn default() -> Self {
+		Self {
+			ma_period: 60,
+			method: RegularMethods::SMA,
+			period: 30,
+			zone: 0.2,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct TrendIntensityIndexInstance {
+	cfg: TrendIntensityIndex,
+
+	ma: RegularMethod,
+	window: Window<ValueType>,
+	positive: ValueType,
+	negative: ValueType,
+	cross: Cross,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for TrendIntensityIndexInstance {
+	type Config = TrendIntensityIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let deviation = src - self.ma.next(src);
+
+		let left = self.window.push(deviation);
+		self.positive += deviation.max(0.) - left.max(0.);
+		self.negative += (-deviation).max(0.) - (-left).max(0.);
+
+		let total = self.positive + self.negative;
+		let value = if total > 0. {
+			self.positive / total
+		} else {
+			0.5
+		};
+
+		let signal1 = self.cross.next((value, 0.5));
+		let signal2 = self.cross_above.next((value, 1. - self.cfg.zone))
+			- self.cross_under.next((value, self.cfg.zone));
+
+		IndicatorResult::new(&[value], &[signal1, signal2])
+	}
+}
@@ -0,0 +1,327 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ZigZag;
+use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Automatic Trendlines
+///
+/// Fits two dynamic trendlines through the recent swing points of [`ZigZag`]: the upper line through the last `count` swing highs
+/// and the lower line through the last `count` swing lows. When `count` is `2`, each line goes exactly through two last swing points,
+/// otherwise the line is fitted by the least squares linear regression.
+///
+/// Every confirmed swing point is placed at the bar where it actually occurred (using [`ZigZag`]'s `pivot offset`),
+/// and the lines are extended to the current bar.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/t/trendline.asp>
+///
+/// # 4 values
+///
+/// * `upper` line value at the current bar
+///
+/// When there are not enough swing highs yet, equals to the `close` value.
+///
+/// * `lower` line value at the current bar
+///
+/// When there are not enough swing lows yet, equals to the `close` value.
+///
+/// Range of values is the same as the range of the `high`/`low` values.
+///
+/// * `upper` line slope (change per bar)
+/// * `lower` line slope (change per bar)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// When `close` value crosses the `upper` line upwards, returns full buy signal.
+/// When `close` value crosses the `lower` line downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trendlines {
+	/// [`ZigZag`] relative reversal threshold. Default is `0.05`.
+	///
+	/// Range in \[`0.0`; `1.0`\). When `0.0`, only ATR-based threshold is used.
+	pub deviation: ValueType,
+
+	/// [`ZigZag`] ATR multiplier for reversal threshold. Default is `0.0`.
+	///
+	/// Range in \[`0.0`; `+inf`\). When `0.0`, only relative threshold is used.
+	pub atr_multiplier: ValueType,
+
+	/// [`ZigZag`] ATR period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_period: PeriodType,
+
+	/// Count of swing points to fit each line through. Default is `2`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub count: PeriodType,
+}
+
+impl Trendlines {
+	const fn zigzag(self) -> ZigZag {
+		ZigZag {
+			deviation: self.deviation,
+			atr_multiplier: self.atr_multiplier,
+			atr_period: self.atr_period,
+		}
+	}
+}
+
+impl IndicatorConfig for Trendlines {
+	type Instance = TrendlinesInstance;
+
+	const NAME: &'static str = "Trendlines";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			zigzag: cfg.zigzag().init(candle)?,
+			index: 0,
+			upper: Line::new(cfg.count),
+			lower: Line::new(cfg.count),
+			prev_close: candle.close(),
+			prev_upper: None,
+			prev_lower: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.zigzag().validate() && self.count > 1 && self.count < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"deviation" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.deviation = value,
+			},
+			"atr_multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_multiplier = value,
+			},
+			"atr_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_period = value,
+			},
+			"count" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.count = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for Trendlines {
+	fn default() -> Self {
+		let zigzag = ZigZag::default();
+
+		Self {
+			deviation: zigzag.deviation,
+			atr_multiplier: zigzag.atr_multiplier,
+			atr_period: zigzag.atr_period,
+			count: 2,
+		}
+	}
+}
+
+/// Swing points of a single line: bar index and price
+#[derive(Debug)]
+struct Line {
+	points: Window<(usize, ValueType)>,
+	filled: PeriodType,
+}
+
+impl Line {
+	fn new(count: PeriodType) -> Self {
+		Self {
+			points: Window::new(count, (0, 0.)),
+			filled: 0,
+		}
+	}
+
+	fn push(&mut self, index: usize, price: ValueType) {
+		self.points.push((index, price));
+		self.filled = self.filled.saturating_add(1).min(self.points.len());
+	}
+
+	/// Returns line value and slope at the bar `index`, if there are enough swing points
+	#[allow(clippy::similar_names)]
+	fn at(&self, index: usize) -> Option<(ValueType, ValueType)> {
+		if self.filled < self.points.len() {
+			return None;
+		}
+
+		// bar offsets are relative to the current bar to keep the precision
+		let n = self.points.len() as ValueType;
+		let (s_x, s_y, s_xy, s_x2) =
+			self.points
+				.iter()
+				.fold((0., 0., 0., 0.), |(s_x, s_y, s_xy, s_x2), (i, y)| {
+					let x = -((index - i) as ValueType);
+					(s_x + x, s_y + y, x.mul_add(y, s_xy), x.mul_add(x, s_x2))
+				});
+
+		let divider = n.mul_add(s_x2, -s_x * s_x);
+		if divider == 0. {
+			return None;
+		}
+
+		let slope = n.mul_add(s_xy, -s_x * s_y) / divider;
+		let value = slope.mul_add(-s_x, s_y) / n;
+
+		Some((value, slope))
+	}
+}
+
+#[derive(Debug)]
+pub struct TrendlinesInstance {
+	cfg: Trendlines,
+
+	zigzag: <ZigZag as IndicatorConfig>::Instance,
+	index: usize,
+	upper: Line,
+	lower: Line,
+	prev_close: ValueType,
+	prev_upper: Option<ValueType>,
+	prev_lower: Option<ValueType>,
+}
+
+impl IndicatorInstance for TrendlinesInstance {
+	type Config = Trendlines;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let close = candle.close();
+		self.index += 1;
+
+		let zigzag = self.zigzag.next(candle);
+		let pivot_index = self.index - zigzag.value(1) as usize;
+		match zigzag.signal(0).analog() {
+			-1 => self.upper.push(pivot_index, zigzag.value(0)),
+			1 => self.lower.push(pivot_index, zigzag.value(0)),
+			_ => {}
+		}
+
+		let upper = self.upper.at(self.index);
+		let lower = self.lower.at(self.index);
+
+		let breaks_up = matches!((upper, self.prev_upper), (Some((line, _)), Some(prev_line)) if close > line && self.prev_close <= prev_line);
+		let breaks_down = matches!((lower, self.prev_lower), (Some((line, _)), Some(prev_line)) if close < line && self.prev_close >= prev_line);
+		let signal = breaks_up as i8 - breaks_down as i8;
+
+		self.prev_close = close;
+		self.prev_upper = upper.map(|(line, _)| line);
+		self.prev_lower = lower.map(|(line, _)| line);
+
+		let (upper, upper_slope) = upper.unwrap_or((close, 0.));
+		let (lower, lower_slope) = lower.unwrap_or((close, 0.));
+
+		IndicatorResult::new(&[upper, lower, upper_slope, lower_slope], &[signal.into()])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Trendlines;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(price: ValueType) -> Candle {
+		Candle {
+			open: price,
+			high: price,
+			low: price,
+			close: price,
+			volume: 1.,
+		}
+	}
+
+	/// Swing highs at `110`, `108` and `107.5`, swing lows at `100`, `100` and `102`
+	const PRICES: [ValueType; 10] = [100., 110., 104., 100., 106., 108., 102., 105., 107.5, 96.];
+
+	#[test]
+	fn test_trendlines() {
+		let mut instance = Trendlines::default().init(&candle(100.)).unwrap();
+		let results: Vec<_> = PRICES.iter().map(|&p| instance.next(&candle(p))).collect();
+
+		// only one swing high is confirmed
+		assert_eq_float(108., results[5].value(0));
+		assert_eq_float(0., results[5].value(2));
+
+		// line through swing highs at bars 2 and 6 is extended to bar 7
+		assert_eq_float(107.5, results[6].value(0));
+		assert_eq_float(-0.5, results[6].value(2));
+		assert_eq_float(100., results[6].value(1));
+		assert_eq_float(0., results[6].value(3));
+
+		assert_eq_float(107., results[7].value(0));
+
+		// `close` crosses the upper line upwards
+		assert_eq!(results[8].signal(0), Action::BUY_ALL);
+		assert_eq_float(106.5, results[8].value(0));
+		// swing low at bar 7 moves the lower line
+		assert_eq_float(2. / 3., results[8].value(3));
+		assert_eq_float(100. + 5. * 2. / 3., results[8].value(1));
+
+		// `close` crosses the lower line downwards
+		assert_eq!(results[9].signal(0), Action::SELL_ALL);
+		assert_eq_float(-1. / 6., results[9].value(2));
+		assert_eq_float(104., results[9].value(1));
+
+		assert!(results[..8].iter().all(|r| r.signal(0) == Action::None));
+	}
+
+	#[test]
+	fn test_trendlines_regression() {
+		let cfg = Trendlines {
+			count: 3,
+			..Trendlines::default()
+		};
+		let mut instance = cfg.init(&candle(100.)).unwrap();
+		let results: Vec<_> = PRICES.iter().map(|&p| instance.next(&candle(p))).collect();
+
+		// not enough swing points yet
+		assert_eq_float(105., results[7].value(0));
+		assert_eq_float(105., results[7].value(1));
+
+		// least squares line through swing lows at bars 1, 4 and 7
+		assert_eq_float(1. / 3., results[8].value(3));
+		assert_eq_float(100. + 7. / 3., results[8].value(1));
+		// upper line still has two swing highs only
+		assert_eq_float(107.5, results[8].value(0));
+		assert_eq!(results[8].signal(0), Action::None);
+
+		// least squares line through swing highs at bars 2, 6 and 9
+		let slope = -27. / 74.;
+		assert_eq_float(slope, results[9].value(2));
+		assert_eq_float(slope.mul_add(10. - 17. / 3., 108.5), results[9].value(0));
+		assert_eq!(results[9].signal(0), Action::SELL_ALL);
+	}
+}
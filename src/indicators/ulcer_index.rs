@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Cross, Highest, SMA};
+
+/// Ulcer Index
+///
+/// Root mean square of relative drawdowns from the rolling highest value over the last `period` bars.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Ulcer_index>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:ulcer_index>
+///
+/// # 2 values
+///
+/// * `Ulcer Index` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * Current relative drawdown from the rolling highest value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// When `Ulcer Index` crosses `threshold` upwards, returns full sell signal.
+/// When `Ulcer Index` crosses `threshold` downwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UlcerIndex {
+	/// Main period. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Signal threshold. Default is `0.05`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub threshold: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for UlcerIndex {
+	type Instance = UlcerIndexInstance;
+
+	const NAME: &'static str = "UlcerIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, src)?,
+			sma: SMA::new(cfg.period, 0.)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.threshold > 0.
+			&& self.threshold < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for UlcerIndex {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			threshold: 0.05,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct UlcerIndexInstance {
+	cfg: UlcerIndex,
+
+	highest: Highest,
+	sma: SMA,
+	cross: Cross,
+}
+
+impl IndicatorInstance for UlcerIndexInstance {
+	type Config = UlcerIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let highest = self.highest.next(src);
+
+		let drawdown = if highest > 0. {
+			(highest - src) / highest
+		} else {
+			0.
+		};
+
+		// sometimes float values may produce negative values, when mean is really near to zero value
+		let value = self.sma.next(drawdown * drawdown).max(0.).sqrt();
+
+		let signal = -self.cross.next((value, self.cfg.threshold));
+
+		IndicatorResult::new(&[value, drawdown], &[signal])
+	}
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Cross, Highest, Lowest};
+
+/// Vertical Horizontal Filter
+///
+/// (highest `source` - lowest `source`) / sum of absolute `source` changes over the last `period` values.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/v/vertical_horizontal_filter.asp>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// When `main` value crosses `threshold` upwards (market starts trending), returns full buy signal.
+/// When `main` value crosses `threshold` downwards (market becomes congested), returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerticalHorizontalFilter {
+	/// Main period. Default is `28`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Trending market threshold. Default is `0.35`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub threshold: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for VerticalHorizontalFilter {
+	type Instance = VerticalHorizontalFilterInstance;
+
+	const NAME: &'static str = "VerticalHorizontalFilter";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, src)?,
+			lowest: Lowest::new(cfg.period, src)?,
+			window: Window::new(cfg.period, 0.),
+			prev_value: src,
+			sum: 0.,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.threshold > 0.
+			&& self.threshold < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for VerticalHorizontalFilter {
+	fn default() -> Self {
+		Self {
+			period: 28,
+			threshold: 0.35,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct VerticalHorizontalFilterInstance {
+	cfg: VerticalHorizontalFilter,
+
+	highest: Highest,
+	lowest: Lowest,
+	window: Window<ValueType>,
+	prev_value: ValueType,
+	sum: ValueType,
+	cross: Cross,
+}
+
+impl IndicatorInstance for VerticalHorizontalFilterInstance {
+	type Config = VerticalHorizontalFilter;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let highest = self.highest.next(src);
+		let lowest = self.lowest.next(src);
+
+		let change = (src - self.prev_value).abs();
+		self.prev_value = src;
+
+		let left = self.window.push(change);
+		self.sum += change - left;
+
+		let value = if self.sum > 0. {
+			((highest - lowest) / self.sum).min(1.)
+		} else {
+			0.
+		};
+
+		let signal = self.cross.next((value, self.cfg.threshold));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
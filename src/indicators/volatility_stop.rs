@@ -0,0 +1,177 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Volatility Stop (ATR trailing stop)
+///
+/// In uptrend `stop` trails the highest `source` value since the trend start by `multiplier` * `ATR` and can only move up.
+/// In downtrend `stop` trails the lowest `source` value since the trend start by `multiplier` * `ATR` and can only move down.
+/// When `source` value crosses `stop`, the direction flips.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000594675-volatility-stop/>
+///
+/// # 2 values
+///
+/// * `stop` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `direction`
+///
+/// `1.0` for uptrend, `-1.0` for downtrend.
+///
+/// # 1 signal
+///
+/// When `source` value crosses `stop` upwards, returns full buy signal.
+/// When `source` value crosses `stop` downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolatilityStop {
+	/// ATR period. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// ATR multiplier. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub multiplier: ValueType,
+
+	/// ATR method. Default is [`RMA`](crate::methods::RMA).
+	pub method: RegularMethods,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolatilityStop {
+	type Instance = VolatilityStopInstance;
+
+	const NAME: &'static str = "VolatilityStop";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let tr = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			prev_close: candle.close(),
+			atr: method(cfg.method, cfg.period, tr)?,
+			extreme: src,
+			stop: cfg.multiplier.mul_add(-tr, src),
+			direction: 1,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.multiplier > 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.multiplier = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for VolatilityStop {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			multiplier: 2.0,
+			method: RegularMethods::RMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct VolatilityStopInstance {
+	cfg: VolatilityStop,
+
+	prev_close: ValueType,
+	atr: RegularMethod,
+	extreme: ValueType,
+	stop: ValueType,
+	direction: i8,
+}
+
+impl IndicatorInstance for VolatilityStopInstance {
+	type Config = VolatilityStop;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+
+		let atr = self.atr.next(tr) * self.cfg.multiplier;
+		let src = candle.source(self.cfg.source);
+
+		let prev_direction = self.direction;
+
+		if self.direction > 0 {
+			self.extreme = self.extreme.max(src);
+			self.stop = self.stop.max(self.extreme - atr);
+
+			if src < self.stop {
+				self.direction = -1;
+			}
+		} else {
+			self.extreme = self.extreme.min(src);
+			self.stop = self.stop.min(self.extreme + atr);
+
+			if src > self.stop {
+				self.direction = 1;
+			}
+		}
+
+		// on direction flip the stop starts trailing from the current value
+		if self.direction != prev_direction {
+			self.extreme = src;
+			self.stop = (self.direction as ValueType).mul_add(-atr, src);
+		}
+
+		let signal = (self.direction != prev_direction) as i8 * self.direction;
+
+		IndicatorResult::new(&[self.stop, self.direction as ValueType], &[signal.into()])
+	}
+}
@@ -0,0 +1,62 @@
+use crate::core::{Error, IndicatorResult, Method, PeriodType, ValueType};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+
+const INITIAL_VALUE: ValueType = 1000.;
+
+/// Direction of `volume` change, which makes volume index follow the `source` value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeDirection {
+	Increase,
+	Decrease,
+}
+
+/// Common part of [`PositiveVolumeIndex`](crate::indicators::PositiveVolumeIndex)
+/// and [`NegativeVolumeIndex`](crate::indicators::NegativeVolumeIndex)
+#[derive(Debug)]
+pub struct VolumeIndex {
+	direction: VolumeDirection,
+	prev_value: ValueType,
+	prev_volume: ValueType,
+	index: ValueType,
+	ma: RegularMethod,
+	cross: Cross,
+}
+
+impl VolumeIndex {
+	pub fn new(
+		direction: VolumeDirection,
+		signal_period: PeriodType,
+		signal_method: RegularMethods,
+		value: ValueType,
+		volume: ValueType,
+	) -> Result<Self, Error> {
+		Ok(Self {
+			direction,
+			prev_value: value,
+			prev_volume: volume,
+			index: INITIAL_VALUE,
+			ma: method(signal_method, signal_period, INITIAL_VALUE)?,
+			cross: Cross::default(),
+		})
+	}
+
+	pub fn next(&mut self, value: ValueType, volume: ValueType) -> IndicatorResult {
+		let is_changed = match self.direction {
+			VolumeDirection::Increase => volume > self.prev_volume,
+			VolumeDirection::Decrease => volume < self.prev_volume,
+		};
+
+		if is_changed && self.prev_value != 0. {
+			self.index *= value / self.prev_value;
+		}
+
+		self.prev_value = value;
+		self.prev_volume = volume;
+
+		let signal_line = self.ma.next(self.index);
+		let signal = self.cross.next((self.index, signal_line));
+
+		IndicatorResult::new(&[self.index, signal_line], &[signal])
+	}
+}
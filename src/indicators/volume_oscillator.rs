@@ -0,0 +1,167 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+
+/// Volume Oscillator
+///
+/// Difference between fast and slow moving averages of `volume`.
+/// When `relative` is `true`, the difference is divided by the slow moving average value.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000591350-volume-oscillator/>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \(`-inf`; `+inf`\) or in \[`-1.0`; `+inf`\) when `relative` is `true`
+///
+/// # 2 signals
+///
+/// * When `main` value crosses `0.0` upwards, returns full buy signal.
+///   When `main` value crosses `0.0` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `main` value crosses `threshold` upwards, returns full buy signal.
+///   When `main` value crosses `threshold` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeOscillator {
+	/// Fast MA period. Default is `5`.
+	///
+	/// Range in \[`1`; `period2`\)
+	pub period1: PeriodType,
+
+	/// Slow MA period. Default is `10`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// MA type. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Divide the difference by the slow MA value. Default is `true`.
+	pub relative: bool,
+
+	/// Signal threshold. Default is `0.1`.
+	///
+	/// Range in \(`-inf`; `+inf`\)
+	pub threshold: ValueType,
+}
+
+impl IndicatorConfig for VolumeOscillator {
+	type Instance = VolumeOscillatorInstance;
+
+	const NAME: &'static str = "VolumeOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let volume = candle.volume();
+
+		Ok(Self::Instance {
+			ma1: method(cfg.method, cfg.period1, volume)?,
+			ma2: method(cfg.method, cfg.period2, volume)?,
+			cross1: Cross::default(),
+			cross2: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period1 > 0 && self.period1 < self.period2 && self.period2 < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period2 = value,
+			},
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.method = value,
+			},
+			"relative" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.relative = value,
+			},
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Default for VolumeOscillator {
+	fn default() -> Self {
+		Self {
+			period1: 5,
+			period2: 10,
+			method: RegularMethods::EMA,
+			relative: true,
+			threshold: 0.1,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct VolumeOscillatorInstance {
+	cfg: VolumeOscillator,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	cross1: Cross,
+	cross2: Cross,
+}
+
+impl IndicatorInstance for VolumeOscillatorInstance {
+	type Config = VolumeOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let volume = candle.volume();
+
+		let fast = self.ma1.next(volume);
+		let slow = self.ma2.next(volume);
+
+		let value = if !self.cfg.relative {
+			fast - slow
+		} else if slow > 0. {
+			(fast - slow) / slow
+		} else {
+			0.
+		};
+
+		let signal1 = self.cross1.next((value, 0.));
+		let signal2 = self.cross2.next((value, self.cfg.threshold));
+
+		IndicatorResult::new(&[value], &[signal1, signal2])
+	}
+}
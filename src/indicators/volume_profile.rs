@@ -0,0 +1,234 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Cross, CrossAbove, CrossUnder};
+
+/// Rolling Volume Profile
+///
+/// Price range of the last `period` bars is split into `bins` equal price levels.
+/// Volume of every bar is distributed over the price levels between its `low` and `high` values proportionally.
+///
+/// * `POC` (point of control) is the middle of the price level with the biggest volume.
+/// * Value area is the smallest range of price levels around `POC` which contains at least `value_area` part of the total volume.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000502040-volume-profile/>
+///
+/// # 3 values
+///
+/// * `POC` value
+/// * `value area high` value
+/// * `value area low` value
+///
+/// Range of values is the same as the range of the `high`/`low` values.
+///
+/// # 2 signals
+///
+/// * When `source` value crosses `value area high` upwards, returns full buy signal.
+///   When `source` value crosses `value area low` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `source` value crosses `POC` upwards, returns full buy signal.
+///   When `source` value crosses `POC` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeProfile {
+	/// Count of bars in the profile. Default is `50`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Count of price levels. Default is `24`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\]
+	pub bins: PeriodType,
+
+	/// Relative part of the total volume inside the value area. Default is `0.7`.
+	///
+	/// Range in \(`0.0`; `1.0`\]
+	pub value_area: ValueType,
+
+	/// Source value type for signals. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolumeProfile {
+	type Instance = VolumeProfileInstance;
+
+	const NAME: &'static str = "VolumeProfile";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			window: Window::new(cfg.period, (candle.low(), candle.high(), 0.)),
+			profile: vec![0.; cfg.bins as usize],
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cross_poc: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.bins > 0
+			&& self.value_area > 0.
+			&& self.value_area <= 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.period = value,
+			},
+			"bins" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.bins = value,
+			},
+			"value_area" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.value_area = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 2)
+	}
+}
+
+impl Default for VolumeProfile {
+	fn default() -> Self {
+		Self {
+			period: 50,
+			bins: 24,
+			value_area: 0.7,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct VolumeProfileInstance {
+	cfg: VolumeProfile,
+
+	/// `low`, `high` and `volume` values of the bars
+	window: Window<(ValueType, ValueType, ValueType)>,
+	profile: Vec<ValueType>,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+	cross_poc: Cross,
+}
+
+impl VolumeProfileInstance {
+	/// Fills the profile and returns lowest price and price level size
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		clippy::suboptimal_flops
+	)]
+	fn fill_profile(&mut self) -> (ValueType, ValueType) {
+		let (lowest, highest) = self.window.iter().fold(
+			(ValueType::INFINITY, ValueType::NEG_INFINITY),
+			|(lo, hi), (low, high, _)| (lo.min(low), hi.max(high)),
+		);
+
+		let last_bin = self.profile.len() - 1;
+		let step = (highest - lowest) / self.profile.len() as ValueType;
+		let bin_of = |price: ValueType| {
+			if step > 0. {
+				(((price - lowest) / step) as usize).min(last_bin)
+			} else {
+				0
+			}
+		};
+
+		self.profile.iter_mut().for_each(|v| *v = 0.);
+
+		for (low, high, volume) in &self.window {
+			let (first, last) = (bin_of(low), bin_of(high));
+
+			if first == last {
+				self.profile[first] += volume;
+				continue;
+			}
+
+			let range = high - low;
+			for (i, v) in self.profile[first..=last].iter_mut().enumerate() {
+				let bin_low = lowest + (first + i) as ValueType * step;
+				let overlap = high.min(bin_low + step) - low.max(bin_low);
+				*v += volume * overlap / range;
+			}
+		}
+
+		(lowest, step)
+	}
+}
+
+impl IndicatorInstance for VolumeProfileInstance {
+	type Config = VolumeProfile;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[allow(clippy::suboptimal_flops)]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.window
+			.push((candle.low(), candle.high(), candle.volume()));
+
+		let (lowest, step) = self.fill_profile();
+		let profile = &self.profile;
+
+		let poc =
+			(1..profile.len()).fold(0, |max, i| if profile[i] > profile[max] { i } else { max });
+
+		// value area grows from POC to the side with bigger volume
+		let target = profile.iter().sum::<ValueType>() * self.cfg.value_area;
+		let (mut lower, mut upper) = (poc, poc);
+		let mut volume = profile[poc];
+
+		while volume < target && (lower > 0 || upper < profile.len() - 1) {
+			let below = if lower > 0 { profile[lower - 1] } else { -1. };
+			let above = profile.get(upper + 1).copied().unwrap_or(-1.);
+
+			if above >= below {
+				upper += 1;
+				volume += above;
+			} else {
+				lower -= 1;
+				volume += below;
+			}
+		}
+
+		let poc_value = lowest + (poc as ValueType + 0.5) * step;
+		let vah = lowest + (upper + 1) as ValueType * step;
+		let val = lowest + lower as ValueType * step;
+
+		let src = candle.source(self.cfg.source);
+		let signal1 = self.cross_above.next((src, vah)) - self.cross_under.next((src, val));
+		let signal2 = self.cross_poc.next((src, poc_value));
+
+		IndicatorResult::new(&[poc_value, vah, val], &[signal1, signal2])
+	}
+}
@@ -0,0 +1,415 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use crate::core::{Action, Error, IndicatorInstances, Indicators, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+
+/// Voting logic of the [`Voting`] indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum VotingLogic {
+	/// Returns signal only when every indicator votes for the same direction.
+	Unanimous,
+
+	/// Returns signal when more than a half of the indicators vote for the same direction.
+	Majority,
+
+	/// Returns weighted score of the votes, when its absolute value reaches `threshold`.
+	Weighted,
+
+	/// Returns signal when at least one indicator votes for some direction and no indicator votes against it.
+	Veto,
+}
+
+impl FromStr for VotingLogic {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"unanimous" => Ok(Self::Unanimous),
+			"majority" => Ok(Self::Majority),
+			"weighted" => Ok(Self::Weighted),
+			"veto" => Ok(Self::Veto),
+
+			value => Err(Error::ParameterParse(
+				"logic".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Voting of several indicators
+///
+/// Merges signals of several `indicators` (a tuple of up to 8 indicators configurations) into a single signal
+/// with the configured voting [`logic`](VotingLogic).
+///
+/// Every indicator votes by one of its signals (selected by `signals`) with some weight (selected by `weights`).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{CommodityChannelIndex, StochasticOscillator, Voting, VotingLogic, RSI};
+///
+/// // buy or sell when at least two of three oscillators agree
+/// let voting = Voting {
+///     indicators: (RSI::default(), CommodityChannelIndex::default(), StochasticOscillator::default()),
+///     logic: VotingLogic::Majority,
+///     ..Voting::default()
+/// };
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = voting.over(&candles).unwrap();
+/// ```
+///
+/// # 1 value
+///
+/// * Weighted score of the votes
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// Combined signal according to the voting `logic`.
+///
+/// For [`Weighted`](VotingLogic::Weighted) logic returns weighted score as signal value,
+/// for the other kinds of logic returns full buy or full sell signal.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Voting<I> {
+	/// Voting indicators.
+	pub indicators: I,
+
+	/// Voting logic. Default is [`Majority`](VotingLogic::Majority).
+	pub logic: VotingLogic,
+
+	/// Indexes of the signals every indicator votes with. Default is empty (every indicator votes with its first signal).
+	///
+	/// When not empty, length must be equal to the count of the indicators.
+	/// Every index must be less than the count of signals of the corresponding indicator.
+	pub signals: Vec<u8>,
+
+	/// Weights of the indicators votes. Default is empty (every vote has weight `1.0`).
+	///
+	/// When not empty, length must be equal to the count of the indicators. Every weight must be non-negative.
+	pub weights: Vec<ValueType>,
+
+	/// Minimum absolute value of the weighted score for [`Weighted`](VotingLogic::Weighted) logic. Default is `0.5`.
+	///
+	/// Range in \[`0.0`; `1.0`\]
+	pub threshold: ValueType,
+}
+
+fn parse_list<T: FromStr>(name: &str, value: &str) -> Result<Vec<T>, Error> {
+	value
+		.split(',')
+		.map(str::trim)
+		.filter(|item| !item.is_empty())
+		.map(|item| {
+			item.parse()
+				.map_err(|_| Error::ParameterParse(name.to_string(), value.to_string()))
+		})
+		.collect()
+}
+
+impl<I: Indicators> IndicatorConfig for Voting<I> {
+	type Instance = VotingInstance<I>;
+
+	const NAME: &'static str = "Voting";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		let signals = if cfg.signals.is_empty() {
+			vec![0; I::COUNT]
+		} else {
+			cfg.signals.iter().map(|&x| x as usize).collect()
+		};
+
+		let weights = if cfg.weights.is_empty() {
+			vec![1.; I::COUNT]
+		} else {
+			cfg.weights.clone()
+		};
+
+		Ok(Self::Instance {
+			instances: cfg.indicators.clone().init(candle)?,
+			results: vec![IndicatorResult::new(&[], &[]); I::COUNT],
+			total_weight: weights.iter().sum(),
+			signals,
+			weights,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.indicators.validate()
+			&& (self.signals.is_empty() || self.signals.len() == I::COUNT)
+			&& self
+				.indicators
+				.sizes()
+				.iter()
+				.enumerate()
+				.all(|(i, &(_, signals))| self.signals.get(i).copied().unwrap_or(0) < signals)
+			&& (self.weights.is_empty() || self.weights.len() == I::COUNT)
+			&& self.weights.iter().all(|&weight| weight >= 0.)
+			&& (self.weights.is_empty() || self.weights.iter().any(|&weight| weight > 0.))
+			&& self.threshold >= 0.
+			&& self.threshold <= 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"logic" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.logic = value,
+			},
+			"signals" => self.signals = parse_list(name, &value)?,
+			"weights" => self.weights = parse_list(name, &value)?,
+			"threshold" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.threshold = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl<I: Default> Default for Voting<I> {
+	fn default() -> Self {
+		Self {
+			indicators: I::default(),
+			logic: VotingLogic::Majority,
+			signals: Vec::new(),
+			weights: Vec::new(),
+			threshold: 0.5,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct VotingInstance<I: Indicators> {
+	cfg: Voting<I>,
+
+	instances: I::Instances,
+	results: Vec<IndicatorResult>,
+	signals: Vec<usize>,
+	weights: Vec<ValueType>,
+	total_weight: ValueType,
+}
+
+impl<I: Indicators> IndicatorInstance for VotingInstance<I> {
+	type Config = Voting<I>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.instances.next(candle, &mut self.results);
+
+		let mut buys = 0.;
+		let mut sells = 0.;
+		let mut score = 0.;
+
+		for ((result, &signal), &weight) in
+			self.results.iter().zip(&self.signals).zip(&self.weights)
+		{
+			let vote = result.signal(signal);
+
+			match vote.analog() {
+				1 => buys += weight,
+				-1 => sells += weight,
+				_ => {}
+			}
+
+			score = vote.ratio().unwrap_or(0.).mul_add(weight, score);
+		}
+
+		let total = self.total_weight;
+		let score = score / total;
+
+		let signal = match self.cfg.logic {
+			VotingLogic::Unanimous => ((buys >= total) as i8 - (sells >= total) as i8).into(),
+			VotingLogic::Majority => {
+				((buys * 2. > total) as i8 - (sells * 2. > total) as i8).into()
+			}
+			VotingLogic::Veto => {
+				((buys > 0. && sells == 0.) as i8 - (sells > 0. && buys == 0.) as i8).into()
+			}
+			VotingLogic::Weighted if score != 0. && score.abs() >= self.cfg.threshold => {
+				score.into()
+			}
+			VotingLogic::Weighted => Action::None,
+		};
+
+		IndicatorResult::new(&[score], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Voting, VotingLogic};
+	use crate::core::{Action, IndicatorConfig, IndicatorResult, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{CommodityChannelIndex, HistoricalVolatility, Trix, RSI};
+
+	fn votes(results: &[Vec<IndicatorResult>], i: usize) -> Vec<Action> {
+		results.iter().map(|x| x[i].signal(0)).collect()
+	}
+
+	#[test]
+	fn test_voting_same_indicators() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let rsi = RSI::default().over(&candles).unwrap();
+
+		for &logic in &[
+			VotingLogic::Unanimous,
+			VotingLogic::Majority,
+			VotingLogic::Weighted,
+			VotingLogic::Veto,
+		] {
+			let voting = Voting {
+				indicators: (RSI::default(), RSI::default(), RSI::default()),
+				logic,
+				weights: vec![1., 2., 3.],
+				..Voting::default()
+			};
+
+			let results = voting.over(&candles).unwrap();
+
+			for (voted, original) in results.iter().zip(&rsi) {
+				assert_eq!(voted.signal(0), original.signal(0));
+			}
+		}
+	}
+
+	#[test]
+	fn test_voting_veto() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let rsi = RSI::default().over(&candles).unwrap();
+		let trix = Trix::default().over(&candles).unwrap();
+
+		let voting = Voting {
+			indicators: (RSI::default(), Trix::default()),
+			logic: VotingLogic::Veto,
+			..Voting::default()
+		};
+
+		let results = voting.over(&candles).unwrap();
+
+		for ((voted, rsi), trix) in results.iter().zip(&rsi).zip(&trix) {
+			let (a, b) = (rsi.signal(0).analog(), trix.signal(0).analog());
+			let expected = if a * b < 0 { 0 } else { (a + b).signum() };
+
+			assert_eq!(voted.signal(0).analog(), expected);
+		}
+	}
+
+	#[test]
+	fn test_voting_wrong_config() {
+		let voting = Voting {
+			indicators: (RSI::default(), Trix::default()),
+			weights: vec![1.],
+			..Voting::default()
+		};
+
+		assert!(!voting.validate());
+	}
+
+	#[test]
+	fn test_voting_wrong_signals() {
+		let mut voting = Voting {
+			indicators: (RSI::default(), CommodityChannelIndex::default()),
+			..Voting::default()
+		};
+		assert!(voting.validate());
+
+		voting.set("signals", "0,5".to_string()).unwrap();
+		assert!(!voting.validate());
+		assert!(voting.init(&RandomCandles::new().first()).is_err());
+
+		// indicator without signals cannot vote by default
+		let voting = Voting {
+			indicators: (RSI::default(), HistoricalVolatility::default()),
+			..Voting::default()
+		};
+		assert!(!voting.validate());
+	}
+
+	#[test]
+	fn test_voting_disagreement() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let results = vec![
+			RSI::default().over(&candles).unwrap(),
+			Trix::default().over(&candles).unwrap(),
+			CommodityChannelIndex::default().over(&candles).unwrap(),
+		];
+		let weights: [ValueType; 3] = [1., 2., 3.];
+
+		let voting = |logic| Voting {
+			indicators: (
+				RSI::default(),
+				Trix::default(),
+				CommodityChannelIndex::default(),
+			),
+			logic,
+			weights: weights.to_vec(),
+			threshold: 0.3,
+			..Voting::default()
+		};
+
+		let majority = voting(VotingLogic::Majority).over(&candles).unwrap();
+		let weighted = voting(VotingLogic::Weighted).over(&candles).unwrap();
+
+		let mut disagreements = 0;
+		for i in 0..candles.len() {
+			let votes = votes(&results, i);
+
+			let buys: ValueType = votes
+				.iter()
+				.zip(&weights)
+				.filter(|(vote, _)| vote.analog() > 0)
+				.map(|(_, &weight)| weight)
+				.sum();
+			let sells: ValueType = votes
+				.iter()
+				.zip(&weights)
+				.filter(|(vote, _)| vote.analog() < 0)
+				.map(|(_, &weight)| weight)
+				.sum();
+			let expected = (buys > 3.) as i8 - (sells > 3.) as i8;
+			assert_eq!(majority[i].signal(0).analog(), expected);
+
+			let score = (buys - sells) / 6.;
+			let expected = if score.abs() >= 0.3 {
+				score.into()
+			} else {
+				Action::None
+			};
+			assert_eq!(weighted[i].signal(0), expected);
+
+			disagreements += (buys > 0. && sells > 0.) as usize;
+		}
+
+		assert!(disagreements > 0);
+	}
+}
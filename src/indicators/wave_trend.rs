@@ -0,0 +1,199 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, EMA};
+
+// `0.015` from the original formula multiplied by `100` to get values in the range around \[`-1.0`; `1.0`\]
+const SCALE: ValueType = 1.5;
+
+/// `WaveTrend` Oscillator
+///
+/// `esa` = `EMA`(`source`, `channel_period`)
+///
+/// `ci` = (`source` - `esa`) / (`1.5` * `EMA`(|`source` - `esa`|, `channel_period`))
+///
+/// `WT1` = `EMA`(`ci`, `average_period`)
+///
+/// `WT2` = `MA`(`WT1`, `signal_period`)
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/script/2KE8wTuF-Indicator-WaveTrend-Oscillator-WT/>
+///
+/// # 2 values
+///
+/// * `WT1` value
+///
+/// Range in \(`-inf`; `+inf`\), mostly in \[`-1.0`; `1.0`\]
+///
+/// * `WT2` value
+///
+/// Range in \(`-inf`; `+inf`\), mostly in \[`-1.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * When `WT1` crosses `WT2` upwards, returns full buy signal.
+///   When `WT1` crosses `WT2` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `WT1` crosses `WT2` upwards below -`zone`, returns full buy signal.
+///   When `WT1` crosses `WT2` downwards above `zone`, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WaveTrend {
+	/// Channel period. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub channel_period: PeriodType,
+
+	/// Average period. Default is `21`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub average_period: PeriodType,
+
+	/// `WT2` period. Default is `4`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+
+	/// `WT2` method. Default is [`SMA`](crate::methods::SMA).
+	pub signal_method: RegularMethods,
+
+	/// Overbought/oversold zone. Default is `0.6`.
+	///
+	/// Range in \[`0.0`; `+inf`\)
+	pub zone: ValueType,
+
+	/// Source value type. Default is [`TP`](crate::core::Source::TP).
+	pub source: Source,
+}
+
+impl IndicatorConfig for WaveTrend {
+	type Instance = WaveTrendInstance;
+
+	const NAME: &'static str = "WaveTrend";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			esa: EMA::new(cfg.channel_period, src)?,
+			d: EMA::new(cfg.channel_period, 0.)?,
+			tci: EMA::new(cfg.average_period, 0.)?,
+			ma: method(cfg.signal_method, cfg.signal_period, 0.)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.channel_period > 1
+			&& self.channel_period < PeriodType::MAX
+			&& self.average_period > 1
+			&& self.average_period < PeriodType::MAX
+			&& self.signal_period > 1
+			&& self.signal_period < PeriodType::MAX
+			&& self.zone >= 0.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"channel_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.channel_period = value,
+			},
+			"average_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.average_period = value,
+			},
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_period = value,
+			},
+			"signal_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_method = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for WaveTrend {
+	fn default() -> Self {
+		Self {
+			channel_period: 10,
+			average_period: 21,
+			signal_period: 4,
+			signal_method: RegularMethods::SMA,
+			zone: 0.6,
+			source: Source::TP,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct WaveTrendInstance {
+	cfg: WaveTrend,
+
+	esa: EMA,
+	d: EMA,
+	tci: EMA,
+	ma: RegularMethod,
+	cross: Cross,
+}
+
+impl IndicatorInstance for WaveTrendInstance {
+	type Config = WaveTrend;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let esa = self.esa.next(src);
+		let d = self.d.next((src - esa).abs());
+		let ci = if d > 0. {
+			(src - esa) / (SCALE * d)
+		} else {
+			0.
+		};
+
+		let wt1 = self.tci.next(ci);
+		let wt2 = self.ma.next(wt1);
+
+		let signal1 = self.cross.next((wt1, wt2));
+		let cross = signal1.analog();
+		let signal2 =
+			(cross > 0 && wt1 < -self.cfg.zone) as i8 - (cross < 0 && wt1 > self.cfg.zone) as i8;
+
+		IndicatorResult::new(&[wt1, wt2], &[signal1, signal2.into()])
+	}
+}
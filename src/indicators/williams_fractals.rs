@@ -0,0 +1,176 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{CrossAbove, CrossUnder};
+
+/// Bill Williams' Fractals
+///
+/// Up fractal is a bar which `high` value is strictly greater than `high` values of `wing` bars on each side.
+/// Down fractal is a bar which `low` value is strictly lower than `low` values of `wing` bars on each side.
+///
+/// Fractal can be confirmed only after `wing` next bars are known, so every fractal is reported with a delay of `wing` bars.
+/// While less than 2 * `wing` + 1 bars has been passed, no fractals can be confirmed.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/f/fractal.asp>
+/// * Bill Williams, "Trading Chaos", 1995
+///
+/// # 2 values
+///
+/// * last confirmed `up fractal` level
+///
+/// Range of values is the same as the range of the `high` values.
+///
+/// * last confirmed `down fractal` level
+///
+/// Range of values is the same as the range of the `low` values.
+///
+/// # 2 signals
+///
+/// * When new down fractal is confirmed, returns full buy signal.
+///   When new up fractal is confirmed, returns full sell signal.
+///   Otherwise returns no signal.
+/// * When `source` value crosses last `up fractal` level upwards, returns full buy signal.
+///   When `source` value crosses last `down fractal` level downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WilliamsFractals {
+	/// Count of bars on each side of the fractal. Default is `2`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)/2\)
+	pub wing: PeriodType,
+
+	/// Source value type for breakout signal. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for WilliamsFractals {
+	type Instance = WilliamsFractalsInstance;
+
+	const NAME: &'static str = "WilliamsFractals";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let length = cfg.wing * 2 + 1;
+
+		Ok(Self::Instance {
+			highs: Window::new(length, candle.high()),
+			lows: Window::new(length, candle.low()),
+			bars_left: length,
+			up_fractal: candle.high(),
+			down_fractal: candle.low(),
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.wing > 0 && self.wing < PeriodType::MAX / 2
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"wing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.wing = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for WilliamsFractals {
+	fn default() -> Self {
+		Self {
+			wing: 2,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct WilliamsFractalsInstance {
+	cfg: WilliamsFractals,
+
+	highs: Window<ValueType>,
+	lows: Window<ValueType>,
+	bars_left: PeriodType,
+	up_fractal: ValueType,
+	down_fractal: ValueType,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for WilliamsFractalsInstance {
+	type Config = WilliamsFractals;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.highs.push(candle.high());
+		self.lows.push(candle.low());
+		self.bars_left = self.bars_left.saturating_sub(1);
+
+		let wing = self.cfg.wing;
+		let mut signal1 = 0;
+
+		if self.bars_left == 0 {
+			let high = self.highs[wing];
+			let low = self.lows[wing];
+
+			let is_up = self
+				.highs
+				.iter()
+				.enumerate()
+				.all(|(i, v)| i == wing as usize || v < high);
+			let is_down = self
+				.lows
+				.iter()
+				.enumerate()
+				.all(|(i, v)| i == wing as usize || v > low);
+
+			if is_up {
+				self.up_fractal = high;
+				signal1 -= 1;
+			}
+
+			if is_down {
+				self.down_fractal = low;
+				signal1 += 1;
+			}
+		}
+
+		let src = candle.source(self.cfg.source);
+		let signal2 = self.cross_above.next((src, self.up_fractal))
+			- self.cross_under.next((src, self.down_fractal));
+
+		IndicatorResult::new(
+			&[self.up_fractal, self.down_fractal],
+			&[signal1.into(), signal2],
+		)
+	}
+}
@@ -0,0 +1,226 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+
+/// Zig Zag
+///
+/// Tracks swings of `high` and `low` values. Swing reverses when price retraces from the current leg extreme by at least
+/// `threshold` = max(`deviation` * extreme, `atr_multiplier` * `ATR`).
+///
+/// Classic zig zag drawings repaint: the last line always moves to the newest extreme until reversal happens.
+/// This implementation never repaints. Swing point becomes **confirmed** only at the bar when the reversal threshold is reached,
+/// so confirmed swing point is always reported with a delay. That delay is provided as `pivot offset` value.
+/// Until confirmation, the current leg extreme is provided as `tentative extreme` value, which may change on every bar.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:zigzag>
+///
+/// # 4 values
+///
+/// * `pivot` - last confirmed swing point value
+///
+/// Range of values is the same as the range of the `high`/`low` values.
+///
+/// * `pivot offset` - how many bars ago last confirmed swing point has occurred
+///
+/// Range in \[`0`; `+inf`\)
+///
+/// * `tentative extreme` - extreme value of the current (unconfirmed) leg
+///
+/// Range of values is the same as the range of the `high`/`low` values.
+///
+/// * `direction` of the current leg
+///
+/// `1.0` for upward leg, `-1.0` for downward leg, `0.0` while no swing point is confirmed yet.
+///
+/// # 1 signal
+///
+/// When swing low is confirmed, returns full buy signal.
+/// When swing high is confirmed, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZigZag {
+	/// Relative reversal threshold. Default is `0.05`.
+	///
+	/// Range in \[`0.0`; `1.0`\). When `0.0`, only ATR-based threshold is used.
+	pub deviation: ValueType,
+
+	/// ATR multiplier for reversal threshold. Default is `0.0`.
+	///
+	/// Range in \[`0.0`; `+inf`\). When `0.0`, only relative threshold is used.
+	pub atr_multiplier: ValueType,
+
+	/// ATR period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_period: PeriodType,
+}
+
+impl IndicatorConfig for ZigZag {
+	type Instance = ZigZagInstance;
+
+	const NAME: &'static str = "ZigZag";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			prev_close: candle.close(),
+			atr: method(
+				RegularMethods::RMA,
+				cfg.atr_period,
+				candle.high() - candle.low(),
+			)?,
+			high: candle.high(),
+			high_offset: 0,
+			low: candle.low(),
+			low_offset: 0,
+			pivot: candle.close(),
+			pivot_offset: 0,
+			direction: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.deviation >= 0.
+			&& self.deviation < 1.
+			&& self.atr_multiplier >= 0.
+			&& (self.deviation > 0. || self.atr_multiplier > 0.)
+			&& self.atr_period > 0
+			&& self.atr_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"deviation" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.deviation = value,
+			},
+			"atr_multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_multiplier = value,
+			},
+			"atr_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for ZigZag {
+	fn default() -> Self {
+		Self {
+			deviation: 0.05,
+			atr_multiplier: 0.0,
+			atr_period: 14,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct ZigZagInstance {
+	cfg: ZigZag,
+
+	prev_close: ValueType,
+	atr: RegularMethod,
+	high: ValueType,
+	high_offset: usize,
+	low: ValueType,
+	low_offset: usize,
+	pivot: ValueType,
+	pivot_offset: usize,
+	direction: i8,
+}
+
+impl ZigZagInstance {
+	fn threshold(&self, extreme: ValueType, atr: ValueType) -> ValueType {
+		(self.cfg.deviation * extreme.abs()).max(self.cfg.atr_multiplier * atr)
+	}
+}
+
+impl IndicatorInstance for ZigZagInstance {
+	type Config = ZigZag;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let tr = candle.tr_close(self.prev_close);
+		self.prev_close = candle.close();
+		let atr = self.atr.next(tr);
+
+		let (high, low) = (candle.high(), candle.low());
+
+		self.high_offset += 1;
+		self.low_offset += 1;
+		self.pivot_offset += 1;
+
+		if self.direction >= 0 && high >= self.high {
+			self.high = high;
+			self.high_offset = 0;
+		}
+
+		if self.direction <= 0 && low <= self.low {
+			self.low = low;
+			self.low_offset = 0;
+		}
+
+		let mut signal = 0;
+
+		if self.direction >= 0 && self.high - low >= self.threshold(self.high, atr) {
+			// swing high confirmed, downward leg starts
+			self.pivot = self.high;
+			self.pivot_offset = self.high_offset;
+			self.direction = -1;
+			self.low = low;
+			self.low_offset = 0;
+			signal = -1;
+		} else if self.direction <= 0 && high - self.low >= self.threshold(self.low, atr) {
+			// swing low confirmed, upward leg starts
+			self.pivot = self.low;
+			self.pivot_offset = self.low_offset;
+			self.direction = 1;
+			self.high = high;
+			self.high_offset = 0;
+			signal = 1;
+		}
+
+		let tentative = match self.direction {
+			1 => self.high,
+			-1 => self.low,
+			_ => candle.close(),
+		};
+
+		IndicatorResult::new(
+			&[
+				self.pivot,
+				self.pivot_offset as ValueType,
+				tentative,
+				self.direction as ValueType,
+			],
+			&[signal.into()],
+		)
+	}
+}
@@ -106,6 +106,20 @@ limitations under the License.
 //!
 //! And many others: [See Full list](crate::indicators#structs)
 //!
+//! ## Patterns recognition
+//!
+//! - [Candlestick patterns](crate::patterns::CandlestickPatterns);
+//! - [Chart patterns](crate::patterns::ChartPatterns);
+//!
+//! ## Backtesting
+//!
+//! - [Backtesting engine](crate::backtest::Backtest) driven by indicators signals;
+//! - [Performance metrics](crate::metrics::Metrics);
+//! - [Parameters optimization](crate::optimize);
+//! - [Validation against reference series](crate::validation);
+//! - [Chart-ready series export](crate::export::Chart);
+//! - [Custom indicators and rules from text expressions](crate::expression::Expression);
+//!
 //! ## Method usage example
 //!
 //! ```
@@ -161,15 +175,22 @@ limitations under the License.
 //!
 //! If you like this library and you want to say thanks, you can do it also by donating to bitcoin address `1P3gTnaTK9LKSYx2nETrKe2zjP4HMkdhvK`
 
+pub mod backtest;
 pub mod core;
+pub mod export;
+pub mod expression;
 pub mod helpers;
 pub mod indicators;
 pub mod methods;
+pub mod metrics;
+pub mod optimize;
+pub mod patterns;
+pub mod validation;
 
 /// Contains main traits you need to start using this library
 pub mod prelude {
 	pub use super::core::{
-		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Sequence, OHLCV,
+		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Sequence, Strategy, OHLCV,
 	};
 
 	/// Dynamically dispatchable traits for indicators creation
@@ -0,0 +1,133 @@
+use crate::core::{Error, Method, PeriodType, OHLCV};
+use crate::methods::{HighestIndex, LowestIndex};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the number of bars since the highest `high` and since the lowest `low` over the last `length` candles
+///
+/// It is the building block of the [Aroon](crate::indicators::Aroon) indicator. Also it may be used for time-based exits:
+/// with `length` = `11` the first output equals `10` when there is no new high for the last `10` bars.
+///
+/// If period has more than one extreme values, then the newest one is taken (e.g. the smallest number of bars).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is (`bars since highest high`, `bars since lowest low`): ([`PeriodType`], [`PeriodType`])
+///
+/// Both values are in range \[`0`; `length`\)
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Candle, Method};
+/// use yata::methods::BarsSinceExtreme;
+///
+/// let candle = |high, low| Candle { high, low, ..Candle::default() };
+/// let candles = [candle(2.0, 1.0), candle(3.0, 2.0), candle(2.5, 0.5), candle(2.0, 1.0), candle(1.5, 1.0)];
+/// let r       = [(0, 0),           (0, 1),           (1, 0),           (2, 1),           (3, 2)];
+///
+/// let mut bars_since = BarsSinceExtreme::new(5, &candles[0]).unwrap();
+///
+/// candles.iter().zip(r.iter()).for_each(|(candle, &r)| {
+///     assert_eq!(bars_since.next(candle), r);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`HighestIndex`], [`LowestIndex`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`OHLCV`]: crate::core::OHLCV
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BarsSinceExtreme {
+	highest_index: HighestIndex,
+	lowest_index: LowestIndex,
+}
+
+impl<'a> Method<'a> for BarsSinceExtreme {
+	type Params = PeriodType;
+	type Input = &'a dyn OHLCV;
+	type Output = (PeriodType, PeriodType);
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			highest_index: HighestIndex::new(length, value.high())?,
+			lowest_index: LowestIndex::new(length, value.low())?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		(
+			self.highest_index.next(value.high()),
+			self.lowest_index.next(value.low()),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BarsSinceExtreme as TestingMethod, Method};
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_bars_since_extreme_const() {
+		for i in 1..255 {
+			let input = RandomCandles::default().first();
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_bars_since_extreme() {
+		let src: Vec<_> = RandomCandles::default().take(300).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, candle)| {
+				let (mut max_value, mut min_value) = (candle.high, candle.low);
+				let (mut max_index, mut min_index) = (0, 0);
+
+				for j in 0..length {
+					let c = &src[i.saturating_sub(j)];
+
+					if c.high > max_value {
+						max_value = c.high;
+						max_index = j;
+					}
+
+					if c.low < min_value {
+						min_value = c.low;
+						min_index = j;
+					}
+				}
+
+				let (highest, lowest) = method.next(candle);
+				assert_eq!((max_index, min_index), (highest as usize, lowest as usize));
+			});
+		});
+	}
+}
@@ -0,0 +1,188 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Moving [Pearson correlation](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient) of two timeseries over the window of size `length`
+///
+/// Also provides covariance, variances and beta of the first timeseries relative to the second one over the same window.
+///
+/// When any of timeseries is constant over the window, correlation is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is a pair of [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Correlation;
+///
+/// let mut correlation = Correlation::new(3, (1.0, 2.0)).unwrap();
+///
+/// correlation.next((2.0, 4.0));
+/// assert!((correlation.next((3.0, 6.0)) - 1.0).abs() < 1e-5);
+/// assert!((correlation.beta() - 0.5).abs() < 1e-5);
+///
+/// correlation.next((4.0, 4.0));
+/// assert!((correlation.next((5.0, 2.0)) + 1.0).abs() < 1e-5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Correlation {
+	length: ValueType,
+	mean_x: ValueType,
+	mean_y: ValueType,
+	moment_xx: ValueType,
+	moment_yy: ValueType,
+	moment_xy: ValueType,
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl Correlation {
+	/// Returns sample covariance of timeseries over the window
+	#[must_use]
+	pub fn covariance(&self) -> ValueType {
+		self.moment_xy / (self.length - 1.)
+	}
+
+	/// Returns sample variances of the first and the second timeseries over the window
+	#[must_use]
+	pub fn variance(&self) -> (ValueType, ValueType) {
+		// float values may produce negative values, when variance is really near to zero
+		(
+			self.moment_xx.max(0.) / (self.length - 1.),
+			self.moment_yy.max(0.) / (self.length - 1.),
+		)
+	}
+
+	/// Returns beta of the first timeseries relative to the second one: covariance divided by variance of the second timeseries
+	///
+	/// Returns `0.0` when the second timeseries is constant over the window.
+	#[must_use]
+	pub fn beta(&self) -> ValueType {
+		let (_, variance) = self.variance();
+
+		if variance > 0. {
+			self.covariance() / variance
+		} else {
+			0.
+		}
+	}
+}
+
+impl Method<'_> for Correlation {
+	type Params = PeriodType;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 | 1 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				length: length as ValueType,
+				mean_x: value.0,
+				mean_y: value.1,
+				moment_xx: 0.,
+				moment_yy: 0.,
+				moment_xy: 0.,
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let (x, y) = value;
+		let (prev_x, prev_y) = self.window.push(value);
+
+		// co-moments are updated around the means, which is much more stable than raw sums
+		let mean_x = self.mean_x + (x - prev_x) / self.length;
+		let mean_y = self.mean_y + (y - prev_y) / self.length;
+
+		self.moment_xx += (x - prev_x) * (x + prev_x - self.mean_x - mean_x);
+		self.moment_yy += (y - prev_y) * (y + prev_y - self.mean_y - mean_y);
+		self.moment_xy +=
+			(x - mean_x).mul_add(y - self.mean_y, -(prev_x - mean_x) * (prev_y - self.mean_y));
+
+		self.mean_x = mean_x;
+		self.mean_y = mean_y;
+
+		let (variance_x, variance_y) = self.variance();
+		let divider = (variance_x * variance_y).sqrt();
+
+		if divider > 0. {
+			(self.covariance() / divider).clamp(-1., 1.)
+		} else {
+			0.
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Correlation as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_correlation_const() {
+		for i in 2..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 2.5);
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_correlation() {
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+		let src: Vec<_> = candles.iter().map(|x| (x.close, x.volume)).collect();
+
+		for length in (3..255).step_by(7) {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			for i in 0..src.len() {
+				let value = method.next(src[i]);
+
+				let window: Vec<_> = (0..length).map(|j| src[i.saturating_sub(j)]).collect();
+				let n = length as ValueType;
+				let mean_x = window.iter().map(|x| x.0).sum::<ValueType>() / n;
+				let mean_y = window.iter().map(|x| x.1).sum::<ValueType>() / n;
+
+				let cov: ValueType = window.iter().map(|x| (x.0 - mean_x) * (x.1 - mean_y)).sum();
+				let var_x: ValueType = window.iter().map(|x| (x.0 - mean_x).powi(2)).sum();
+				let var_y: ValueType = window.iter().map(|x| (x.1 - mean_y).powi(2)).sum();
+
+				if var_x > 1e-9 && var_y > 1e-9 {
+					assert_eq_float(cov / (var_x * var_y).sqrt(), value);
+					assert_eq_float(cov / var_y, method.beta());
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,168 @@
+use crate::core::Method;
+use crate::core::{Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Calculates relative drawdown of timeseries of type [`ValueType`] from its running peak
+///
+/// Drawdown is (`peak` - `value`) / `peak`, where `peak` is the highest value so far.
+///
+/// Also keeps track of the running peak, maximum drawdown so far and count of values since the last peak.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// Input values should be positive (e.g. price or account equity).
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is in range \[`0.0`; `+inf`\): it is in \[`0.0`; `1.0`\] while values stay positive
+/// and exceeds `1.0` only when value drops below zero.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Drawdown;
+///
+/// let values = [10.0, 12.0, 9.0, 6.0, 15.0, 12.0];
+/// let r      = [ 0.0,  0.0, 0.25, 0.5, 0.0, 0.2];
+/// let mut drawdown = Drawdown::new((), values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = drawdown.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+///
+/// assert_eq!(drawdown.max_drawdown(), 0.5);
+/// assert_eq!(drawdown.peak(), 15.0);
+/// assert_eq!(drawdown.duration(), 1);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Drawdown {
+	peak: ValueType,
+	max: ValueType,
+	duration: usize,
+}
+
+impl Drawdown {
+	/// Returns the highest value so far
+	#[inline]
+	#[must_use]
+	pub const fn peak(&self) -> ValueType {
+		self.peak
+	}
+
+	/// Returns maximum relative drawdown so far
+	#[inline]
+	#[must_use]
+	pub const fn max_drawdown(&self) -> ValueType {
+		self.max
+	}
+
+	/// Returns count of values since the last peak (`0` when the last value is the peak)
+	#[inline]
+	#[must_use]
+	pub const fn duration(&self) -> usize {
+		self.duration
+	}
+}
+
+impl Method<'_> for Drawdown {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, value: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		if !value.is_finite() || value <= 0. {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			peak: value,
+			max: 0.,
+			duration: 0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		if value >= self.peak {
+			self.peak = value;
+			self.duration = 0;
+			return 0.;
+		}
+
+		self.duration += 1;
+
+		let drawdown = (self.peak - value) / self.peak;
+		self.max = self.max.max(drawdown);
+
+		drawdown
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Drawdown as TestingMethod;
+	use crate::core::{Method, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_drawdown_const() {
+		for i in 1..30 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((), input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+			assert_eq!(method.duration(), 0);
+		}
+
+		assert!(TestingMethod::new((), 0.0).is_err());
+		assert!(TestingMethod::new((), ValueType::NAN).is_err());
+	}
+
+	#[test]
+	fn test_drawdown() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let mut method = TestingMethod::new((), src[0]).unwrap();
+		let mut max_drawdown: ValueType = 0.0;
+
+		src.iter().enumerate().for_each(|(i, &x)| {
+			let value = method.next(x);
+
+			let peak_index = (0..=i)
+				.max_by(|&a, &b| src[a].partial_cmp(&src[b]).unwrap())
+				.unwrap();
+			let peak = src[peak_index];
+			let drawdown = (peak - x) / peak;
+			max_drawdown = max_drawdown.max(drawdown);
+
+			assert_eq_float(drawdown, value);
+			assert_eq_float(peak, method.peak());
+			assert_eq_float(max_drawdown, method.max_drawdown());
+			assert_eq!(i - peak_index, method.duration());
+		});
+	}
+}
@@ -68,6 +68,8 @@ mod tsi;
 pub use tsi::*;
 mod st_dev;
 pub use st_dev::*;
+mod correlation;
+pub use correlation::*;
 mod volatility;
 pub use volatility::*;
 mod cci;
@@ -76,11 +78,19 @@ mod mean_abs_dev;
 pub use mean_abs_dev::*;
 mod median_abs_dev;
 pub use median_abs_dev::*;
+mod percent_rank;
+pub use percent_rank::*;
 mod vidya;
 pub use vidya::*;
+mod drawdown;
+pub use drawdown::*;
 
 mod cross;
 pub use cross::*;
+mod zone_cross;
+pub use zone_cross::*;
+mod signal_filters;
+pub use signal_filters::*;
 mod reversal;
 pub use reversal::*;
 mod highest_lowest;
@@ -89,6 +99,8 @@ mod adi;
 mod highest_lowest_index;
 pub use adi::*;
 pub use highest_lowest_index::*;
+mod bars_since_extreme;
+pub use bars_since_extreme::*;
 mod past;
 pub use past::*;
 mod heikin_ashi;
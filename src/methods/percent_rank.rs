@@ -0,0 +1,118 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Percent rank](https://en.wikipedia.org/wiki/Percentile_rank) of the current value over the previous `length` values for timeseries of type [`ValueType`]
+///
+/// Returns the part of the previous `length` values, which are strictly less than the current value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::PercentRank;
+///
+/// let values = [1.0, 2.0, 3.0, 2.5, 1.0, 2.0];
+/// let r      = [0.0, 1.0, 1.0, 0.5, 0.0, 0.5];
+///
+/// let mut percent_rank = PercentRank::new(2, values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = percent_rank.next(values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentRank {
+	window: Window<ValueType>,
+	divider: ValueType,
+}
+
+impl Method<'_> for PercentRank {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				window: Window::new(length, value),
+				divider: (length as ValueType).recip(),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let count = self.window.iter().filter(|&x| x < value).count();
+		self.window.push(value);
+
+		count as ValueType * self.divider
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PercentRank as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_percent_rank_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const_float(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_percent_rank() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let count = (1..=length)
+					.filter(|&j| src[i.saturating_sub(j)] < x)
+					.count();
+
+				assert_eq_float(count as ValueType / length as ValueType, method.next(x));
+			});
+		});
+	}
+}
@@ -0,0 +1,375 @@
+use crate::core::Method;
+use crate::core::{Action, Error, PeriodType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Suppresses signals of type [`Action`] until they are sustained for `period` consecutive values.
+///
+/// Returns input `action` when the last `period` inputs (including the current one) all have the same direction.
+/// Otherwise returns [`Action::None`](crate::core::Action::None).
+///
+/// Initial `value` is not counted.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` should be > `0`. When `period` is `1`, every input is returned as is.
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Debounce;
+///
+/// let mut debounce = Debounce::new(2, Action::None).unwrap();
+///
+/// let s = vec![1, -1, -1, -1, 0, 1, 1];
+/// let r = vec![0,  0, -1, -1, 0, 0, 1];
+///
+/// (0..s.len()).for_each(|i| {
+///     assert_eq!(debounce.next(s[i].into()).analog(), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Cooldown`], [`Confirm`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Debounce {
+	period: PeriodType,
+	direction: i8,
+	streak: PeriodType,
+}
+
+impl Method<'_> for Debounce {
+	type Params = PeriodType;
+	type Input = Action;
+	type Output = Action;
+
+	fn new(period: Self::Params, _: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		match period {
+			0 => Err(Error::WrongMethodParameters),
+			period => Ok(Self {
+				period,
+				direction: 0,
+				streak: 0,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let direction = value.analog();
+
+		if direction == self.direction {
+			self.streak = self.streak.saturating_add(1);
+		} else {
+			self.direction = direction;
+			self.streak = 1;
+		}
+
+		if direction != 0 && self.streak >= self.period {
+			value
+		} else {
+			Action::None
+		}
+	}
+}
+
+/// Suppresses any signals of type [`Action`] for `period` values after the last returned signal.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// When `period` is `0`, every input is returned as is.
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Cooldown;
+///
+/// let mut cooldown = Cooldown::new(2, Action::None).unwrap();
+///
+/// let s = vec![1, -1, 1, 0, -1, 1];
+/// let r = vec![1,  0, 0, 0, -1, 0];
+///
+/// (0..s.len()).for_each(|i| {
+///     assert_eq!(cooldown.next(s[i].into()).analog(), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Debounce`], [`Confirm`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cooldown {
+	period: PeriodType,
+	left: PeriodType,
+}
+
+impl Method<'_> for Cooldown {
+	type Params = PeriodType;
+	type Input = Action;
+	type Output = Action;
+
+	fn new(period: Self::Params, _: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		Ok(Self { period, left: 0 })
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		if self.left > 0 {
+			self.left -= 1;
+			return Action::None;
+		}
+
+		if value.analog() == 0 {
+			return Action::None;
+		}
+
+		self.left = self.period;
+		value
+	}
+}
+
+/// Returns signal of type [`Action`] only when it is confirmed by the same direction signal within the next `period` values.
+///
+/// After confirmation, the next confirmation requires two new signals again.
+/// Signal of the opposite direction replaces the unconfirmed one.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Confirm;
+///
+/// let mut confirm = Confirm::new(2, Action::None).unwrap();
+///
+/// let s = vec![1, 0, 1, 1, 0, 0, 1, -1, -1];
+/// let r = vec![0, 0, 1, 0, 0, 0, 0,  0, -1];
+///
+/// (0..s.len()).for_each(|i| {
+///     assert_eq!(confirm.next(s[i].into()).analog(), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Debounce`], [`Cooldown`]
+///
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Confirm {
+	period: PeriodType,
+	direction: i8,
+	age: PeriodType,
+}
+
+impl Method<'_> for Confirm {
+	type Params = PeriodType;
+	type Input = Action;
+	type Output = Action;
+
+	fn new(period: Self::Params, _: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		match period {
+			0 => Err(Error::WrongMethodParameters),
+			period => Ok(Self {
+				period,
+				direction: 0,
+				age: 0,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.age = self.age.saturating_add(1);
+
+		let direction = value.analog();
+		if direction == 0 {
+			return Action::None;
+		}
+
+		if direction == self.direction && self.age <= self.period {
+			self.direction = 0;
+			return value;
+		}
+
+		self.direction = direction;
+		self.age = 0;
+
+		Action::None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Confirm, Cooldown, Debounce};
+	use crate::core::{Action, Method};
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+	use crate::methods::Cross;
+
+	fn signals() -> Vec<Action> {
+		let src: Vec<_> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+		let mut cross = Cross::new((), (src[0], 1.0)).unwrap();
+
+		src.iter().map(|&x| cross.next((x, 1.0))).collect()
+	}
+
+	#[test]
+	fn test_debounce_const() {
+		for period in 1..20 {
+			let mut debounce = Debounce::new(period, Action::BUY_ALL).unwrap();
+
+			for _ in 1..period {
+				assert_eq!(debounce.next(Action::BUY_ALL), Action::None);
+			}
+
+			test_const(&mut debounce, Action::BUY_ALL, Action::BUY_ALL);
+		}
+
+		assert!(Debounce::new(0, Action::None).is_err());
+	}
+
+	#[test]
+	fn test_cooldown_const() {
+		for period in 0..20 {
+			let mut cooldown = Cooldown::new(period, Action::None).unwrap();
+			test_const(&mut cooldown, Action::None, Action::None);
+
+			for i in 0..100 {
+				let expected = if i % (period as usize + 1) == 0 {
+					Action::SELL_ALL
+				} else {
+					Action::None
+				};
+
+				assert_eq!(cooldown.next(Action::SELL_ALL), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_confirm_const() {
+		let mut confirm = Confirm::new(1, Action::None).unwrap();
+
+		for i in 0..100 {
+			let expected = if i % 2 == 1 {
+				Action::BUY_ALL
+			} else {
+				Action::None
+			};
+
+			assert_eq!(confirm.next(Action::BUY_ALL), expected);
+		}
+
+		assert!(Confirm::new(0, Action::None).is_err());
+	}
+
+	#[test]
+	fn test_filters_identity() {
+		let signals = signals();
+
+		let mut debounce = Debounce::new(1, Action::None).unwrap();
+		let mut cooldown = Cooldown::new(0, Action::None).unwrap();
+
+		for &signal in &signals {
+			assert_eq!(debounce.next(signal), signal);
+			assert_eq!(cooldown.next(signal), signal);
+		}
+	}
+
+	#[test]
+	fn test_cooldown() {
+		let signals = signals();
+
+		for period in 0..10 {
+			let mut cooldown = Cooldown::new(period, Action::None).unwrap();
+			let mut last: Option<usize> = None;
+
+			for (i, &signal) in signals.iter().enumerate() {
+				let output = cooldown.next(signal);
+
+				let allowed = last.is_none_or(|last| i - last > period as usize);
+				if allowed && signal.analog() != 0 {
+					assert_eq!(output, signal);
+					last = Some(i);
+				} else {
+					assert_eq!(output, Action::None);
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,188 @@
+use crate::core::Method;
+use crate::core::{Action, Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{CrossAbove, CrossUnder};
+
+/// Searches for timeseries line of type [`ValueType`] enters or leaves overbought/oversold zones.
+///
+/// Zones are bounded by `lower` and `upper` levels: overbought zone is above `upper` level and oversold zone is below `lower` level.
+///
+/// Returns an array of 2 signals \[`enters`, `leaves`\]:
+///
+/// * `enters` is [`Action::SELL_ALL`](crate::core::Action::SELL_ALL) when `value` crosses `upper` level upwards,
+///   [`Action::BUY_ALL`](crate::core::Action::BUY_ALL) when `value` crosses `lower` level downwards.
+///   Otherwise returns [`Action::None`](crate::core::Action::None).
+/// * `leaves` is [`Action::SELL_ALL`](crate::core::Action::SELL_ALL) when `value` crosses `upper` level downwards,
+///   [`Action::BUY_ALL`](crate::core::Action::BUY_ALL) when `value` crosses `lower` level upwards.
+///   Otherwise returns [`Action::None`](crate::core::Action::None).
+///
+/// Current zone of the last `value` is available through [`ZoneCross::zone`].
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`lower`: [`ValueType`], `upper`: [`ValueType`])
+///
+/// `lower` should be less or equal to `upper`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is \[`enters`: [`Action`], `leaves`: [`Action`]\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ZoneCross;
+///
+/// let mut zone_cross = ZoneCross::new((0.3, 0.7), 0.5).unwrap();
+///
+/// let s = vec![0.5, 0.8, 0.6, 0.2, 0.4, 0.5];
+/// let e = vec![ 0,  -1,   0,   1,   0,   0 ];
+/// let l = vec![ 0,   0,  -1,   0,   1,   0 ];
+///
+/// (0..s.len()).for_each(|i| {
+///     let [enters, leaves] = zone_cross.next(s[i]);
+///     assert_eq!(enters.analog(), e[i]);
+///     assert_eq!(leaves.analog(), l[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Cross`](crate::methods::Cross), [`CrossAbove`], [`CrossUnder`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZoneCross {
+	lower: ValueType,
+	upper: ValueType,
+	zone: i8,
+	lower_above: CrossAbove,
+	lower_under: CrossUnder,
+	upper_above: CrossAbove,
+	upper_under: CrossUnder,
+}
+
+impl ZoneCross {
+	/// Returns `1` if the last `value` is above `upper` level, `-1` if it is below `lower` level.
+	/// Otherwise returns `0`
+	#[inline]
+	#[must_use]
+	pub const fn zone(&self) -> i8 {
+		self.zone
+	}
+
+	#[inline]
+	fn zone_of(value: ValueType, lower: ValueType, upper: ValueType) -> i8 {
+		(value > upper) as i8 - (value < lower) as i8
+	}
+}
+
+impl Method<'_> for ZoneCross {
+	type Params = (ValueType, ValueType);
+	type Input = ValueType;
+	type Output = [Action; 2];
+
+	fn new(levels: Self::Params, value: Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		let (lower, upper) = levels;
+
+		if lower.is_nan() || upper.is_nan() || lower > upper {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			lower,
+			upper,
+			zone: Self::zone_of(value, lower, upper),
+			lower_above: CrossAbove::new((), (value, lower))?,
+			lower_under: CrossUnder::new((), (value, lower))?,
+			upper_above: CrossAbove::new((), (value, upper))?,
+			upper_under: CrossUnder::new((), (value, upper))?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let lower_above = self.lower_above.binary(value, self.lower);
+		let lower_under = self.lower_under.binary(value, self.lower);
+		let upper_above = self.upper_above.binary(value, self.upper);
+		let upper_under = self.upper_under.binary(value, self.upper);
+
+		self.zone = Self::zone_of(value, self.lower, self.upper);
+
+		let enters = lower_under as i8 - upper_above as i8;
+		let leaves = lower_above as i8 - upper_under as i8;
+
+		[enters.into(), leaves.into()]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(unused_imports)]
+	use super::ZoneCross as TestingMethod;
+	use crate::core::{Method, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+	use crate::methods::Cross;
+
+	#[test]
+	fn test_zone_cross_const() {
+		let input = 0.5;
+		let mut zone_cross = TestingMethod::new((0.3, 0.7), input).unwrap();
+		let output = zone_cross.next(input);
+
+		test_const(&mut zone_cross, input, output);
+		assert_eq!(zone_cross.zone(), 0);
+	}
+
+	#[test]
+	fn test_zone_cross_wrong_levels() {
+		assert!(TestingMethod::new((0.7, 0.3), 0.5).is_err());
+		assert!(TestingMethod::new((ValueType::NAN, 0.3), 0.5).is_err());
+	}
+
+	#[test]
+	fn test_zone_cross() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let avg = src.iter().sum::<ValueType>() / src.len() as ValueType;
+		let (lower, upper) = (avg * 0.95, avg * 1.05);
+
+		let mut zone_cross = TestingMethod::new((lower, upper), src[0]).unwrap();
+		let mut cross_lower = Cross::new((), (src[0], lower)).unwrap();
+		let mut cross_upper = Cross::new((), (src[0], upper)).unwrap();
+
+		src.iter().enumerate().for_each(|(i, &x)| {
+			let [enters, leaves] = zone_cross.next(x);
+
+			let oversold = cross_lower.next((x, lower)).analog();
+			let overbought = cross_upper.next((x, upper)).analog();
+
+			let enters2 = (oversold < 0) as i8 - (overbought > 0) as i8;
+			let leaves2 = (oversold > 0) as i8 - (overbought < 0) as i8;
+			let zone2 = (x > upper) as i8 - (x < lower) as i8;
+
+			assert_eq!(enters.analog(), enters2, "enters at index {i}");
+			assert_eq!(leaves.analog(), leaves2, "leaves at index {i}");
+			assert_eq!(zone_cross.zone(), zone2, "zone at index {i}");
+		});
+	}
+}
@@ -0,0 +1,257 @@
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::Correlation;
+
+/// Rolling correlation matrix of several symbols
+///
+/// Ingests aligned candles of `N` symbols (one candle per symbol at every step) and tracks [`Correlation`]
+/// of close-to-close returns of every pair of symbols over the window of size `length`.
+/// Also tracks beta of every symbol relative to the `benchmark` symbol.
+///
+/// Every step is O(`N`²).
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::RandomCandles;
+/// use yata::metrics::CorrelationMatrix;
+///
+/// let first: Vec<_> = RandomCandles::new().take(100).collect();
+/// let second: Vec<_> = RandomCandles::new().skip(100).take(100).collect();
+///
+/// let mut matrix = CorrelationMatrix::new(20, 0, &[first[0], second[0]]).unwrap();
+///
+/// for (a, b) in first.iter().zip(&second).skip(1) {
+///     let (i, j, change) = matrix.next(&[*a, *b]);
+///     println!("correlation of {} and {} changed by {}", i, j, change);
+/// }
+///
+/// println!("correlation: {}", matrix.correlation(0, 1));
+/// println!("beta of the second symbol: {}", matrix.beta(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CorrelationMatrix {
+	benchmark: usize,
+	prev: Vec<ValueType>,
+	pairs: Vec<Correlation>,
+	values: Vec<ValueType>,
+}
+
+impl CorrelationMatrix {
+	/// Creates new correlation matrix over the window of size `length` from the first `candles` of every symbol
+	///
+	/// Returns an error when there are less than `2` symbols, when `benchmark` is not a valid symbol index
+	/// or when `length` is less than `2`.
+	pub fn new<T: OHLCV>(
+		length: PeriodType,
+		benchmark: usize,
+		candles: &[T],
+	) -> Result<Self, Error> {
+		let symbols = candles.len();
+
+		if symbols < 2 || benchmark >= symbols {
+			return Err(Error::WrongConfig);
+		}
+
+		let count = symbols * (symbols - 1) / 2;
+		let pairs = (0..count)
+			.map(|_| Correlation::new(length, (0., 0.)))
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self {
+			benchmark,
+			prev: candles.iter().map(OHLCV::close).collect(),
+			pairs,
+			values: vec![0.; count],
+		})
+	}
+
+	/// Ingests the next aligned candles of every symbol
+	///
+	/// Returns the pair of symbols which correlation has changed the most (by absolute value) and the change.
+	///
+	/// # Panics
+	///
+	/// Panics when count of `candles` differs from count of symbols.
+	pub fn next<T: OHLCV>(&mut self, candles: &[T]) -> (usize, usize, ValueType) {
+		assert_eq!(
+			candles.len(),
+			self.symbols(),
+			"count of candles must be equal to count of symbols"
+		);
+
+		let returns: Vec<_> = candles
+			.iter()
+			.zip(&self.prev)
+			.map(|(candle, &prev)| {
+				if prev > 0. {
+					candle.close() / prev - 1.
+				} else {
+					0.
+				}
+			})
+			.collect();
+
+		for (prev, candle) in self.prev.iter_mut().zip(candles) {
+			*prev = candle.close();
+		}
+
+		let mut largest: (usize, usize, ValueType) = (0, 1, 0.);
+
+		for i in 0..returns.len() {
+			for j in i + 1..returns.len() {
+				let index = self.index(i, j);
+				let value = self.pairs[index].next((returns[i], returns[j]));
+				let change = value - self.values[index];
+				self.values[index] = value;
+
+				if change.abs() > largest.2.abs() {
+					largest = (i, j, change);
+				}
+			}
+		}
+
+		largest
+	}
+
+	/// Returns count of symbols
+	#[must_use]
+	pub const fn symbols(&self) -> usize {
+		self.prev.len()
+	}
+
+	/// Returns index of the benchmark symbol
+	#[must_use]
+	pub const fn benchmark(&self) -> usize {
+		self.benchmark
+	}
+
+	/// Returns current correlation of returns of the symbols `i` and `j`
+	///
+	/// Correlation of a symbol with itself is always `1.0`.
+	///
+	/// # Panics
+	///
+	/// Panics when `i` or `j` is not a valid symbol index.
+	#[must_use]
+	pub fn correlation(&self, i: usize, j: usize) -> ValueType {
+		assert!(
+			i < self.symbols() && j < self.symbols(),
+			"wrong symbol index"
+		);
+
+		match i.cmp(&j) {
+			std::cmp::Ordering::Equal => 1.,
+			std::cmp::Ordering::Less => self.values[self.index(i, j)],
+			std::cmp::Ordering::Greater => self.values[self.index(j, i)],
+		}
+	}
+
+	/// Returns current beta of returns of the symbol `i` relative to the benchmark symbol
+	///
+	/// Beta of the benchmark itself is always `1.0`. Beta is `0.0` while benchmark returns are constant over the window.
+	///
+	/// # Panics
+	///
+	/// Panics when `i` is not a valid symbol index.
+	#[must_use]
+	pub fn beta(&self, i: usize) -> ValueType {
+		assert!(i < self.symbols(), "wrong symbol index");
+
+		if i == self.benchmark {
+			return 1.;
+		}
+
+		let (pair, variance) = if i < self.benchmark {
+			let pair = &self.pairs[self.index(i, self.benchmark)];
+			(pair, pair.variance().1)
+		} else {
+			let pair = &self.pairs[self.index(self.benchmark, i)];
+			(pair, pair.variance().0)
+		};
+
+		if variance > 0. {
+			pair.covariance() / variance
+		} else {
+			0.
+		}
+	}
+
+	/// Returns the whole matrix of current correlations
+	#[must_use]
+	pub fn matrix(&self) -> Vec<Vec<ValueType>> {
+		let symbols = self.symbols();
+
+		(0..symbols)
+			.map(|i| (0..symbols).map(|j| self.correlation(i, j)).collect())
+			.collect()
+	}
+
+	/// Index of the pair `i` < `j` in the upper triangle of the matrix
+	const fn index(&self, i: usize, j: usize) -> usize {
+		i * (2 * self.symbols() - i - 1) / 2 + (j - i - 1)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CorrelationMatrix;
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_correlation_matrix() {
+		let source: Vec<_> = RandomCandles::new().take(200).collect();
+
+		// the second symbol returns are exactly doubled benchmark returns, the third one's are negated
+		let mut closes: [ValueType; 3] = [source[0].close; 3];
+		let candle = |close| Candle {
+			close,
+			..Candle::default()
+		};
+
+		let mut matrix = CorrelationMatrix::new(20, 0, &closes.map(candle)).unwrap();
+
+		for pair in source.windows(2) {
+			let ratio = pair[1].close / pair[0].close - 1.;
+			closes[0] *= 1. + ratio;
+			closes[1] *= ratio.mul_add(2., 1.);
+			closes[2] *= 1. - ratio;
+
+			matrix.next(&closes.map(candle));
+		}
+
+		assert_eq_float(1., matrix.correlation(0, 1));
+		assert_eq_float(-1., matrix.correlation(2, 0));
+		assert_eq_float(-1., matrix.correlation(1, 2));
+		assert_eq_float(1., matrix.correlation(2, 2));
+
+		assert_eq_float(1., matrix.beta(0));
+		assert_eq_float(2., matrix.beta(1));
+		assert_eq_float(-1., matrix.beta(2));
+
+		let matrix = matrix.matrix();
+		assert_eq!(matrix.len(), 3);
+		assert_eq_float(matrix[0][2], matrix[2][0]);
+
+		assert!(CorrelationMatrix::new(20, 2, &[candle(1.), candle(1.)]).is_err());
+		assert!(CorrelationMatrix::new(1, 0, &[candle(1.), candle(1.)]).is_err());
+	}
+
+	#[test]
+	fn test_correlation_matrix_largest_change() {
+		let candle = |close| Candle {
+			close,
+			..Candle::default()
+		};
+		let mut matrix =
+			CorrelationMatrix::new(3, 0, &[candle(1.), candle(1.), candle(1.)]).unwrap();
+
+		matrix.next(&[candle(1.), candle(1.), candle(1.)]);
+		matrix.next(&[candle(1.), candle(1.), candle(1.)]);
+
+		// only the first and the third symbols move together
+		let (i, j, change) = matrix.next(&[candle(2.), candle(1.), candle(2.)]);
+		assert_eq!((i, j), (0, 2));
+		assert_eq_float(1., change);
+	}
+}
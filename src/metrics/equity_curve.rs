@@ -0,0 +1,167 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::Report;
+use crate::core::{Error, Method, ValueType};
+use crate::methods::Drawdown;
+
+/// Incremental equity curve and drawdown tracker
+///
+/// Equity is updated either by its absolute value with [`push_equity`](EquityCurve::push_equity)
+/// (e.g. marked to market account equity on every bar), or by a relative return with [`push_return`](EquityCurve::push_return)
+/// (e.g. return of every closed trade). Drawdowns are tracked by the [`Drawdown`] method.
+///
+/// # Examples
+///
+/// ```
+/// use yata::metrics::EquityCurve;
+///
+/// let mut curve = EquityCurve::new(1000.0).unwrap();
+///
+/// curve.push_return(0.2);
+/// curve.push_return(-0.5);
+/// curve.push_equity(900.0);
+///
+/// assert_eq!(curve.equity(), 900.0);
+/// assert_eq!(curve.peak(), 1200.0);
+/// assert_eq!(curve.drawdown(), 0.25);
+/// assert_eq!(curve.max_drawdown(), 0.5);
+/// assert_eq!(curve.duration(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EquityCurve {
+	initial: ValueType,
+	equity: ValueType,
+	drawdown: Drawdown,
+	current: ValueType,
+	max_duration: usize,
+}
+
+impl EquityCurve {
+	/// Creates new equity curve starting at the `initial` equity
+	///
+	/// `initial` equity must be positive.
+	pub fn new(initial: ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			initial,
+			equity: initial,
+			drawdown: Drawdown::new((), initial)?,
+			current: 0.,
+			max_duration: 0,
+		})
+	}
+
+	/// Creates equity curve of the [`Backtest`](crate::backtest::Backtest) report
+	pub fn from_report(report: &Report) -> Result<Self, Error> {
+		let mut curve = Self::new(1.)?;
+
+		for &equity in &report.equity {
+			curve.push_equity(equity);
+		}
+
+		Ok(curve)
+	}
+
+	/// Sets current equity to the `value` and returns current drawdown
+	pub fn push_equity(&mut self, value: ValueType) -> ValueType {
+		self.equity = value;
+		self.current = self.drawdown.next(value);
+		self.max_duration = self.max_duration.max(self.drawdown.duration());
+
+		self.current
+	}
+
+	/// Changes current equity by the relative return `value` and returns current drawdown
+	pub fn push_return(&mut self, value: ValueType) -> ValueType {
+		self.push_equity(self.equity * (1. + value))
+	}
+
+	/// Returns current equity
+	#[must_use]
+	pub const fn equity(&self) -> ValueType {
+		self.equity
+	}
+
+	/// Returns relative return of the current equity to the initial equity
+	#[must_use]
+	pub fn total_return(&self) -> ValueType {
+		self.equity / self.initial - 1.
+	}
+
+	/// Returns the highest equity so far
+	#[must_use]
+	pub const fn peak(&self) -> ValueType {
+		self.drawdown.peak()
+	}
+
+	/// Returns current relative drawdown
+	#[must_use]
+	pub const fn drawdown(&self) -> ValueType {
+		self.current
+	}
+
+	/// Returns maximum relative drawdown so far
+	#[must_use]
+	pub const fn max_drawdown(&self) -> ValueType {
+		self.drawdown.max_drawdown()
+	}
+
+	/// Returns count of updates since the last peak (`0` when current equity is the peak)
+	#[must_use]
+	pub const fn duration(&self) -> usize {
+		self.drawdown.duration()
+	}
+
+	/// Returns the longest count of updates spent below the peak so far
+	#[must_use]
+	pub const fn max_duration(&self) -> usize {
+		self.max_duration
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::EquityCurve;
+	use crate::backtest::{Backtest, SignalRule};
+	use crate::core::{Candle, IndicatorConfigDyn, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::RSI;
+
+	#[test]
+	fn test_equity_curve() {
+		let mut curve = EquityCurve::new(100.).unwrap();
+
+		for &value in &[110., 99., 105., 120., 108., 130.] {
+			curve.push_equity(value);
+		}
+
+		assert_eq_float(130., curve.peak());
+		assert_eq_float(0., curve.drawdown());
+		assert_eq_float(0.1, curve.max_drawdown());
+		assert_eq!(curve.duration(), 0);
+		assert_eq!(curve.max_duration(), 2);
+
+		assert_eq_float(0.5, curve.push_return(-0.5));
+		assert_eq_float(-0.35, curve.total_return());
+		assert_eq!(curve.duration(), 1);
+
+		assert!(EquityCurve::new(0.).is_err());
+	}
+
+	#[test]
+	fn test_equity_curve_report() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let config: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(RSI::default());
+		let mut indicators = vec![config.init(&candles[0]).unwrap()];
+
+		let report = Backtest::default()
+			.run(&candles, &mut indicators, SignalRule::default())
+			.unwrap();
+		let curve = EquityCurve::from_report(&report).unwrap();
+
+		let peak = report.equity.iter().fold(1., |a: ValueType, &b| a.max(b));
+		assert_eq_float(peak, curve.peak());
+		assert_eq_float(report.summary.total_return, curve.total_return());
+	}
+}
@@ -0,0 +1,49 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Performance metrics of trading strategies.
+//!
+//! [`Metrics`] consumes a series of returns (or a [`Report`](crate::backtest::Report) of the [`Backtest`](crate::backtest::Backtest))
+//! incrementally and calculates Sharpe, Sortino and Calmar ratios, maximum drawdown, exposure, profit factor and win rate.
+//!
+//! [`EquityCurve`] tracks current equity, its peak, current and maximum drawdown and underwater duration.
+//!
+//! [`CorrelationMatrix`] tracks rolling correlations and betas between returns of several symbols.
+//!
+//! [`Seasonality`] analysis calculates average returns and volatility by hour of day, day of week and month.
+//!
+//! [`MonteCarlo`] analysis resamples trades or returns of the backtest and estimates distributions of returns and drawdowns.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::prelude::*;
+//! use yata::prelude::dd::*;
+//! use yata::backtest::{Backtest, SignalRule};
+//! use yata::helpers::RandomCandles;
+//! use yata::indicators::RSI;
+//! use yata::metrics::Metrics;
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(200).collect();
+//! let rsi: Box<dyn IndicatorConfigDyn<_>> = Box::new(RSI::default());
+//! let mut indicators = vec![rsi.init(&candles[0]).unwrap()];
+//!
+//! let report = Backtest::default().run(&candles, &mut indicators, SignalRule::default()).unwrap();
+//! let metrics = Metrics::from_report(&report, 252.).unwrap();
+//!
+//! println!("Sharpe ratio: {}", metrics.sharpe());
+//! println!("Max drawdown: {}", metrics.max_drawdown());
+//! ```
+
+mod correlation_matrix;
+pub use correlation_matrix::CorrelationMatrix;
+
+mod equity_curve;
+pub use equity_curve::EquityCurve;
+
+mod monte_carlo;
+pub use monte_carlo::{Distribution, MonteCarlo, MonteCarloReport, Resampling};
+
+mod performance;
+pub use performance::Metrics;
+
+mod seasonality;
+pub use seasonality::{SeasonalStats, Seasonality};
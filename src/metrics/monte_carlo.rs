@@ -0,0 +1,340 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::cmp::Ordering;
+
+use super::EquityCurve;
+use crate::backtest::Report;
+use crate::core::{Error, ValueType};
+use crate::helpers::Rng;
+
+/// Resampling mode of the [`MonteCarlo`] analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Resampling {
+	/// Reshuffles the order of trades. Total return stays the same, only the path (and so the drawdown) changes.
+	Shuffle,
+
+	/// Samples trades with replacement.
+	Bootstrap,
+
+	/// Samples blocks of consecutive per-candle returns of the given length with replacement.
+	/// Blocks keep short-term dependency between returns.
+	Block(usize),
+}
+
+/// Sorted values of some metric over all the simulations
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Distribution(Vec<ValueType>);
+
+impl Distribution {
+	fn new(mut values: Vec<ValueType>) -> Self {
+		values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+		Self(values)
+	}
+
+	/// Returns sorted values
+	#[must_use]
+	pub fn values(&self) -> &[ValueType] {
+		&self.0
+	}
+
+	/// Returns mean value
+	#[must_use]
+	pub fn mean(&self) -> ValueType {
+		self.0.iter().sum::<ValueType>() / self.0.len() as ValueType
+	}
+
+	/// Returns `q`-th quantile (`q` in range \[`0.0`; `1.0`\]) with linear interpolation between values
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	pub fn quantile(&self, q: ValueType) -> ValueType {
+		let position = q.clamp(0., 1.) * (self.0.len() - 1) as ValueType;
+		let index = position.floor() as usize;
+		let next = (index + 1).min(self.0.len() - 1);
+
+		(self.0[next] - self.0[index]).mul_add(position.fract(), self.0[index])
+	}
+
+	/// Returns central `confidence` interval (e.g. `0.95` for 95% interval)
+	#[must_use]
+	pub fn interval(&self, confidence: ValueType) -> (ValueType, ValueType) {
+		let tail = (1. - confidence) / 2.;
+
+		(self.quantile(tail), self.quantile(1. - tail))
+	}
+}
+
+/// Result of the [`MonteCarlo`] analysis
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonteCarloReport {
+	/// Distribution of the compounded total return
+	pub total_return: Distribution,
+
+	/// Distribution of the compounded annual growth rate
+	pub annualized_return: Distribution,
+
+	/// Distribution of the maximum relative drawdown
+	pub max_drawdown: Distribution,
+}
+
+/// Monte Carlo analysis of the [`Backtest`](crate::backtest::Backtest) results
+///
+/// Simulates `simulations` alternative equity curves by resampling trades or per-candle returns of the report
+/// according to the `resampling` mode and returns distributions of total return, annual growth rate and maximum drawdown.
+///
+/// Every simulated curve spans the same count of candles as the report. Returns are annualized using `periods_per_year`.
+/// Random numbers are generated from the `seed`, so results are reproducible.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::prelude::dd::*;
+/// use yata::backtest::{Backtest, SignalRule};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RSI;
+/// use yata::metrics::{MonteCarlo, Resampling};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(300).collect();
+/// let rsi: Box<dyn IndicatorConfigDyn<_>> = Box::new(RSI::default());
+/// let mut indicators = vec![rsi.init(&candles[0]).unwrap()];
+///
+/// let report = Backtest::default().run(&candles, &mut indicators, SignalRule::default()).unwrap();
+///
+/// let monte_carlo = MonteCarlo {
+///     resampling: Resampling::Block(20),
+///     ..MonteCarlo::default()
+/// };
+/// let result = monte_carlo.run(&report).unwrap();
+///
+/// let (low, high) = result.max_drawdown.interval(0.95);
+/// println!("Max drawdown 95% interval: {} - {}", low, high);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonteCarlo {
+	/// Resampling mode. Default is [`Shuffle`](Resampling::Shuffle).
+	pub resampling: Resampling,
+
+	/// Count of simulations. Default is `1000`.
+	///
+	/// Range in \[`1`; `usize::MAX`\]
+	pub simulations: usize,
+
+	/// Count of candles per year. Default is `252.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub periods_per_year: ValueType,
+
+	/// Seed of the random numbers generator. Default is `0`.
+	pub seed: u64,
+}
+
+impl MonteCarlo {
+	/// Validates analysis parameters
+	#[must_use]
+	pub fn validate(&self) -> bool {
+		let resampling = match self.resampling {
+			Resampling::Block(length) => length > 0,
+			_ => true,
+		};
+
+		resampling
+			&& self.simulations > 0
+			&& self.periods_per_year.is_finite()
+			&& self.periods_per_year > 0.
+	}
+
+	/// Runs analysis over the `report`
+	pub fn run(&self, report: &Report) -> Result<MonteCarloReport, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let returns: Vec<_> = match self.resampling {
+			Resampling::Shuffle | Resampling::Bootstrap => report
+				.trades
+				.iter()
+				.map(|trade| trade.size * trade.return_ratio())
+				.collect(),
+			Resampling::Block(_) => {
+				let mut prev = 1.;
+				report
+					.equity
+					.iter()
+					.map(|&equity| {
+						let value = equity / prev - 1.;
+						prev = equity;
+						value
+					})
+					.collect()
+			}
+		};
+
+		let mut rng = Rng::new(self.seed);
+		let mut sample = returns.clone();
+		let mut total_return = Vec::with_capacity(self.simulations);
+		let mut annualized_return = Vec::with_capacity(self.simulations);
+		let mut max_drawdown = Vec::with_capacity(self.simulations);
+
+		for _ in 0..self.simulations {
+			self.resample(&returns, &mut sample, &mut rng);
+
+			let mut curve = EquityCurve::new(1.)?;
+			for &value in &sample {
+				curve.push_return(value);
+			}
+
+			total_return.push(curve.total_return());
+			annualized_return.push(self.annualize(curve.equity(), report.equity.len()));
+			max_drawdown.push(curve.max_drawdown());
+		}
+
+		Ok(MonteCarloReport {
+			total_return: Distribution::new(total_return),
+			annualized_return: Distribution::new(annualized_return),
+			max_drawdown: Distribution::new(max_drawdown),
+		})
+	}
+
+	fn resample(&self, returns: &[ValueType], sample: &mut [ValueType], rng: &mut Rng) {
+		let len = returns.len();
+
+		match self.resampling {
+			Resampling::Shuffle => {
+				// Fisher-Yates shuffle
+				for i in (1..len).rev() {
+					sample.swap(i, rng.index(i + 1));
+				}
+			}
+			Resampling::Bootstrap => {
+				for value in sample.iter_mut() {
+					*value = returns[rng.index(len)];
+				}
+			}
+			Resampling::Block(length) => {
+				for block in sample.chunks_mut(length) {
+					let start = rng.index(len);
+
+					for (i, value) in block.iter_mut().enumerate() {
+						*value = returns[(start + i) % len];
+					}
+				}
+			}
+		}
+	}
+
+	fn annualize(&self, equity: ValueType, periods: usize) -> ValueType {
+		if periods == 0 {
+			return 0.;
+		}
+
+		if equity <= 0. {
+			return -1.;
+		}
+
+		equity.powf(self.periods_per_year / periods as ValueType) - 1.
+	}
+}
+
+impl Default for MonteCarlo {
+	fn default() -> Self {
+		Self {
+			resampling: Resampling::Shuffle,
+			simulations: 1000,
+			periods_per_year: 252.,
+			seed: 0,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MonteCarlo, Resampling};
+	use crate::backtest::{Backtest, Report, Side, SignalRule, Trade};
+	use crate::core::{Candle, IndicatorConfigDyn, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::RSI;
+
+	fn report() -> Report {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let config: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(RSI::default());
+		let mut indicators = vec![config.init(&candles[0]).unwrap()];
+
+		Backtest::default()
+			.run(&candles, &mut indicators, SignalRule::default())
+			.unwrap()
+	}
+
+	#[test]
+	fn test_monte_carlo_shuffle() {
+		let trades = [0.1, -0.2, 0.3, -0.1]
+			.iter()
+			.enumerate()
+			.map(|(index, &ratio)| Trade {
+				side: Side::Long,
+				entry_index: index,
+				entry_price: 1.,
+				exit_index: index + 1,
+				exit_price: 1. + ratio,
+				size: 1.,
+				commission: 0.,
+			})
+			.collect();
+		let report = Report::new(trades, vec![1.; 5]);
+
+		let result = MonteCarlo {
+			simulations: 200,
+			..MonteCarlo::default()
+		}
+		.run(&report)
+		.unwrap();
+
+		for &value in result.total_return.values() {
+			assert_eq_float(report.summary.total_return, value);
+		}
+
+		// the deepest drawdown is two consecutive losses, the smallest one is the largest single loss
+		let drawdowns = result.max_drawdown.values();
+		assert_eq!(drawdowns.len(), 200);
+		assert_eq_float(0.2, drawdowns[0]);
+		assert_eq_float(ValueType::mul_add(-0.8, 0.9, 1.), drawdowns[199]);
+	}
+
+	#[test]
+	fn test_monte_carlo_block() {
+		let report = report();
+		let monte_carlo = MonteCarlo {
+			resampling: Resampling::Block(300),
+			simulations: 50,
+			..MonteCarlo::default()
+		};
+		let result = monte_carlo.run(&report).unwrap();
+
+		// a single block of the whole series may only be rotated
+		let (low, high) = result.total_return.interval(0.9);
+		assert_eq_float(report.summary.total_return, low);
+		assert_eq_float(report.summary.total_return, high);
+		assert_eq!(result, monte_carlo.run(&report).unwrap());
+
+		let monte_carlo = MonteCarlo {
+			resampling: Resampling::Block(0),
+			..monte_carlo
+		};
+		assert!(monte_carlo.run(&report).is_err());
+	}
+
+	#[test]
+	fn test_distribution_quantile() {
+		let distribution = super::Distribution::new(vec![3., 1., 4., 2.]);
+
+		assert_eq_float(1., distribution.quantile(0.));
+		assert_eq_float(2.5, distribution.quantile(0.5));
+		assert_eq_float(4., distribution.quantile(1.));
+		assert_eq_float(2.5, distribution.mean());
+	}
+}
@@ -0,0 +1,329 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::EquityCurve;
+use crate::backtest::Report;
+use crate::core::{Error, ValueType};
+
+/// Incremental performance metrics
+///
+/// Every period (e.g. candle) is added by [`push_return`](Metrics::push_return) with its relative return.
+/// Every closed trade is added by [`push_trade`](Metrics::push_trade) with its relative return.
+///
+/// Returns-based metrics (Sharpe, Sortino, Calmar ratios, drawdown and exposure) use periods,
+/// trades-based metrics (profit factor and win rate) use trades.
+///
+/// Ratios are annualized using `periods_per_year` (e.g. `252.0` for daily candles of the stock market)
+/// and assume zero risk-free rate.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Metrics {
+	periods_per_year: ValueType,
+
+	periods: usize,
+	exposed: usize,
+	sum: ValueType,
+	squares: ValueType,
+	downside_squares: ValueType,
+	curve: EquityCurve,
+
+	trades: usize,
+	wins: usize,
+	gross_profit: ValueType,
+	gross_loss: ValueType,
+}
+
+impl Metrics {
+	/// Creates empty metrics
+	///
+	/// `periods_per_year` must be positive.
+	pub fn new(periods_per_year: ValueType) -> Result<Self, Error> {
+		if !periods_per_year.is_finite() || periods_per_year <= 0. {
+			return Err(Error::WrongConfig);
+		}
+
+		Ok(Self {
+			periods_per_year,
+			periods: 0,
+			exposed: 0,
+			sum: 0.,
+			squares: 0.,
+			downside_squares: 0.,
+			curve: EquityCurve::new(1.)?,
+			trades: 0,
+			wins: 0,
+			gross_profit: 0.,
+			gross_loss: 0.,
+		})
+	}
+
+	/// Calculates metrics of the [`Backtest`](crate::backtest::Backtest) report
+	///
+	/// Returns an error when any trade's bars are out of the report's equity curve.
+	pub fn from_report(report: &Report, periods_per_year: ValueType) -> Result<Self, Error> {
+		let mut metrics = Self::new(periods_per_year)?;
+
+		let mut exposed = vec![false; report.equity.len()];
+		for trade in &report.trades {
+			exposed
+				.get_mut(trade.entry_index + 1..=trade.exit_index)
+				.ok_or_else(|| {
+					Error::Other("trade bars must be within the equity curve".to_string())
+				})?
+				.iter_mut()
+				.for_each(|x| *x = true);
+		}
+
+		let mut prev = 1.;
+		for (&equity, &exposed) in report.equity.iter().zip(&exposed) {
+			metrics.push_return(equity / prev - 1., exposed);
+			prev = equity;
+		}
+
+		for trade in &report.trades {
+			metrics.push_trade(trade.return_ratio());
+		}
+
+		Ok(metrics)
+	}
+
+	/// Adds relative return of the next period. `exposed` tells if there was an open position during the period.
+	pub fn push_return(&mut self, value: ValueType, exposed: bool) {
+		self.periods += 1;
+		self.exposed += exposed as usize;
+		self.sum += value;
+		self.squares = value.mul_add(value, self.squares);
+
+		let downside = value.min(0.);
+		self.downside_squares = downside.mul_add(downside, self.downside_squares);
+
+		self.curve.push_return(value);
+	}
+
+	/// Adds relative return of the next closed trade
+	pub fn push_trade(&mut self, value: ValueType) {
+		self.trades += 1;
+
+		if value > 0. {
+			self.wins += 1;
+			self.gross_profit += value;
+		} else {
+			self.gross_loss -= value;
+		}
+	}
+
+	/// Returns count of periods
+	#[must_use]
+	pub const fn periods(&self) -> usize {
+		self.periods
+	}
+
+	/// Returns count of trades
+	#[must_use]
+	pub const fn trades(&self) -> usize {
+		self.trades
+	}
+
+	/// Returns compounded relative return of all the periods
+	#[must_use]
+	pub fn total_return(&self) -> ValueType {
+		self.curve.total_return()
+	}
+
+	/// Returns compounded annual growth rate. `0.0` when there are no periods.
+	#[must_use]
+	pub fn annualized_return(&self) -> ValueType {
+		if self.periods == 0 {
+			return 0.;
+		}
+
+		let equity = self.curve.equity();
+
+		if equity <= 0. {
+			return -1.;
+		}
+
+		equity.powf(self.periods_per_year / self.periods as ValueType) - 1.
+	}
+
+	fn mean(&self) -> ValueType {
+		self.sum / self.periods as ValueType
+	}
+
+	/// Returns annualized standard deviation of returns. `0.0` when there are less than 2 periods.
+	#[must_use]
+	pub fn volatility(&self) -> ValueType {
+		if self.periods < 2 {
+			return 0.;
+		}
+
+		let n = self.periods as ValueType;
+		let variance = self.mean().mul_add(-self.sum, self.squares) / (n - 1.);
+
+		(variance.max(0.) * self.periods_per_year).sqrt()
+	}
+
+	/// Returns annualized Sharpe ratio. `0.0` when volatility is `0.0`.
+	#[must_use]
+	pub fn sharpe(&self) -> ValueType {
+		let volatility = self.volatility();
+
+		if volatility == 0. {
+			return 0.;
+		}
+
+		self.mean() * self.periods_per_year / volatility
+	}
+
+	/// Returns annualized Sortino ratio. `0.0` when there are no negative returns.
+	#[must_use]
+	pub fn sortino(&self) -> ValueType {
+		if self.downside_squares == 0. {
+			return 0.;
+		}
+
+		let downside =
+			(self.downside_squares / self.periods as ValueType * self.periods_per_year).sqrt();
+
+		self.mean() * self.periods_per_year / downside
+	}
+
+	/// Returns Calmar ratio: annualized return divided by maximum drawdown. `0.0` when there were no drawdowns.
+	#[must_use]
+	pub fn calmar(&self) -> ValueType {
+		let max_drawdown = self.max_drawdown();
+
+		if max_drawdown == 0. {
+			return 0.;
+		}
+
+		self.annualized_return() / max_drawdown
+	}
+
+	/// Returns maximum relative drawdown of the equity
+	#[must_use]
+	pub const fn max_drawdown(&self) -> ValueType {
+		self.curve.max_drawdown()
+	}
+
+	/// Returns ratio of periods with an open position to all periods. `0.0` when there are no periods.
+	#[must_use]
+	pub fn exposure(&self) -> ValueType {
+		if self.periods == 0 {
+			return 0.;
+		}
+
+		self.exposed as ValueType / self.periods as ValueType
+	}
+
+	/// Returns gross profit of winning trades divided by gross loss of losing trades.
+	///
+	/// When there are no losing trades, returns [`INFINITY`](ValueType::INFINITY) if there are winning trades and `0.0` otherwise.
+	#[must_use]
+	pub fn profit_factor(&self) -> ValueType {
+		if self.gross_loss == 0. {
+			return if self.gross_profit > 0. {
+				ValueType::INFINITY
+			} else {
+				0.
+			};
+		}
+
+		self.gross_profit / self.gross_loss
+	}
+
+	/// Returns ratio of winning trades to all trades. `0.0` when there are no trades.
+	#[must_use]
+	pub fn win_rate(&self) -> ValueType {
+		if self.trades == 0 {
+			return 0.;
+		}
+
+		self.wins as ValueType / self.trades as ValueType
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Metrics;
+	use crate::backtest::{Backtest, SignalRule, Trade};
+	use crate::core::{Candle, IndicatorConfigDyn, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::RSI;
+
+	#[test]
+	fn test_metrics_returns() {
+		let returns = [0.1, -0.05, 0.02, -0.1, 0.03];
+		let mut metrics = Metrics::new(1.).unwrap();
+
+		for (i, &value) in returns.iter().enumerate() {
+			metrics.push_return(value, i != 2);
+		}
+
+		let n = returns.len() as ValueType;
+		let mean = returns.iter().sum::<ValueType>() / n;
+		let std = (returns
+			.iter()
+			.map(|x| (x - mean).powi(2))
+			.sum::<ValueType>()
+			/ (n - 1.))
+			.sqrt();
+		let downside = (returns.iter().map(|x| x.min(0.).powi(2)).sum::<ValueType>() / n).sqrt();
+		let equity: ValueType = returns.iter().map(|x| 1. + x).product();
+
+		assert_eq_float(mean / std, metrics.sharpe());
+		assert_eq_float(mean / downside, metrics.sortino());
+		assert_eq_float(equity - 1., metrics.total_return());
+		assert_eq_float(equity.powf(1. / n) - 1., metrics.annualized_return());
+		assert_eq_float(
+			ValueType::mul_add(0.95 * 1.02, -0.9, 1.),
+			metrics.max_drawdown(),
+		);
+		assert_eq_float(0.8, metrics.exposure());
+	}
+
+	#[test]
+	fn test_metrics_trades() {
+		let mut metrics = Metrics::new(252.).unwrap();
+		assert_eq_float(0., metrics.profit_factor());
+
+		metrics.push_trade(0.3);
+		assert!(metrics.profit_factor().is_infinite());
+
+		metrics.push_trade(-0.1);
+		metrics.push_trade(-0.05);
+		metrics.push_trade(0.0);
+
+		assert_eq_float(2., metrics.profit_factor());
+		assert_eq_float(0.25, metrics.win_rate());
+		assert!(Metrics::new(0.).is_err());
+	}
+
+	#[test]
+	fn test_metrics_report() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let config: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(RSI::default());
+		let mut indicators = vec![config.init(&candles[0]).unwrap()];
+
+		let mut report = Backtest::default()
+			.run(&candles, &mut indicators, SignalRule::default())
+			.unwrap();
+		let metrics = Metrics::from_report(&report, 252.).unwrap();
+
+		assert_eq!(metrics.periods(), candles.len());
+		assert_eq!(metrics.trades(), report.trades.len());
+		assert_eq_float(report.summary.total_return, metrics.total_return());
+		assert_eq_float(report.summary.win_rate, metrics.win_rate());
+
+		let bars: usize = report.trades.iter().map(Trade::bars).sum();
+		assert_eq_float(
+			bars as ValueType / candles.len() as ValueType,
+			metrics.exposure(),
+		);
+
+		let mut trade = report.trades[0];
+		trade.exit_index = candles.len();
+		report.trades.push(trade);
+		assert!(Metrics::from_report(&report, 252.).is_err());
+	}
+}
@@ -0,0 +1,264 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, ValueType, OHLCV};
+use crate::helpers::DateTime;
+
+/// Statistics of returns which fall into a single seasonal bucket of the [`Seasonality`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeasonalStats {
+	count: usize,
+	mean: ValueType,
+	moment: ValueType,
+}
+
+impl SeasonalStats {
+	/// Adds next `value` to the bucket
+	pub fn push(&mut self, value: ValueType) {
+		// Welford's algorithm
+		self.count += 1;
+		let delta = value - self.mean;
+		self.mean += delta / self.count as ValueType;
+		self.moment += delta * (value - self.mean);
+	}
+
+	/// Returns count of returns in the bucket
+	#[must_use]
+	pub const fn count(&self) -> usize {
+		self.count
+	}
+
+	/// Returns average return of the bucket
+	#[must_use]
+	pub const fn mean(&self) -> ValueType {
+		self.mean
+	}
+
+	/// Returns volatility (sample standard deviation of returns) of the bucket
+	///
+	/// Returns `0.0` when there are less than `2` returns in the bucket.
+	#[must_use]
+	pub fn volatility(&self) -> ValueType {
+		if self.count < 2 {
+			return 0.;
+		}
+
+		(self.moment.max(0.) / (self.count - 1) as ValueType).sqrt()
+	}
+}
+
+/// Seasonality analysis
+///
+/// Collects close-to-close returns of timestamped candles into buckets by hour of day, day of week and month
+/// and calculates average return and volatility of every bucket.
+///
+/// Timestamps are Unix timestamps in seconds and are interpreted in UTC (see [`DateTime`]).
+/// Every return is assigned to the timestamp of the candle it ends at.
+///
+/// [`bias`](Seasonality::bias) returns seasonal bias of any moment, which may be used as an input of methods and indicators.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::RandomCandles;
+/// use yata::metrics::Seasonality;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(500).collect();
+/// // hourly candles
+/// let timestamps: Vec<i64> = (0..500).map(|i| 1_600_000_000 + i * 3600).collect();
+///
+/// let seasonality = Seasonality::from_candles(&candles, &timestamps).unwrap();
+///
+/// for hour in 0..24 {
+///     let stats = seasonality.hour(hour);
+///     println!("{}: {} ± {}", hour, stats.mean(), stats.volatility());
+/// }
+///
+/// let bias = seasonality.biases(&timestamps);
+/// assert_eq!(bias.len(), candles.len());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Seasonality {
+	hours: Vec<SeasonalStats>,
+	weekdays: Vec<SeasonalStats>,
+	months: Vec<SeasonalStats>,
+}
+
+impl Seasonality {
+	/// Creates new empty seasonality analysis
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			hours: vec![SeasonalStats::default(); 24],
+			weekdays: vec![SeasonalStats::default(); 7],
+			months: vec![SeasonalStats::default(); 12],
+		}
+	}
+
+	/// Creates seasonality analysis over the `candles` with corresponding `timestamps`
+	///
+	/// Returns an error when count of `timestamps` differs from count of `candles`.
+	pub fn from_candles<T: OHLCV>(candles: &[T], timestamps: &[i64]) -> Result<Self, Error> {
+		if candles.len() != timestamps.len() {
+			return Err(Error::Other(
+				"count of timestamps must be equal to count of candles".to_string(),
+			));
+		}
+
+		let mut seasonality = Self::new();
+
+		for (pair, &timestamp) in candles.windows(2).zip(&timestamps[1..]) {
+			let prev = pair[0].close();
+
+			if prev > 0. {
+				seasonality.push(timestamp, pair[1].close() / prev - 1.);
+			}
+		}
+
+		Ok(seasonality)
+	}
+
+	/// Adds `value` of return at the `timestamp`
+	pub fn push(&mut self, timestamp: i64, value: ValueType) {
+		let datetime = DateTime::from_timestamp(timestamp);
+
+		self.hours[datetime.hour as usize].push(value);
+		self.weekdays[datetime.weekday as usize].push(value);
+		self.months[datetime.month as usize - 1].push(value);
+	}
+
+	/// Returns statistics of the `hour` of day in range \[`0`; `23`\]
+	///
+	/// # Panics
+	///
+	/// Panics when `hour` is out of range.
+	#[must_use]
+	pub fn hour(&self, hour: u8) -> &SeasonalStats {
+		&self.hours[hour as usize]
+	}
+
+	/// Returns statistics of the `weekday` in range \[`0`; `6`\], where `0` is Monday
+	///
+	/// # Panics
+	///
+	/// Panics when `weekday` is out of range.
+	#[must_use]
+	pub fn weekday(&self, weekday: u8) -> &SeasonalStats {
+		&self.weekdays[weekday as usize]
+	}
+
+	/// Returns statistics of the `month` in range \[`1`; `12`\]
+	///
+	/// # Panics
+	///
+	/// Panics when `month` is out of range.
+	#[must_use]
+	pub fn month(&self, month: u8) -> &SeasonalStats {
+		&self.months[month as usize - 1]
+	}
+
+	/// Returns seasonal bias at the `timestamp`
+	///
+	/// Seasonal bias is the average of mean returns of the hour, the weekday and the month buckets of the `timestamp`.
+	/// Empty buckets are not taken into account. When all the buckets are empty, returns `0.0`.
+	#[must_use]
+	pub fn bias(&self, timestamp: i64) -> ValueType {
+		let datetime = DateTime::from_timestamp(timestamp);
+
+		let buckets = [
+			self.hours[datetime.hour as usize],
+			self.weekdays[datetime.weekday as usize],
+			self.months[datetime.month as usize - 1],
+		];
+
+		let (sum, count) = buckets
+			.iter()
+			.filter(|stats| stats.count > 0)
+			.fold((0., 0), |(sum, count), stats| (sum + stats.mean, count + 1));
+
+		if count > 0 {
+			sum / count as ValueType
+		} else {
+			0.
+		}
+	}
+
+	/// Returns seasonal bias at every timestamp of `timestamps`
+	#[must_use]
+	pub fn biases(&self, timestamps: &[i64]) -> Vec<ValueType> {
+		timestamps
+			.iter()
+			.map(|&timestamp| self.bias(timestamp))
+			.collect()
+	}
+}
+
+impl Default for Seasonality {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Seasonality;
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_seasonality() {
+		// 2021-03-01 00:00:00, Monday
+		let start = 1_614_556_800;
+		let timestamps: Vec<i64> = (0..24 * 14).map(|i| start + i * 3600).collect();
+
+		// price rises by 1% at 10:00 and falls by 0.5% at 20:00 every day
+		let mut close = 100.;
+		let candles: Vec<_> = timestamps
+			.iter()
+			.map(|&timestamp| {
+				match timestamp / 3600 % 24 {
+					10 => close *= 1.01,
+					20 => close *= 0.995,
+					_ => {}
+				}
+
+				Candle {
+					close,
+					..Candle::default()
+				}
+			})
+			.collect();
+
+		let seasonality = Seasonality::from_candles(&candles, &timestamps).unwrap();
+
+		assert_eq!(seasonality.hour(10).count(), 14);
+		assert_eq_float(0.01, seasonality.hour(10).mean());
+		assert_eq_float(-0.005, seasonality.hour(20).mean());
+		assert_eq_float(0., seasonality.hour(10).volatility());
+
+		// the very first candle has no return
+		assert_eq!(seasonality.hour(0).count(), 13);
+		assert_eq!(seasonality.weekday(0).count(), 47);
+		assert_eq_float(0.005 / 24., seasonality.weekday(2).mean());
+		assert!(seasonality.weekday(2).volatility() > 0.);
+		assert_eq!(seasonality.month(3).count(), 24 * 14 - 1);
+		assert_eq!(seasonality.month(4).count(), 0);
+
+		let month = seasonality.month(3).mean();
+		let weekday = seasonality.weekday(2).mean();
+		assert_eq_float(
+			(0.01 + weekday + month) / 3.,
+			seasonality.bias(start + 2 * 86_400 + 10 * 3600),
+		);
+
+		// April bucket is empty and is not taken into account
+		assert_eq_float(
+			ValueType::midpoint(0.01, seasonality.weekday(3).mean()),
+			seasonality.bias(1_617_271_200),
+		);
+
+		assert!(Seasonality::from_candles(&candles, &timestamps[1..]).is_err());
+	}
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::cmp::Ordering;
+
+use crate::backtest::{Backtest, Report, SignalRule};
+use crate::core::{Error, IndicatorConfigDyn, ValueType, OHLCV};
+
+/// List of parameters names and their values
+pub type Params = Vec<(String, String)>;
+
+/// Result of evaluation of some set of parameters
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Evaluation {
+	/// Evaluated parameters
+	pub params: Params,
+
+	/// Value of the objective function
+	pub score: ValueType,
+
+	/// Backtest report
+	pub report: Report,
+}
+
+/// Applies `params` to the `config`, passes `warmup` candles into the indicator and runs the `backtest` over the `candles`
+///
+/// Returns `Ok(None)` when parameters make invalid configuration.
+pub(super) fn evaluate<C, T, F>(
+	config: &C,
+	params: Params,
+	backtest: &Backtest,
+	rule: SignalRule,
+	warmup: &[T],
+	candles: &[T],
+	objective: &F,
+) -> Result<Option<Evaluation>, Error>
+where
+	C: IndicatorConfigDyn<T> + Clone,
+	T: OHLCV,
+	F: Fn(&Report) -> ValueType,
+{
+	let mut config = config.clone();
+
+	for (name, value) in &params {
+		config.set(name, value.clone())?;
+	}
+
+	let first = match warmup.first().or_else(|| candles.first()) {
+		Some(candle) if config.validate() => candle,
+		_ => return Ok(None),
+	};
+
+	let mut indicator = config.init(first)?;
+	for candle in warmup {
+		indicator.next(candle);
+	}
+
+	let mut indicators = [indicator];
+	let report = backtest.run(candles, &mut indicators, rule)?;
+
+	Ok(Some(Evaluation {
+		params,
+		score: objective(&report),
+		report,
+	}))
+}
+
+/// Sorts evaluations by descending score
+pub(super) fn rank(evaluations: &mut [Evaluation]) {
+	evaluations.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+}
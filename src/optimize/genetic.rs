@@ -0,0 +1,373 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::evaluation::{evaluate, rank};
+use super::{Evaluation, Grid};
+use crate::backtest::{Backtest, Report, SignalRule};
+use crate::core::{Error, IndicatorConfigDyn, ValueType, OHLCV};
+use crate::helpers::Rng;
+
+type Genome = Vec<usize>;
+
+/// Genetic parameters optimizer
+///
+/// Evolutionary alternative to the [`GridSearch`](crate::optimize::GridSearch) for large grids of parameters.
+/// Instead of evaluating every combination of the `grid`, evolves a `population` of combinations over `generations`:
+///
+/// * `elite` best combinations pass into the next generation unchanged;
+/// * the rest of the next generation are children of parents chosen by tournament selection;
+/// * child takes every parameter value from one of the parents at random with `crossover` probability,
+///   otherwise it is a copy of the first parent;
+/// * every parameter value of the child is replaced by a random value from the `grid` with `mutation` probability.
+///
+/// Every distinct combination is evaluated only once. Combinations which make invalid configuration or `NaN` score
+/// are never selected as parents nor pass into the next generation as elite. When there are no other combinations in generation,
+/// the next generation is chosen at random.
+/// Random numbers are generated from the `seed`, so results are reproducible.
+///
+/// When `rayon` feature is enabled, every generation is evaluated in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+/// use yata::optimize::{Genetic, Grid};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(300).collect();
+///
+/// let genetic = Genetic {
+///     config: MACD::default(),
+///     grid: Grid::new()
+///         .add("period1", 5..20)
+///         .add("period2", 20..40)
+///         .add("period3", 5..15),
+///     population: 10,
+///     generations: 5,
+///     ..Genetic::default()
+/// };
+///
+/// let results = genetic.run(&candles, |report| report.summary.total_return).unwrap();
+///
+/// // the best parameters come first
+/// println!("{:?}: {}", results[0].params, results[0].score);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Genetic<C> {
+	/// Base indicator configuration
+	pub config: C,
+
+	/// Space of parameters to apply to the base configuration
+	pub grid: Grid,
+
+	/// Backtest parameters
+	pub backtest: Backtest,
+
+	/// Signal to trade by. Default is the first signal.
+	pub rule: SignalRule,
+
+	/// Count of combinations in every generation. Default is `20`.
+	///
+	/// Range in \[`2`; `usize::MAX`\]
+	pub population: usize,
+
+	/// Count of generations after the initial one. Default is `10`.
+	pub generations: usize,
+
+	/// Probability of crossover. Default is `0.8`.
+	///
+	/// Range in \[`0.0`; `1.0`\]
+	pub crossover: ValueType,
+
+	/// Probability of mutation of every parameter. Default is `0.1`.
+	///
+	/// Range in \[`0.0`; `1.0`\]
+	pub mutation: ValueType,
+
+	/// Count of the best combinations passed into the next generation unchanged. Default is `2`.
+	///
+	/// Range in \[`0`; `population`\)
+	pub elite: usize,
+
+	/// Seed of the random numbers generator. Default is `0`.
+	pub seed: u64,
+}
+
+impl<C> Genetic<C> {
+	/// Validates optimizer parameters
+	#[must_use]
+	pub fn validate(&self) -> bool {
+		self.population > 1
+			&& (0. ..=1.).contains(&self.crossover)
+			&& (0. ..=1.).contains(&self.mutation)
+			&& self.elite < self.population
+	}
+
+	/// Evolves combinations of parameters over the `candles` and returns all the evaluated combinations ranked by the `objective` function
+	///
+	/// The best evaluation (with the greatest `objective` value) comes first.
+	pub fn run<T, F>(&self, candles: &[T], objective: F) -> Result<Vec<Evaluation>, Error>
+	where
+		C: IndicatorConfigDyn<T> + Clone + Send + Sync,
+		T: OHLCV + Sync,
+		F: Fn(&Report) -> ValueType + Sync,
+	{
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let sizes = self.grid.sizes();
+		if self.grid.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let mut rng = Rng::new(self.seed);
+		let mut scores = HashMap::new();
+		let mut evaluations = Vec::new();
+
+		let mut population: Vec<Genome> = (0..self.population)
+			.map(|_| sizes.iter().map(|&size| rng.index(size)).collect())
+			.collect();
+
+		for generation in 0..=self.generations {
+			let mut fresh: Vec<_> = population
+				.iter()
+				.filter(|genome| !scores.contains_key(*genome))
+				.cloned()
+				.collect();
+			fresh.sort_unstable();
+			fresh.dedup();
+
+			let results = self.evaluate(&fresh, candles, &objective)?;
+
+			for (genome, result) in fresh.into_iter().zip(results) {
+				let score = result
+					.as_ref()
+					.map(|evaluation| evaluation.score)
+					.filter(|score| !score.is_nan())
+					.unwrap_or(ValueType::NEG_INFINITY);
+
+				scores.insert(genome, score);
+				evaluations.extend(result);
+			}
+
+			if generation < self.generations {
+				population = self.breed(&population, &scores, &sizes, &mut rng);
+			}
+		}
+
+		rank(&mut evaluations);
+
+		Ok(evaluations)
+	}
+
+	fn evaluate<T, F>(
+		&self,
+		genomes: &[Genome],
+		candles: &[T],
+		objective: &F,
+	) -> Result<Vec<Option<Evaluation>>, Error>
+	where
+		C: IndicatorConfigDyn<T> + Clone + Send + Sync,
+		T: OHLCV + Sync,
+		F: Fn(&Report) -> ValueType + Sync,
+	{
+		#[cfg(feature = "rayon")]
+		let genomes = genomes.par_iter();
+		#[cfg(not(feature = "rayon"))]
+		let genomes = genomes.iter();
+
+		genomes
+			.map(|genome| {
+				evaluate(
+					&self.config,
+					self.grid.pick(genome),
+					&self.backtest,
+					self.rule,
+					&[],
+					candles,
+					objective,
+				)
+			})
+			.collect()
+	}
+
+	fn breed(
+		&self,
+		population: &[Genome],
+		scores: &HashMap<Genome, ValueType>,
+		sizes: &[usize],
+		rng: &mut Rng,
+	) -> Vec<Genome> {
+		let compare =
+			|a: &Genome, b: &Genome| scores[b].partial_cmp(&scores[a]).unwrap_or(Ordering::Equal);
+
+		let mut ranked: Vec<_> = population
+			.iter()
+			.filter(|genome| scores[*genome] > ValueType::NEG_INFINITY)
+			.cloned()
+			.collect();
+		ranked.sort_by(compare);
+
+		if ranked.is_empty() {
+			return (0..self.population)
+				.map(|_| sizes.iter().map(|&size| rng.index(size)).collect())
+				.collect();
+		}
+
+		let mut next: Vec<_> = ranked.iter().take(self.elite).cloned().collect();
+
+		while next.len() < self.population {
+			let first = Self::select(&ranked, scores, rng);
+			let second = Self::select(&ranked, scores, rng);
+
+			let mut child: Genome = if rng.value() < self.crossover {
+				first
+					.iter()
+					.zip(second)
+					.map(|(&a, &b)| if rng.value() < 0.5 { a } else { b })
+					.collect()
+			} else {
+				first.clone()
+			};
+
+			for (gene, &size) in child.iter_mut().zip(sizes) {
+				if rng.value() < self.mutation {
+					*gene = rng.index(size);
+				}
+			}
+
+			next.push(child);
+		}
+
+		next
+	}
+
+	/// Tournament selection of two random genomes
+	fn select<'a>(
+		population: &'a [Genome],
+		scores: &HashMap<Genome, ValueType>,
+		rng: &mut Rng,
+	) -> &'a Genome {
+		let a = &population[rng.index(population.len())];
+		let b = &population[rng.index(population.len())];
+
+		if scores[b] > scores[a] {
+			b
+		} else {
+			a
+		}
+	}
+}
+
+impl<C: Default> Default for Genetic<C> {
+	fn default() -> Self {
+		Self {
+			config: C::default(),
+			grid: Grid::default(),
+			backtest: Backtest::default(),
+			rule: SignalRule::default(),
+			population: 20,
+			generations: 10,
+			crossover: 0.8,
+			mutation: 0.1,
+			elite: 2,
+			seed: 0,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Genetic;
+	use crate::core::ValueType;
+	use crate::helpers::{RandomCandles, Rng};
+	use crate::indicators::RSI;
+	use crate::optimize::{Grid, GridSearch};
+
+	use std::collections::HashMap;
+
+	#[test]
+	fn test_genetic() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let grid = Grid::new()
+			.add("period", 1..30)
+			.add("zone", [0.1, 0.2, 0.3, 0.4]);
+
+		let genetic = Genetic {
+			config: RSI::default(),
+			grid: grid.clone(),
+			population: 8,
+			generations: 4,
+			..Genetic::default()
+		};
+		let results = genetic
+			.run(&candles, |report| report.summary.total_return)
+			.unwrap();
+
+		assert!(!results.is_empty());
+		assert!(results.len() <= 8 * 5);
+		assert_eq!(
+			results,
+			genetic
+				.run(&candles, |report| report.summary.total_return)
+				.unwrap()
+		);
+
+		for pair in results.windows(2) {
+			assert!(pair[0].score >= pair[1].score);
+			assert_ne!(pair[0].params, pair[1].params);
+		}
+
+		let best = GridSearch {
+			config: RSI::default(),
+			grid,
+			..GridSearch::default()
+		}
+		.run(&candles, |report| report.summary.total_return)
+		.unwrap();
+
+		assert!(results[0].score <= best[0].score);
+		let same = best.iter().find(|x| x.params == results[0].params).unwrap();
+		assert_eq!(same.report, results[0].report);
+
+		let genetic = Genetic {
+			elite: 8,
+			..genetic
+		};
+		assert!(!genetic.validate());
+	}
+
+	#[test]
+	fn test_genetic_skips_invalid() {
+		let genetic: Genetic<RSI> = Genetic {
+			population: 6,
+			elite: 2,
+			crossover: 0.,
+			mutation: 0.,
+			..Genetic::default()
+		};
+
+		let population = vec![vec![0], vec![1], vec![2], vec![3]];
+		let mut scores: HashMap<_, _> = population
+			.iter()
+			.map(|genome| (genome.clone(), ValueType::NEG_INFINITY))
+			.collect();
+		scores.insert(vec![2], -1.);
+
+		let mut rng = Rng::new(0);
+		let next = genetic.breed(&population, &scores, &[4], &mut rng);
+
+		assert_eq!(next.len(), 6);
+		assert!(next.iter().all(|genome| genome == &[2]));
+
+		scores.insert(vec![2], ValueType::NEG_INFINITY);
+		let next = genetic.breed(&population, &scores, &[4], &mut rng);
+
+		assert_eq!(next.len(), 6);
+		assert!(next.iter().all(|genome| genome.len() == 1 && genome[0] < 4));
+	}
+}
@@ -0,0 +1,235 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::evaluation::{evaluate, rank};
+use super::{Evaluation, Params};
+use crate::backtest::{Backtest, Report, SignalRule};
+use crate::core::{Error, IndicatorConfigDyn, ValueType, OHLCV};
+
+/// Grid of parameters values
+///
+/// Every parameter has a list of values. Grid consists of all the combinations of parameters values.
+///
+/// # Examples
+///
+/// ```
+/// use yata::optimize::Grid;
+///
+/// let grid = Grid::new()
+///     .add("period1", &[12, 24])
+///     .add("period2", (20..=40).step_by(10));
+///
+/// assert_eq!(grid.len(), 6);
+///
+/// let combinations: Vec<_> = grid.iter().collect();
+/// assert_eq!(combinations[1], vec![("period1".to_string(), "24".to_string()), ("period2".to_string(), "20".to_string())]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Grid {
+	params: Vec<(String, Vec<String>)>,
+}
+
+impl Grid {
+	/// Creates empty grid
+	///
+	/// Empty grid has the only combination with no parameters.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { params: Vec::new() }
+	}
+
+	/// Adds parameter `name` with the list of its `values`
+	#[must_use]
+	pub fn add<V, I>(mut self, name: &str, values: I) -> Self
+	where
+		V: ToString,
+		I: IntoIterator<Item = V>,
+	{
+		let values = values.into_iter().map(|value| value.to_string()).collect();
+		self.params.push((name.to_string(), values));
+		self
+	}
+
+	/// Returns count of combinations
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.params.iter().map(|(_, values)| values.len()).product()
+	}
+
+	/// Checks if there are no combinations
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns combination of parameters with the given `index`
+	///
+	/// The first parameter changes the fastest.
+	#[must_use]
+	pub fn get(&self, index: usize) -> Option<Params> {
+		if index >= self.len() {
+			return None;
+		}
+
+		let mut rest = index;
+		let params = self
+			.params
+			.iter()
+			.map(|(name, values)| {
+				let value = values[rest % values.len()].clone();
+				rest /= values.len();
+				(name.clone(), value)
+			})
+			.collect();
+
+		Some(params)
+	}
+
+	/// Returns count of values of every parameter
+	pub(super) fn sizes(&self) -> Vec<usize> {
+		self.params.iter().map(|(_, values)| values.len()).collect()
+	}
+
+	/// Returns combination of parameters with the given index of value of every parameter
+	pub(super) fn pick(&self, indexes: &[usize]) -> Params {
+		self.params
+			.iter()
+			.zip(indexes)
+			.map(|((name, values), &index)| (name.clone(), values[index].clone()))
+			.collect()
+	}
+
+	/// Returns an iterator over all the combinations of parameters
+	pub fn iter(&self) -> impl Iterator<Item = Params> + '_ {
+		(0..self.len()).filter_map(move |index| self.get(index))
+	}
+}
+
+/// Parameters grid search
+///
+/// Evaluates every combination of the `grid` parameters applied to the `config` by the `backtest`.
+/// Backtest trades by the `rule` signal of the indicator (the only signal for [strategies](crate::core::Strategy)).
+///
+/// Combinations which make invalid configuration are skipped. Unknown parameters or unparsable values produce an error.
+///
+/// See [module level documentation](crate::optimize) for example.
+#[derive(Debug, Clone, Default)]
+pub struct GridSearch<C> {
+	/// Base indicator configuration
+	pub config: C,
+
+	/// Grid of parameters to apply to the base configuration
+	pub grid: Grid,
+
+	/// Backtest parameters
+	pub backtest: Backtest,
+
+	/// Signal to trade by. Default is the first signal.
+	pub rule: SignalRule,
+}
+
+impl<C> GridSearch<C> {
+	/// Evaluates every combination of parameters over the `candles` and returns evaluations ranked by the `objective` function
+	///
+	/// The best evaluation (with the greatest `objective` value) comes first.
+	pub fn run<T, F>(&self, candles: &[T], objective: F) -> Result<Vec<Evaluation>, Error>
+	where
+		C: IndicatorConfigDyn<T> + Clone + Send + Sync,
+		T: OHLCV + Sync,
+		F: Fn(&Report) -> ValueType + Sync,
+	{
+		let indices = 0..self.grid.len();
+
+		#[cfg(feature = "rayon")]
+		let indices = indices.into_par_iter();
+
+		let evaluations: Vec<_> = indices
+			.filter_map(|index| self.grid.get(index))
+			.map(|params| {
+				evaluate(
+					&self.config,
+					params,
+					&self.backtest,
+					self.rule,
+					&[],
+					candles,
+					&objective,
+				)
+			})
+			.collect::<Result<_, _>>()?;
+
+		let mut evaluations: Vec<_> = evaluations.into_iter().flatten().collect();
+		rank(&mut evaluations);
+
+		Ok(evaluations)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Grid, GridSearch};
+	use crate::core::IndicatorConfig;
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{Trix, RSI};
+
+	#[test]
+	fn test_grid() {
+		let grid = Grid::new().add("a", [1, 2, 3]).add("b", ["x", "y"]);
+		assert_eq!(grid.len(), 6);
+
+		let combinations: Vec<_> = grid.iter().collect();
+		assert_eq!(combinations.len(), 6);
+		assert_eq!(combinations[4][0].1, "2");
+		assert_eq!(combinations[4][1].1, "y");
+		assert!(grid.get(6).is_none());
+
+		assert_eq!(Grid::new().len(), 1);
+		assert!(Grid::new().add("a", Vec::<u8>::new()).is_empty());
+	}
+
+	#[test]
+	fn test_grid_search() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		let search = GridSearch {
+			config: RSI::default(),
+			grid: Grid::new().add("period", [0, 7, 14, 21]),
+			..GridSearch::default()
+		};
+		let results = search
+			.run(&candles, |report| report.summary.total_return)
+			.unwrap();
+
+		// period `0` is invalid
+		assert_eq!(results.len(), 3);
+
+		for pair in results.windows(2) {
+			assert!(pair[0].score >= pair[1].score);
+		}
+
+		for result in &results {
+			let mut config = RSI::default();
+			config.set("period", result.params[0].1.clone()).unwrap();
+
+			let expected = GridSearch {
+				config,
+				..GridSearch::default()
+			}
+			.run(&candles, |report| report.summary.total_return)
+			.unwrap();
+
+			assert_eq!(expected[0].report, result.report);
+		}
+
+		let search = GridSearch {
+			config: Trix::default(),
+			grid: Grid::new().add("unknown", [1]),
+			..GridSearch::default()
+		};
+		assert!(search.run(&candles, |_| 0.).is_err());
+	}
+}
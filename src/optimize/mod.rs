@@ -0,0 +1,54 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Optimization of indicators parameters.
+//!
+//! Parameters are changed by their names through [`set`](crate::core::IndicatorConfig::set), the same way as
+//! for dynamically configured indicators. Every set of parameters is evaluated by the [`Backtest`](crate::backtest::Backtest)
+//! and scored by some objective function of its [`Report`](crate::backtest::Report).
+//!
+//! [`GridSearch`] sweeps all the combinations of parameters values of the [`Grid`] and returns ranked [`Evaluation`]s.
+//!
+//! [`Genetic`] optimizer evolves a population of combinations of the [`Grid`] instead of evaluating all of them,
+//! which is useful for large grids of many parameters.
+//!
+//! [`WalkForward`] analysis optimizes parameters over rolling in-sample ranges of candles and tests them over the following
+//! out-of-sample ranges.
+//!
+//! When `rayon` feature is enabled, combinations are evaluated in parallel.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::backtest::Backtest;
+//! use yata::helpers::RandomCandles;
+//! use yata::indicators::RSI;
+//! use yata::optimize::{Grid, GridSearch};
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(300).collect();
+//!
+//! let search = GridSearch {
+//!     config: RSI::default(),
+//!     grid: Grid::new()
+//!         .add("period", (7..=21).step_by(7))
+//!         .add("zone", &[0.2, 0.3]),
+//!     backtest: Backtest::default(),
+//!     ..GridSearch::default()
+//! };
+//!
+//! let results = search.run(&candles, |report| report.summary.total_return).unwrap();
+//! assert_eq!(results.len(), 6);
+//!
+//! // the best parameters come first
+//! println!("{:?}: {}", results[0].params, results[0].score);
+//! ```
+
+mod evaluation;
+pub use evaluation::{Evaluation, Params};
+
+mod genetic;
+pub use genetic::Genetic;
+
+mod grid;
+pub use grid::{Grid, GridSearch};
+
+mod walk_forward;
+pub use walk_forward::{Fold, WalkForward, WalkForwardReport};
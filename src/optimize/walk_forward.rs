@@ -0,0 +1,272 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::ops::Range;
+
+use super::evaluation::evaluate;
+use super::{GridSearch, Params};
+use crate::backtest::Report;
+use crate::core::{Error, IndicatorConfigDyn, ValueType, OHLCV};
+
+/// Single fold of the [`WalkForward`] analysis
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fold {
+	/// Range of candles the parameters were optimized over
+	pub in_sample: Range<usize>,
+
+	/// Range of candles the optimized parameters were tested over
+	pub out_of_sample: Range<usize>,
+
+	/// The best parameters of the in-sample range
+	pub params: Params,
+
+	/// Value of the objective function over the in-sample range
+	pub in_sample_score: ValueType,
+
+	/// Value of the objective function over the out-of-sample range
+	pub score: ValueType,
+
+	/// Backtest report of the out-of-sample range. Candles indexes are relative to the out-of-sample range start.
+	pub report: Report,
+}
+
+/// Result of the [`WalkForward`] analysis
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WalkForwardReport {
+	/// List of folds
+	pub folds: Vec<Fold>,
+
+	/// Stitched report of all out-of-sample ranges
+	///
+	/// Trades candles indexes are relative to the whole candles series.
+	/// Equity starts at the first out-of-sample candle and is compounded from fold to fold.
+	pub report: Report,
+}
+
+/// Walk-forward analysis
+///
+/// Splits candles into consecutive folds. Every fold consists of `in_sample` candles followed by `out_of_sample` candles.
+/// Parameters are optimized by the `search` over in-sample candles and the best of them are tested over the out-of-sample candles.
+/// The next fold is shifted by `out_of_sample` candles, so out-of-sample ranges do not overlap and cover the rest of the series.
+///
+/// When `anchored` is `true`, every in-sample range starts at the first candle and grows from fold to fold.
+///
+/// Indicator is warmed up by the in-sample candles before testing over the out-of-sample candles.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::RSI;
+/// use yata::optimize::{Grid, GridSearch, WalkForward};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(500).collect();
+///
+/// let walk_forward = WalkForward {
+///     search: GridSearch {
+///         config: RSI::default(),
+///         grid: Grid::new().add("period", [7, 14, 21]),
+///         ..GridSearch::default()
+///     },
+///     in_sample: 200,
+///     out_of_sample: 100,
+///     anchored: false,
+/// };
+///
+/// let result = walk_forward.run(&candles, |report| report.summary.total_return).unwrap();
+/// assert_eq!(result.folds.len(), 3);
+/// assert_eq!(result.report.equity.len(), 300);
+///
+/// for fold in &result.folds {
+///     println!("{:?}: {} -> {}", fold.params, fold.in_sample_score, fold.score);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WalkForward<C> {
+	/// Parameters optimizer
+	pub search: GridSearch<C>,
+
+	/// Count of in-sample candles. Default is `500`.
+	///
+	/// Range in \[`1`; `usize::MAX`\]
+	pub in_sample: usize,
+
+	/// Count of out-of-sample candles. Default is `100`.
+	///
+	/// Range in \[`1`; `usize::MAX`\]
+	pub out_of_sample: usize,
+
+	/// Anchors every in-sample range at the first candle. Default is `false`.
+	pub anchored: bool,
+}
+
+impl<C> WalkForward<C> {
+	/// Returns in-sample and out-of-sample ranges of every fold for the series of `len` candles
+	///
+	/// The last out-of-sample range may be shorter than `out_of_sample`.
+	#[must_use]
+	pub fn splits(&self, len: usize) -> Vec<(Range<usize>, Range<usize>)> {
+		let mut splits = Vec::new();
+
+		if self.in_sample == 0 || self.out_of_sample == 0 {
+			return splits;
+		}
+
+		let mut start = 0;
+		while start + self.in_sample < len {
+			let middle = start + self.in_sample;
+			let end = len.min(middle + self.out_of_sample);
+			let first = if self.anchored { 0 } else { start };
+
+			splits.push((first..middle, middle..end));
+			start += self.out_of_sample;
+		}
+
+		splits
+	}
+
+	/// Runs walk-forward analysis over the `candles` with the `objective` function
+	///
+	/// Returns an error when there is no valid combination of parameters in some fold.
+	pub fn run<T, F>(&self, candles: &[T], objective: F) -> Result<WalkForwardReport, Error>
+	where
+		C: IndicatorConfigDyn<T> + Clone + Send + Sync,
+		T: OHLCV + Sync,
+		F: Fn(&Report) -> ValueType + Sync,
+	{
+		if self.in_sample == 0 || self.out_of_sample == 0 {
+			return Err(Error::WrongConfig);
+		}
+
+		let mut folds = Vec::new();
+		let mut trades = Vec::new();
+		let mut equity = Vec::new();
+
+		for (in_sample, out_of_sample) in self.splits(candles.len()) {
+			let best = self
+				.search
+				.run(&candles[in_sample.clone()], &objective)?
+				.into_iter()
+				.next()
+				.ok_or(Error::WrongConfig)?;
+
+			let evaluation = evaluate(
+				&self.search.config,
+				best.params.clone(),
+				&self.search.backtest,
+				self.search.rule,
+				&candles[in_sample.clone()],
+				&candles[out_of_sample.clone()],
+				&objective,
+			)?
+			.ok_or(Error::WrongConfig)?;
+
+			trades.extend(evaluation.report.trades.iter().map(|trade| {
+				let mut trade = *trade;
+				trade.entry_index += out_of_sample.start;
+				trade.exit_index += out_of_sample.start;
+				trade
+			}));
+
+			let last = equity.last().copied().unwrap_or(1.);
+			equity.extend(evaluation.report.equity.iter().map(|value| value * last));
+
+			folds.push(Fold {
+				in_sample,
+				out_of_sample,
+				params: best.params,
+				in_sample_score: best.score,
+				score: evaluation.score,
+				report: evaluation.report,
+			});
+		}
+
+		Ok(WalkForwardReport {
+			folds,
+			report: Report::new(trades, equity),
+		})
+	}
+}
+
+impl<C: Default> Default for WalkForward<C> {
+	fn default() -> Self {
+		Self {
+			search: GridSearch::default(),
+			in_sample: 500,
+			out_of_sample: 100,
+			anchored: false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::WalkForward;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::RSI;
+	use crate::optimize::{Grid, GridSearch};
+
+	#[test]
+	fn test_walk_forward_splits() {
+		let walk_forward = WalkForward {
+			in_sample: 10,
+			out_of_sample: 4,
+			..WalkForward::<RSI>::default()
+		};
+
+		assert_eq!(
+			walk_forward.splits(20),
+			vec![(0..10, 10..14), (4..14, 14..18), (8..18, 18..20)]
+		);
+		assert!(walk_forward.splits(10).is_empty());
+
+		let walk_forward = WalkForward {
+			anchored: true,
+			..walk_forward
+		};
+		assert_eq!(walk_forward.splits(20)[2], (0..18, 18..20));
+	}
+
+	#[test]
+	fn test_walk_forward() {
+		let candles: Vec<_> = RandomCandles::new().take(450).collect();
+
+		let walk_forward = WalkForward {
+			search: GridSearch {
+				config: RSI::default(),
+				grid: Grid::new().add("period", [7, 14, 21]),
+				..GridSearch::default()
+			},
+			in_sample: 150,
+			out_of_sample: 100,
+			anchored: false,
+		};
+		let result = walk_forward
+			.run(&candles, |report| report.summary.total_return)
+			.unwrap();
+
+		assert_eq!(result.folds.len(), 3);
+		assert_eq!(result.report.equity.len(), 300);
+
+		let mut equity = 1.;
+		for fold in &result.folds {
+			assert!(fold.in_sample_score.is_finite());
+			equity *= 1. + fold.report.summary.total_return;
+		}
+		assert_eq_float(equity - 1., result.report.summary.total_return);
+
+		for trade in &result.report.trades {
+			assert!(trade.entry_index >= 150);
+		}
+
+		let walk_forward = WalkForward {
+			out_of_sample: 0,
+			..walk_forward
+		};
+		assert!(walk_forward
+			.run(&candles, |report| report.summary.total_return)
+			.is_err());
+	}
+}
@@ -0,0 +1,506 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::signi;
+
+/// Candlestick patterns
+///
+/// Recognizes the most common single- and multi-bar candlestick patterns.
+///
+/// Candles' shapes are measured relative to their own `high` - `low` range: f.e. with `long_body` = `0.6`
+/// candle has long body when it's body takes at least `60%` of the candle's range.
+///
+/// Some of the patterns depend on the preceding trend, which is the direction of the `close` value change over the last `trend_period` bars.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Candlestick_pattern>
+/// * <https://www.investopedia.com/articles/active-trading/092315/5-most-powerful-candlestick-patterns.asp>
+///
+/// # 15 signals
+///
+/// Every signal returns full buy signal on bullish pattern and full sell signal on bearish pattern. Otherwise returns no signal.
+///
+/// * #0 Doji: doji after downtrend is bullish, doji after uptrend is bearish.
+/// * #1 Dragonfly doji (bullish) / gravestone doji (bearish).
+/// * #2 Hammer (bullish, after downtrend) / hanging man (bearish, after uptrend).
+/// * #3 Inverted hammer (bullish, after downtrend) / shooting star (bearish, after uptrend).
+/// * #4 Bullish / bearish engulfing.
+/// * #5 Bullish / bearish harami.
+/// * #6 Piercing line (bullish) / dark cloud cover (bearish).
+/// * #7 Morning star (bullish) / evening star (bearish).
+/// * #8 Three white soldiers (bullish) / three black crows (bearish).
+/// * #9 Three inside up (bullish) / three inside down (bearish).
+/// * #10 Three outside up (bullish) / three outside down (bearish).
+/// * #11 Tweezer bottom (bullish, after downtrend) / tweezer top (bearish, after uptrend).
+/// * #12 Bullish / bearish belt hold.
+/// * #13 White (bullish) / black (bearish) marubozu.
+/// * #14 Bullish / bearish kicker.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandlestickPatterns {
+	/// Maximum doji's body relative size. Default is `0.1`.
+	///
+	/// Range in \(`0.0`; `short_body`\]
+	pub doji_body: ValueType,
+
+	/// Maximum short body relative size. Default is `0.3`.
+	///
+	/// Range in \[`doji_body`; `long_body`\)
+	pub short_body: ValueType,
+
+	/// Minimum long body relative size. Default is `0.6`.
+	///
+	/// Range in \(`short_body`; `1.0`\]
+	pub long_body: ValueType,
+
+	/// Maximum short shadow relative size. Default is `0.1`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub short_shadow: ValueType,
+
+	/// Minimum ratio of long shadow to the body for hammer-like patterns. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub shadow_ratio: ValueType,
+
+	/// Trend period. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub trend_period: PeriodType,
+}
+
+impl IndicatorConfig for CandlestickPatterns {
+	type Instance = CandlestickPatternsInstance;
+
+	const NAME: &'static str = "CandlestickPatterns";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let bar = Bar::from(candle);
+
+		Ok(Self::Instance {
+			bars: Window::new(3, bar),
+			closes: Window::new(cfg.trend_period + 1, candle.close()),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.doji_body > 0.
+			&& self.doji_body <= self.short_body
+			&& self.short_body < self.long_body
+			&& self.long_body <= 1.
+			&& self.short_shadow > 0.
+			&& self.short_shadow < 1.
+			&& self.shadow_ratio > 0.
+			&& self.trend_period > 0
+			&& self.trend_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"doji_body" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.doji_body = value,
+			},
+			"short_body" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.short_body = value,
+			},
+			"long_body" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.long_body = value,
+			},
+			"short_shadow" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.short_shadow = value,
+			},
+			"shadow_ratio" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.shadow_ratio = value,
+			},
+			"trend_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.trend_period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(0, 15)
+	}
+}
+
+impl Default for CandlestickPatterns {
+	fn default() -> Self {
+		Self {
+			doji_body: 0.1,
+			short_body: 0.3,
+			long_body: 0.6,
+			short_shadow: 0.1,
+			shadow_ratio: 2.0,
+			trend_period: 5,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bar {
+	open: ValueType,
+	high: ValueType,
+	low: ValueType,
+	close: ValueType,
+}
+
+impl Bar {
+	fn from<T: OHLCV>(src: &T) -> Self {
+		Self {
+			open: src.open(),
+			high: src.high(),
+			low: src.low(),
+			close: src.close(),
+		}
+	}
+
+	fn body(&self) -> ValueType {
+		(self.close - self.open).abs()
+	}
+
+	fn range(&self) -> ValueType {
+		self.high - self.low
+	}
+
+	const fn top(&self) -> ValueType {
+		self.open.max(self.close)
+	}
+
+	const fn bottom(&self) -> ValueType {
+		self.open.min(self.close)
+	}
+
+	fn upper_shadow(&self) -> ValueType {
+		self.high - self.top()
+	}
+
+	fn lower_shadow(&self) -> ValueType {
+		self.bottom() - self.low
+	}
+
+	fn middle(&self) -> ValueType {
+		(self.open + self.close) * 0.5
+	}
+
+	/// `1` for white (bullish) candle, `-1` for black (bearish) candle, `0` otherwise
+	fn direction(&self) -> i8 {
+		(self.close > self.open) as i8 - (self.close < self.open) as i8
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct CandlestickPatternsInstance {
+	cfg: CandlestickPatterns,
+
+	bars: Window<Bar>,
+	closes: Window<ValueType>,
+}
+
+impl CandlestickPatternsInstance {
+	fn is_doji(&self, bar: Bar) -> bool {
+		bar.range() > 0. && bar.body() <= self.cfg.doji_body * bar.range()
+	}
+
+	fn is_short(&self, bar: Bar) -> bool {
+		bar.body() <= self.cfg.short_body * bar.range()
+	}
+
+	fn is_long(&self, bar: Bar) -> bool {
+		bar.range() > 0. && bar.body() >= self.cfg.long_body * bar.range()
+	}
+
+	fn is_short_shadow(&self, shadow: ValueType, bar: Bar) -> bool {
+		shadow <= self.cfg.short_shadow * bar.range()
+	}
+
+	fn is_long_shadow(&self, shadow: ValueType, bar: Bar) -> bool {
+		bar.body() > 0. && shadow >= self.cfg.shadow_ratio * bar.body()
+	}
+
+	fn doji(&self, c: Bar, trend: i8) -> i8 {
+		self.is_doji(c) as i8 * -trend
+	}
+
+	fn dragonfly_gravestone(&self, c: Bar) -> i8 {
+		if !self.is_doji(c) {
+			return 0;
+		}
+
+		self.is_short_shadow(c.upper_shadow(), c) as i8
+			- self.is_short_shadow(c.lower_shadow(), c) as i8
+	}
+
+	fn hammer(&self, c: Bar, trend: i8) -> i8 {
+		let is_hammer =
+			self.is_long_shadow(c.lower_shadow(), c) && self.is_short_shadow(c.upper_shadow(), c);
+
+		is_hammer as i8 * -trend
+	}
+
+	fn inverted_hammer(&self, c: Bar, trend: i8) -> i8 {
+		let is_inverted =
+			self.is_long_shadow(c.upper_shadow(), c) && self.is_short_shadow(c.lower_shadow(), c);
+
+		is_inverted as i8 * -trend
+	}
+
+	fn engulfing(p: Bar, c: Bar) -> i8 {
+		let is_engulfing = p.direction() == -c.direction()
+			&& c.top() >= p.top()
+			&& c.bottom() <= p.bottom()
+			&& c.body() > p.body();
+
+		is_engulfing as i8 * c.direction()
+	}
+
+	fn harami(&self, p: Bar, c: Bar) -> i8 {
+		let is_harami = self.is_long(p) && c.top() < p.top() && c.bottom() > p.bottom();
+
+		is_harami as i8 * -p.direction()
+	}
+
+	fn piercing_dark_cloud(&self, p: Bar, c: Bar) -> i8 {
+		if !self.is_long(p) || p.direction() != -c.direction() {
+			return 0;
+		}
+
+		let piercing = c.open < p.close && c.close > p.middle() && c.close < p.open;
+		let dark_cloud = c.open > p.close && c.close < p.middle() && c.close > p.open;
+
+		piercing as i8 - dark_cloud as i8
+	}
+
+	fn star(&self, pp: Bar, p: Bar, c: Bar) -> i8 {
+		if !self.is_long(pp) || !self.is_short(p) || pp.direction() != -c.direction() {
+			return 0;
+		}
+
+		let morning = p.top() <= pp.close && c.close > pp.middle();
+		let evening = p.bottom() >= pp.close && c.close < pp.middle();
+
+		morning as i8 - evening as i8
+	}
+
+	fn three_soldiers_crows(&self, pp: Bar, p: Bar, c: Bar) -> i8 {
+		let direction = c.direction();
+
+		let is_three = [pp, p, c].iter().all(|bar| {
+			bar.direction() == direction
+				&& !self.is_short(*bar)
+				&& self.is_short_shadow(
+					if direction > 0 {
+						bar.upper_shadow()
+					} else {
+						bar.lower_shadow()
+					},
+					*bar,
+				)
+		}) && [(pp, p), (p, c)].iter().all(|(prev, bar)| {
+			bar.open > prev.bottom()
+				&& bar.open < prev.top()
+				&& signi(bar.close - prev.close) == direction
+		});
+
+		is_three as i8 * direction
+	}
+
+	fn three_inside(&self, pp: Bar, p: Bar, c: Bar) -> i8 {
+		let direction = -pp.direction();
+
+		let is_inside = self.harami(pp, p) == direction
+			&& p.direction() == direction
+			&& c.direction() == direction
+			&& signi(c.close - pp.open) == direction;
+
+		is_inside as i8 * direction
+	}
+
+	fn three_outside(pp: Bar, p: Bar, c: Bar) -> i8 {
+		let direction = p.direction();
+
+		let is_outside = Self::engulfing(pp, p) != 0
+			&& c.direction() == direction
+			&& signi(c.close - p.close) == direction;
+
+		is_outside as i8 * direction
+	}
+
+	fn tweezer(&self, p: Bar, c: Bar, trend: i8) -> i8 {
+		if p.direction() != -c.direction() || c.direction() != -trend {
+			return 0;
+		}
+
+		let tolerance = self.cfg.short_shadow * p.range().max(c.range());
+
+		let bottom = trend < 0 && (p.low - c.low).abs() <= tolerance;
+		let top = trend > 0 && (p.high - c.high).abs() <= tolerance;
+
+		bottom as i8 - top as i8
+	}
+
+	fn belt_hold(&self, c: Bar, trend: i8) -> i8 {
+		if !self.is_long(c) || c.direction() != -trend {
+			return 0;
+		}
+
+		let bullish = self.is_short_shadow(c.lower_shadow(), c);
+		let bearish = self.is_short_shadow(c.upper_shadow(), c);
+
+		(bullish && trend < 0) as i8 - (bearish && trend > 0) as i8
+	}
+
+	fn marubozu(&self, c: Bar) -> i8 {
+		let is_marubozu = self.is_long(c)
+			&& self.is_short_shadow(c.upper_shadow(), c)
+			&& self.is_short_shadow(c.lower_shadow(), c);
+
+		is_marubozu as i8 * c.direction()
+	}
+
+	fn kicker(&self, p: Bar, c: Bar) -> i8 {
+		if !self.is_long(p) || !self.is_long(c) || p.direction() != -c.direction() {
+			return 0;
+		}
+
+		let bullish = c.direction() > 0 && c.low > p.high;
+		let bearish = c.direction() < 0 && c.high < p.low;
+
+		bullish as i8 - bearish as i8
+	}
+}
+
+impl IndicatorInstance for CandlestickPatternsInstance {
+	type Config = CandlestickPatterns;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let trend = signi(self.closes.newest() - self.closes.oldest());
+		self.closes.push(candle.close());
+
+		self.bars.push(Bar::from(candle));
+		let (c, p, pp) = (self.bars[0], self.bars[1], self.bars[2]);
+
+		let signals: [Action; 15] = [
+			self.doji(c, trend).into(),
+			self.dragonfly_gravestone(c).into(),
+			self.hammer(c, trend).into(),
+			self.inverted_hammer(c, trend).into(),
+			Self::engulfing(p, c).into(),
+			self.harami(p, c).into(),
+			self.piercing_dark_cloud(p, c).into(),
+			self.star(pp, p, c).into(),
+			self.three_soldiers_crows(pp, p, c).into(),
+			self.three_inside(pp, p, c).into(),
+			Self::three_outside(pp, p, c).into(),
+			self.tweezer(p, c, trend).into(),
+			self.belt_hold(c, trend).into(),
+			self.marubozu(c).into(),
+			self.kicker(p, c).into(),
+		];
+
+		IndicatorResult::new(&[], &signals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CandlestickPatterns;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+
+	fn candle(open: ValueType, high: ValueType, low: ValueType, close: ValueType) -> Candle {
+		Candle {
+			open,
+			high,
+			low,
+			close,
+			volume: 1.0,
+		}
+	}
+
+	// feeds the candles after a steady downtrend and returns the signals of the last candle
+	fn last_signals(candles: &[Candle]) -> Vec<i8> {
+		let first = candle(20.0, 20.5, 19.5, 20.0);
+		let mut instance = CandlestickPatterns::default().init(&first).unwrap();
+
+		(0..10)
+			.map(|i| {
+				let close = 20.0 - i as ValueType;
+				candle(close + 0.5, close + 0.6, close - 0.1, close)
+			})
+			.chain(candles.iter().copied())
+			.map(|candle| instance.next(&candle))
+			.last()
+			.unwrap()
+			.signals()
+			.iter()
+			.map(|signal| signal.analog())
+			.collect()
+	}
+
+	#[test]
+	fn test_candlestick_patterns_const() {
+		let candle = candle(10.0, 11.0, 9.0, 10.5);
+		let mut instance = CandlestickPatterns::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq!(result.size(), (0, 15));
+			assert!(result.signals().iter().all(|signal| signal.analog() == 0));
+		}
+	}
+
+	#[test]
+	fn test_candlestick_patterns_hammer() {
+		let signals = last_signals(&[candle(10.8, 11.0, 8.0, 11.0)]);
+
+		assert_eq!(signals[2], 1);
+		assert_eq!(signals[3], 0);
+	}
+
+	#[test]
+	fn test_candlestick_patterns_engulfing() {
+		let signals = last_signals(&[
+			candle(11.5, 11.6, 10.4, 10.5),
+			candle(10.3, 12.1, 10.2, 12.0),
+		]);
+
+		assert_eq!(signals[4], 1);
+		assert_eq!(signals[5], 0);
+	}
+
+	#[test]
+	fn test_candlestick_patterns_morning_star() {
+		let signals = last_signals(&[
+			candle(13.0, 13.1, 10.9, 11.0),
+			candle(10.6, 10.9, 10.3, 10.5),
+			candle(10.8, 12.6, 10.7, 12.5),
+		]);
+
+		assert_eq!(signals[7], 1);
+	}
+}
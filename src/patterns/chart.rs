@@ -0,0 +1,457 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::indicators::ZigZag;
+
+const PIVOTS: PeriodType = 5;
+
+/// Chart patterns
+///
+/// Recognizes classical chart patterns over swing points of [`ZigZag`] indicator.
+///
+/// Pattern is formed when the last confirmed swing points have the pattern's shape. Formed pattern waits for `close` value
+/// to break out of it's level (f.e. the neckline of head and shoulders) and becomes complete at the breakout bar.
+/// If no breakout happens until the next swing point of the same kind is confirmed, the pattern is dropped.
+///
+/// * Double top: two highs with the difference not greater than `tolerance` and the low between them, which is the breakout level.
+///   Double bottom is mirrored.
+/// * Head and shoulders: three highs, where the middle one (head) is the highest and the outer ones (shoulders) differ not more than `tolerance`.
+///   The breakout level (neckline) is the average of two lows between them. Inverse head and shoulders is mirrored.
+/// * Triangle: lower highs and higher lows. Breakout levels are the last high and the last low.
+/// * Flag: consolidation of 4 swing points after a strong move (pole), where every swing is not greater than `flag_ratio` of the pole.
+///   Breakout level is the highest (lowest) swing point of bull (bear) flag.
+///
+/// Target level of the pattern is the breakout level moved by the pattern's height (by the pole for flags) in the direction of breakout.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Chart_pattern>
+/// * <https://school.stockcharts.com/doku.php?id=chart_analysis:chart_patterns>
+///
+/// # 4 values
+///
+/// * Target level of the last complete double top/bottom
+/// * Target level of the last complete head and shoulders
+/// * Target level of the last complete triangle
+/// * Target level of the last complete flag
+///
+/// Until the first pattern of the kind is complete, it's value equals to the first `close` value.
+///
+/// Range of values is the same as the range of the `close` values.
+///
+/// # 4 signals
+///
+/// * Double top/bottom
+/// * Head and shoulders
+/// * Triangle
+/// * Flag
+///
+/// On bullish breakout (double bottom, inverse head and shoulders, triangle breakout upwards or bull flag) returns full buy signal.
+/// On bearish breakout (double top, head and shoulders, triangle breakout downwards or bear flag) returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartPatterns {
+	/// [`ZigZag`] relative reversal threshold. Default is `0.05`.
+	///
+	/// Range in \[`0.0`; `1.0`\). When `0.0`, only ATR-based threshold is used.
+	pub deviation: ValueType,
+
+	/// [`ZigZag`] ATR multiplier for reversal threshold. Default is `0.0`.
+	///
+	/// Range in \[`0.0`; `+inf`\). When `0.0`, only relative threshold is used.
+	pub atr_multiplier: ValueType,
+
+	/// [`ZigZag`] ATR period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub atr_period: PeriodType,
+
+	/// Maximum relative difference of the levels treated as equal. Default is `0.03`.
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub tolerance: ValueType,
+
+	/// Maximum size of the flag's swing relative to the pole. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub flag_ratio: ValueType,
+}
+
+impl ChartPatterns {
+	const fn zigzag(self) -> ZigZag {
+		ZigZag {
+			deviation: self.deviation,
+			atr_multiplier: self.atr_multiplier,
+			atr_period: self.atr_period,
+		}
+	}
+}
+
+impl IndicatorConfig for ChartPatterns {
+	type Instance = ChartPatternsInstance;
+
+	const NAME: &'static str = "ChartPatterns";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			zigzag: cfg.zigzag().init(candle)?,
+			pivots: Window::new(PIVOTS, candle.close()),
+			count: 0,
+			pending: [Pending::default(); 4],
+			targets: [candle.close(); 4],
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.zigzag().validate()
+			&& self.tolerance >= 0.
+			&& self.tolerance < 1.
+			&& self.flag_ratio > 0.
+			&& self.flag_ratio < 1.
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"deviation" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.deviation = value,
+			},
+			"atr_multiplier" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_multiplier = value,
+			},
+			"atr_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.atr_period = value,
+			},
+			"tolerance" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.tolerance = value,
+			},
+			"flag_ratio" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.flag_ratio = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		};
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 4)
+	}
+}
+
+impl Default for ChartPatterns {
+	fn default() -> Self {
+		let zigzag = ZigZag::default();
+
+		Self {
+			deviation: zigzag.deviation,
+			atr_multiplier: zigzag.atr_multiplier,
+			atr_period: zigzag.atr_period,
+			tolerance: 0.03,
+			flag_ratio: 0.5,
+		}
+	}
+}
+
+/// Formed pattern waiting for breakout
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+	upper: ValueType,
+	upper_target: ValueType,
+	lower: ValueType,
+	lower_target: ValueType,
+	// how many swing points may be confirmed until the pattern is dropped
+	pivots_left: u8,
+}
+
+impl Pending {
+	fn bullish(level: ValueType, target: ValueType) -> Self {
+		Self {
+			upper: level,
+			upper_target: target,
+			..Self::default()
+		}
+	}
+
+	fn bearish(level: ValueType, target: ValueType) -> Self {
+		Self {
+			lower: level,
+			lower_target: target,
+			..Self::default()
+		}
+	}
+}
+
+impl Default for Pending {
+	fn default() -> Self {
+		Self {
+			upper: ValueType::INFINITY,
+			upper_target: 0.,
+			lower: ValueType::NEG_INFINITY,
+			lower_target: 0.,
+			pivots_left: 2,
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct ChartPatternsInstance {
+	cfg: ChartPatterns,
+
+	zigzag: <ZigZag as IndicatorConfig>::Instance,
+	pivots: Window<ValueType>,
+	count: PeriodType,
+	pending: [Pending; 4],
+	targets: [ValueType; 4],
+}
+
+impl ChartPatternsInstance {
+	fn is_equal(&self, a: ValueType, b: ValueType) -> bool {
+		(a - b).abs() <= self.cfg.tolerance * a.abs().max(b.abs())
+	}
+
+	// `kind` is `1` when the last swing point is high and `-1` when it is low
+	fn double(&self, kind: i8) -> Option<Pending> {
+		let (p0, p1, p2) = (self.pivots[0], self.pivots[1], self.pivots[2]);
+
+		if self.count < 3 || !self.is_equal(p0, p2) {
+			return None;
+		}
+
+		Some(if kind > 0 {
+			Pending::bearish(p1, p1 - (p0.max(p2) - p1))
+		} else {
+			Pending::bullish(p1, p1 + (p1 - p0.min(p2)))
+		})
+	}
+
+	fn head_and_shoulders(&self, kind: i8) -> Option<Pending> {
+		let (p0, p1, p2, p3, p4) = (
+			self.pivots[0],
+			self.pivots[1],
+			self.pivots[2],
+			self.pivots[3],
+			self.pivots[4],
+		);
+
+		let kind = kind as ValueType;
+		let is_head = (p2 - p0) * kind > 0. && (p2 - p4) * kind > 0.;
+
+		if self.count < 5 || !is_head || !self.is_equal(p0, p4) {
+			return None;
+		}
+
+		let neckline = (p1 + p3) * 0.5;
+		let target = neckline - (p2 - neckline);
+
+		Some(if kind > 0. {
+			Pending::bearish(neckline, target)
+		} else {
+			Pending::bullish(neckline, target)
+		})
+	}
+
+	fn triangle(&self, kind: i8) -> Option<Pending> {
+		let (p0, p1, p2, p3) = (
+			self.pivots[0],
+			self.pivots[1],
+			self.pivots[2],
+			self.pivots[3],
+		);
+
+		let ((h0, h1), (l0, l1)) = if kind > 0 {
+			((p0, p2), (p1, p3))
+		} else {
+			((p1, p3), (p0, p2))
+		};
+
+		if self.count < 4 || h0 >= h1 || l0 <= l1 {
+			return None;
+		}
+
+		let height = h1 - l1;
+
+		Some(Pending {
+			upper: h0,
+			upper_target: h0 + height,
+			lower: l0,
+			lower_target: l0 - height,
+			..Pending::default()
+		})
+	}
+
+	fn flag(&self) -> Option<Pending> {
+		let pole = self.pivots[3] - self.pivots[4];
+		let max_swing = self.cfg.flag_ratio * pole.abs();
+
+		let is_flag = self.count >= 5
+			&& (0..3).all(|i| (self.pivots[i] - self.pivots[i + 1]).abs() <= max_swing);
+
+		if !is_flag {
+			return None;
+		}
+
+		let consolidation = (0..4).map(|i| self.pivots[i]);
+
+		Some(if pole > 0. {
+			let level = consolidation.fold(ValueType::NEG_INFINITY, ValueType::max);
+			Pending::bullish(level, level + pole)
+		} else {
+			let level = consolidation.fold(ValueType::INFINITY, ValueType::min);
+			Pending::bearish(level, level + pole)
+		})
+	}
+}
+
+impl IndicatorInstance for ChartPatternsInstance {
+	type Config = ChartPatterns;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let zigzag = self.zigzag.next(candle);
+		// swing low confirmation is a buy signal of zig zag, so the kind of swing point is reversed
+		let kind = -zigzag.signal(0).analog();
+
+		if kind != 0 {
+			self.pivots.push(zigzag.value(0));
+			self.count = self.count.saturating_add(1).min(PIVOTS);
+
+			let patterns = [
+				self.double(kind),
+				self.head_and_shoulders(kind),
+				self.triangle(kind),
+				self.flag(),
+			];
+
+			for (pending, pattern) in self.pending.iter_mut().zip(patterns.iter()) {
+				pending.pivots_left = pending.pivots_left.saturating_sub(1);
+
+				if let Some(pattern) = pattern {
+					*pending = *pattern;
+				} else if pending.pivots_left == 0 {
+					*pending = Pending::default();
+				}
+			}
+		}
+
+		let close = candle.close();
+		let mut signals = [Action::None; 4];
+
+		for ((pending, target), signal) in self
+			.pending
+			.iter_mut()
+			.zip(self.targets.iter_mut())
+			.zip(signals.iter_mut())
+		{
+			if close > pending.upper {
+				*target = pending.upper_target;
+				*signal = Action::BUY_ALL;
+			} else if close < pending.lower {
+				*target = pending.lower_target;
+				*signal = Action::SELL_ALL;
+			} else {
+				continue;
+			}
+
+			*pending = Pending::default();
+		}
+
+		IndicatorResult::new(&self.targets, &signals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ChartPatterns;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType};
+
+	// walks the price through the given points by steps of `1.0`
+	fn walk(points: &[ValueType]) -> Vec<IndicatorResult> {
+		let candle = |price| Candle {
+			open: price,
+			high: price,
+			low: price,
+			close: price,
+			volume: 1.0,
+		};
+
+		let mut instance = ChartPatterns::default().init(&candle(points[0])).unwrap();
+		let mut price = points[0];
+		let mut results = Vec::new();
+
+		for &point in &points[1..] {
+			#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+			let steps = (point - price).abs().ceil() as usize;
+
+			for _ in 0..steps {
+				price += (point - price).clamp(-1.0, 1.0);
+				results.push(instance.next(&candle(price)));
+			}
+		}
+
+		results
+	}
+
+	fn complete(results: &[IndicatorResult], index: usize) -> Vec<(i8, ValueType)> {
+		results
+			.iter()
+			.filter(|result| result.signal(index).analog() != 0)
+			.map(|result| (result.signal(index).analog(), result.value(index)))
+			.collect()
+	}
+
+	#[test]
+	fn test_chart_patterns_double_top() {
+		let results = walk(&[100.0, 120.0, 105.0, 120.5, 95.0]);
+
+		assert_eq!(complete(&results, 0), vec![(-1, 89.5)]);
+	}
+
+	#[test]
+	fn test_chart_patterns_double_bottom() {
+		let results = walk(&[120.0, 100.0, 115.0, 100.5, 125.0]);
+
+		assert_eq!(complete(&results, 0), vec![(1, 130.0)]);
+	}
+
+	#[test]
+	fn test_chart_patterns_head_and_shoulders() {
+		let results = walk(&[100.0, 120.0, 110.0, 130.0, 110.0, 120.0, 90.0]);
+
+		assert_eq!(complete(&results, 1), vec![(-1, 90.0)]);
+	}
+
+	#[test]
+	fn test_chart_patterns_triangle() {
+		let results = walk(&[100.0, 130.0, 110.0, 125.0, 115.0, 135.0]);
+
+		assert_eq!(complete(&results, 2), vec![(1, 145.0)]);
+	}
+
+	#[test]
+	fn test_chart_patterns_flag() {
+		let results = walk(&[100.0, 150.0, 140.0, 149.0, 139.0, 160.0]);
+
+		assert_eq!(complete(&results, 3), vec![(1, 200.0)]);
+	}
+}
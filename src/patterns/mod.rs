@@ -0,0 +1,25 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Patterns recognition.
+//!
+//! Every pattern recognizer implements [`IndicatorConfig`](crate::core::IndicatorConfig) trait,
+//! so it may be used just like any other [indicator](crate::indicators).
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::prelude::*;
+//! use yata::helpers::RandomCandles;
+//! use yata::patterns::CandlestickPatterns;
+//!
+//! let candles: Vec<_> = RandomCandles::new().take(100).collect();
+//! let results = CandlestickPatterns::default().over(&candles).unwrap();
+//!
+//! // signal #4 is for engulfing pattern
+//! let engulfings = results.iter().filter(|result| result.signal(4).analog() != 0).count();
+//! ```
+
+mod candlestick;
+pub use candlestick::CandlestickPatterns;
+
+mod chart;
+pub use chart::ChartPatterns;
@@ -0,0 +1,100 @@
+use super::{Deviation, Fixture, Report};
+use crate::core::{Error, IndicatorConfig, ValueType};
+
+/// Runs indicator over the fixture candles and compares its values with the fixture columns
+///
+/// Every item of `columns` maps index of the indicator value to the name of the fixture column with expected values.
+/// The first `skip` candles are not compared, which is useful when reference implementation needs more candles to converge
+/// (e.g. different initialization of exponential moving averages). [`max_index`](Deviation::max_index) is still an index of the candle.
+///
+/// Returns an error when the fixture has no candles or some column, indicator config is invalid
+/// or indicator has no value with some index.
+pub fn compare<I: IndicatorConfig>(
+	config: I,
+	fixture: &Fixture,
+	columns: &[(usize, &str)],
+	skip: usize,
+) -> Result<Report, Error> {
+	let candles = fixture.candles()?;
+	let results = config.over(&candles)?;
+
+	let deviations = columns
+		.iter()
+		.map(|&(index, name)| {
+			let expected = fixture.column(name)?;
+
+			let actual: Vec<ValueType> = results
+				.iter()
+				.map(|result| {
+					if index < result.values_length() as usize {
+						Ok(result.value(index))
+					} else {
+						Err(Error::Other(format!(
+							"indicator {} has no value with index {}",
+							I::NAME,
+							index
+						)))
+					}
+				})
+				.collect::<Result<_, _>>()?;
+
+			let skip = skip.min(actual.len());
+			let mut deviation = Deviation::between(&actual[skip..], &expected[skip..]);
+			deviation.max_index += skip;
+
+			Ok((name.to_string(), deviation))
+		})
+		.collect::<Result<_, Error>>()?;
+
+	Ok(Report { deviations })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::compare;
+	use crate::core::{Candle, IndicatorConfig};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::RSI;
+	use crate::validation::Fixture;
+
+	use std::fmt::Write;
+
+	#[test]
+	fn test_compare() {
+		let candles: Vec<Candle> = RandomCandles::new().take(50).collect();
+		let results = RSI::default().over(&candles).unwrap();
+
+		// reference values are the same, except for the lookback period and the last value
+		let mut csv = "open,high,low,close,volume,rsi\n".to_string();
+		for (i, (candle, result)) in candles.iter().zip(&results).enumerate() {
+			let rsi = match i {
+				0..=13 => String::new(),
+				49 => format!("{}", result.value(0) + 0.01),
+				_ => format!("{}", result.value(0)),
+			};
+
+			writeln!(
+				csv,
+				"{},{},{},{},{},{}",
+				candle.open, candle.high, candle.low, candle.close, candle.volume, rsi
+			)
+			.unwrap();
+		}
+
+		let fixture = Fixture::parse(&csv).unwrap();
+
+		let report = compare(RSI::default(), &fixture, &[(0, "RSI")], 0).unwrap();
+		let deviation = report.get("rsi").unwrap();
+		assert_eq!(deviation.count, 36);
+		assert_eq!(deviation.max_index, 49);
+		assert!(report.is_within(0.011));
+		assert!(!report.is_within(0.009));
+
+		let report = compare(RSI::default(), &fixture, &[(0, "rsi")], 20).unwrap();
+		assert_eq!(report.get("rsi").unwrap().count, 30);
+		assert_eq!(report.get("rsi").unwrap().max_index, 49);
+
+		assert!(compare(RSI::default(), &fixture, &[(0, "ema")], 0).is_err());
+		assert!(compare(RSI::default(), &fixture, &[(10, "rsi")], 0).is_err());
+	}
+}
@@ -0,0 +1,204 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+use crate::core::ValueType;
+
+/// Deviation of the actual values from the expected values
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Deviation {
+	/// Count of compared values
+	///
+	/// Expected `NaN` values are not compared.
+	pub count: usize,
+
+	/// Count of `NaN` or infinite actual values, where some value is expected
+	pub missing: usize,
+
+	/// Maximum absolute deviation
+	pub max: ValueType,
+
+	/// Mean absolute deviation
+	pub mean: ValueType,
+
+	/// Maximum relative deviation
+	///
+	/// Expected zero values are not taken into account.
+	pub max_relative: ValueType,
+
+	/// Index of the value with maximum absolute deviation
+	pub max_index: usize,
+}
+
+impl Deviation {
+	/// Compares `actual` values with `expected` values
+	///
+	/// Values are compared over the length of the shortest slice.
+	#[must_use]
+	pub fn between(actual: &[ValueType], expected: &[ValueType]) -> Self {
+		let mut deviation = Self::default();
+		let mut sum = 0.;
+
+		for (index, (&actual, &expected)) in actual.iter().zip(expected).enumerate() {
+			if expected.is_nan() {
+				continue;
+			}
+
+			if !actual.is_finite() {
+				deviation.missing += 1;
+				continue;
+			}
+
+			let diff = (actual - expected).abs();
+
+			deviation.count += 1;
+			sum += diff;
+
+			if diff > deviation.max {
+				deviation.max = diff;
+				deviation.max_index = index;
+			}
+
+			if expected != 0. {
+				deviation.max_relative = deviation.max_relative.max(diff / expected.abs());
+			}
+		}
+
+		if deviation.count > 0 {
+			deviation.mean = sum / deviation.count as ValueType;
+		}
+
+		deviation
+	}
+
+	/// Checks if some values are compared, there are no missing values and maximum absolute deviation does not exceed `tolerance`
+	///
+	/// Deviation without any compared values is never within `tolerance`, so an empty or misaligned fixture column fails the check.
+	#[must_use]
+	pub fn is_within(&self, tolerance: ValueType) -> bool {
+		self.count > 0 && self.missing == 0 && self.max <= tolerance
+	}
+
+	/// Asserts values are [within](Deviation::is_within) `tolerance`
+	///
+	/// # Panics
+	///
+	/// Panics when values are not within `tolerance`.
+	#[track_caller]
+	pub fn assert_within(&self, tolerance: ValueType) {
+		assert!(
+			self.is_within(tolerance),
+			"deviation exceeds tolerance {}: {}",
+			tolerance,
+			self
+		);
+	}
+}
+
+impl fmt::Display for Deviation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"max {:e} at {}, mean {:e}, max relative {:e} over {} values, {} missing",
+			self.max, self.max_index, self.mean, self.max_relative, self.count, self.missing
+		)
+	}
+}
+
+/// Deviations of the indicator values from the fixture columns
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Report {
+	/// Fixture column names and deviations of the corresponding indicator values
+	pub deviations: Vec<(String, Deviation)>,
+}
+
+impl Report {
+	/// Returns deviation of the column with the `name`
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&Deviation> {
+		self.deviations
+			.iter()
+			.find(|(column, _)| column.eq_ignore_ascii_case(name))
+			.map(|(_, deviation)| deviation)
+	}
+
+	/// Returns maximum absolute deviation over all the columns
+	#[must_use]
+	pub fn max(&self) -> ValueType {
+		self.deviations
+			.iter()
+			.map(|(_, deviation)| deviation.max)
+			.fold(0., ValueType::max)
+	}
+
+	/// Checks if every column is [within](Deviation::is_within) `tolerance`
+	#[must_use]
+	pub fn is_within(&self, tolerance: ValueType) -> bool {
+		self.deviations
+			.iter()
+			.all(|(_, deviation)| deviation.is_within(tolerance))
+	}
+
+	/// Asserts every column is [within](Deviation::is_within) `tolerance`
+	///
+	/// # Panics
+	///
+	/// Panics when some column is not within `tolerance`.
+	#[track_caller]
+	pub fn assert_within(&self, tolerance: ValueType) {
+		assert!(
+			self.is_within(tolerance),
+			"deviation exceeds tolerance {}:\n{}",
+			tolerance,
+			self
+		);
+	}
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (column, deviation) in &self.deviations {
+			writeln!(f, "{column}: {deviation}")?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Deviation, Report};
+	use crate::core::ValueType;
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_deviation() {
+		let nan = ValueType::NAN;
+		let deviation = Deviation::between(&[1., 2., 3.5, 4., 0.], &[nan, 2., 3., 4.25, 0.]);
+
+		assert_eq!(deviation.count, 4);
+		assert_eq!(deviation.missing, 0);
+		assert_eq_float(0.5, deviation.max);
+		assert_eq!(deviation.max_index, 2);
+		assert_eq_float(0.75 / 4., deviation.mean);
+		assert_eq_float(0.5 / 3., deviation.max_relative);
+		assert!(deviation.is_within(0.5));
+		assert!(!deviation.is_within(0.1));
+
+		let deviation = Deviation::between(&[nan, 1.], &[1., 1.]);
+		assert_eq!(deviation.missing, 1);
+		assert!(!deviation.is_within(1.));
+
+		let deviation = Deviation::between(&[1., 2.], &[nan, nan]);
+		assert_eq!(deviation.count, 0);
+		assert!(!deviation.is_within(1.));
+
+		let report = Report {
+			deviations: vec![("empty".to_string(), deviation)],
+		};
+		assert!(!report.is_within(1.));
+	}
+}
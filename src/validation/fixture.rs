@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use crate::core::{Candle, Error, ValueType};
+
+/// Table of reference values loaded from CSV
+///
+/// The first line is a header with column names. Cells are separated by commas and trimmed.
+/// Empty cells and `null`/`none`/`nan` values (e.g. the lookback period of `TA-Lib` outputs) are read as `NaN`.
+///
+/// Column names are case insensitive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fixture {
+	columns: Vec<String>,
+	rows: Vec<Vec<String>>,
+}
+
+impl Fixture {
+	/// Parses CSV `text`
+	///
+	/// Returns an error when there is no header or some row has different count of cells than the header.
+	pub fn parse(text: &str) -> Result<Self, Error> {
+		let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+		let columns: Vec<String> = match lines.next() {
+			Some(header) => split(header).map(str::to_ascii_lowercase).collect(),
+			None => return Err(Error::Other("fixture has no header".to_string())),
+		};
+
+		let rows = lines
+			.enumerate()
+			.map(|(index, line)| {
+				let row: Vec<String> = split(line).map(str::to_string).collect();
+
+				if row.len() == columns.len() {
+					Ok(row)
+				} else {
+					Err(Error::Other(format!(
+						"fixture row {} has {} cells, but header has {} columns",
+						index + 1,
+						row.len(),
+						columns.len()
+					)))
+				}
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { columns, rows })
+	}
+
+	/// Reads and parses CSV file at the `path`
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+		let path = path.as_ref();
+		let text = std::fs::read_to_string(path).map_err(|error| {
+			Error::Other(format!("unable to read {}: {}", path.display(), error))
+		})?;
+
+		Self::parse(&text)
+	}
+
+	/// Returns names of the columns in lower case
+	#[must_use]
+	pub fn columns(&self) -> &[String] {
+		&self.columns
+	}
+
+	/// Returns count of rows
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.rows.len()
+	}
+
+	/// Checks if there are no rows
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.rows.is_empty()
+	}
+
+	/// Checks if there is a column with the `name`
+	#[must_use]
+	pub fn has_column(&self, name: &str) -> bool {
+		self.position(name).is_some()
+	}
+
+	/// Returns values of the column with the `name`
+	///
+	/// Returns an error when there is no such column or some cell is not a number.
+	pub fn column(&self, name: &str) -> Result<Vec<ValueType>, Error> {
+		let index = self
+			.position(name)
+			.ok_or_else(|| Error::Other(format!("fixture has no column {name:?}")))?;
+
+		self.rows
+			.iter()
+			.enumerate()
+			.map(|(row, cells)| {
+				parse_value(&cells[index]).ok_or_else(|| {
+					Error::Other(format!(
+						"unable to parse fixture cell {:?} at row {}, column {:?}",
+						cells[index],
+						row + 1,
+						name
+					))
+				})
+			})
+			.collect()
+	}
+
+	/// Returns candles from `open`, `high`, `low`, `close` and optional `volume` columns
+	///
+	/// When there is no `volume` column, volume of every candle is `0.0`.
+	pub fn candles(&self) -> Result<Vec<Candle>, Error> {
+		let open = self.column("open")?;
+		let high = self.column("high")?;
+		let low = self.column("low")?;
+		let close = self.column("close")?;
+		let volume = if self.has_column("volume") {
+			self.column("volume")?
+		} else {
+			vec![0.; self.len()]
+		};
+
+		Ok((0..self.len())
+			.map(|i| Candle {
+				open: open[i],
+				high: high[i],
+				low: low[i],
+				close: close[i],
+				volume: volume[i],
+			})
+			.collect())
+	}
+
+	fn position(&self, name: &str) -> Option<usize> {
+		self.columns
+			.iter()
+			.position(|column| column.eq_ignore_ascii_case(name.trim()))
+	}
+}
+
+fn split(line: &str) -> impl Iterator<Item = &str> {
+	line.split(',').map(|cell| cell.trim().trim_matches('"'))
+}
+
+fn parse_value(cell: &str) -> Option<ValueType> {
+	match cell.to_ascii_lowercase().as_str() {
+		"" | "null" | "none" | "nan" => Some(ValueType::NAN),
+		value => value.parse().ok(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Fixture;
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_fixture_parse() {
+		let fixture = Fixture::parse(
+			"Date,Open,High,Low,Close,SMA\n\
+			 2021-01-01,1,2,0.5,1.5,\n\
+			 2021-01-02,1.5,3,1,2.5,NaN\n\
+			 \n\
+			 2021-01-03,2.5,3,2,3,\"2.333\"\n",
+		)
+		.unwrap();
+
+		assert_eq!(fixture.len(), 3);
+		assert_eq!(fixture.columns()[5], "sma");
+
+		let sma = fixture.column("SMA").unwrap();
+		assert!(sma[0].is_nan() && sma[1].is_nan());
+		assert_eq_float(2.333, sma[2]);
+
+		let candles = fixture.candles().unwrap();
+		assert_eq_float(3., candles[1].high);
+		assert_eq_float(0., candles[2].volume);
+
+		assert!(fixture.column("date").is_err());
+		assert!(fixture.column("ema").is_err());
+		assert!(Fixture::parse("").is_err());
+		assert!(Fixture::parse("open,close\n1,2,3").is_err());
+	}
+}
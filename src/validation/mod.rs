@@ -0,0 +1,44 @@
+#![warn(missing_docs, missing_debug_implementations)]
+//! Validation of indicators values against reference series.
+//!
+//! [`Fixture`] loads candles and expected indicator values from CSV (e.g. `TA-Lib` outputs or exchange exports).
+//! [`compare`] runs an indicator over the fixture candles and measures [`Deviation`] of its values from the fixture columns.
+//! The resulting [`Report`] provides tolerance checks, which may be used in tests to certify numerical parity.
+//!
+//! For methods and custom series use [`Deviation::between`] directly.
+//!
+//! [`verify_replay`] checks the indicator is deterministic, so it's **State** rebuilt from a [replay log](crate::core::ReplayLog) reproduces the same results bit-for-bit.
+//!
+//! # Examples
+//!
+//! ```
+//! use yata::indicators::RSI;
+//! use yata::validation::{compare, Fixture};
+//!
+//! let fixture = Fixture::parse(
+//!     "date,open,high,low,close,rsi
+//!     2021-01-01,10.0,10.5,9.5,10.0,
+//!     2021-01-02,10.0,11.0,9.5,10.5,
+//!     2021-01-03,10.5,11.5,10.0,11.0,0.5",
+//! )
+//! .unwrap();
+//!
+//! let report = compare(RSI::default(), &fixture, &[(0, "rsi")], 0).unwrap();
+//! println!("{}", report);
+//!
+//! let deviation = report.get("rsi").unwrap();
+//! assert_eq!(deviation.count, 1);
+//! assert!(report.is_within(1.0));
+//! ```
+
+mod compare;
+pub use compare::compare;
+
+mod deviation;
+pub use deviation::{Deviation, Report};
+
+mod fixture;
+pub use fixture::Fixture;
+
+mod replay;
+pub use replay::verify_replay;
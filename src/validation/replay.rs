@@ -0,0 +1,130 @@
+use crate::core::{Error, IndicatorConfig, IndicatorResult, Recorder, OHLCV};
+
+/// Verifies indicator results are reproduced bit-for-bit after rebuilding the **State** from a [replay log](crate::core::ReplayLog)
+///
+/// Runs indicator over `candles`, takes the replay log after the first `split` candles and compares results of the rest candles
+/// of the original **State**, of the [replayed](Recorder::replay) **State** and of an independent run over all the `candles`.
+///
+/// The replay log is replayed by the same code as the original run, so this check does not verify any stored **State**.
+/// It verifies the indicator is deterministic: it's results depend on nothing but the fed candles
+/// (no global state, time, random numbers or memory addresses). Only such indicators may be rebuilt from the replay log.
+///
+/// Returns an error with the first different results, or when `candles` are empty, or indicator config is invalid.
+pub fn verify_replay<C, T>(config: C, candles: &[T], split: usize) -> Result<(), Error>
+where
+	C: IndicatorConfig,
+	T: OHLCV + Clone,
+{
+	let first = candles.first().ok_or(Error::InvalidCandles)?;
+	let split = split.min(candles.len());
+
+	let independent = config.clone().over(candles)?;
+
+	let mut recorder = Recorder::new(config, first)?;
+	for candle in &candles[..split] {
+		recorder.next(candle);
+	}
+
+	let mut replayed = Recorder::replay(recorder.log())?;
+
+	for (index, candle) in candles.iter().enumerate().skip(split) {
+		let original = recorder.next(candle);
+		let other = replayed.next(candle);
+
+		if !original.is_identical(&other) {
+			return Err(mismatch("replayed", index, &original, &other));
+		}
+
+		if !original.is_identical(&independent[index]) {
+			return Err(mismatch(
+				"independent",
+				index,
+				&original,
+				&independent[index],
+			));
+		}
+	}
+
+	Ok(())
+}
+
+fn mismatch(
+	kind: &str,
+	index: usize,
+	original: &IndicatorResult,
+	other: &IndicatorResult,
+) -> Error {
+	Error::Other(format!(
+		"{kind} results differ at candle {index}: {original:?} != {other:?}"
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::verify_replay;
+	use crate::core::{
+		Candle, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV,
+	};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{AverageDirectionalIndex, Gaps, MACD, RSI};
+
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	static INITS: AtomicUsize = AtomicUsize::new(0);
+
+	/// Indicator, which depends on global state
+	#[derive(Debug, Clone, Copy)]
+	struct Leaky;
+
+	#[derive(Debug)]
+	struct LeakyInstance(usize);
+
+	impl IndicatorConfig for Leaky {
+		type Instance = LeakyInstance;
+
+		const NAME: &'static str = "Leaky";
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+			Err(Error::ParameterParse(name.to_string(), value))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(1, 0)
+		}
+
+		fn init<T: OHLCV>(self, _: &T) -> Result<Self::Instance, Error> {
+			Ok(LeakyInstance(INITS.fetch_add(1, Ordering::SeqCst)))
+		}
+	}
+
+	impl IndicatorInstance for LeakyInstance {
+		type Config = Leaky;
+
+		fn config(&self) -> &Self::Config {
+			&Leaky
+		}
+
+		fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+			IndicatorResult::new(&[candle.close() + self.0 as ValueType], &[])
+		}
+	}
+
+	#[test]
+	fn test_verify_replay() {
+		let candles: Vec<Candle> = RandomCandles::new().take(200).collect();
+
+		for split in &[0, 1, 50, 199, 500] {
+			verify_replay(MACD::default(), &candles, *split).unwrap();
+			verify_replay(RSI::default(), &candles, *split).unwrap();
+			verify_replay(AverageDirectionalIndex::default(), &candles, *split).unwrap();
+			verify_replay(Gaps::default(), &candles, *split).unwrap();
+		}
+
+		assert!(verify_replay(Leaky, &candles, 100).is_err());
+		assert!(verify_replay(RSI::default(), &candles[..0], 0).is_err());
+	}
+}